@@ -6,7 +6,7 @@
 use serde_json::json;
 use sha256::digest;
 
-fn convert_password_ssha256(password: String) -> String {
+pub fn convert_password_ssha256(password: String) -> String {
     digest(password)
 }
 
@@ -20,9 +20,23 @@ use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct TypingInfo {
-    room_id: String,
-    user_id: String,
-    typing: bool,
+    pub room_id: String,
+    // Rocket.Chat identifies typing user by username
+    pub user_name: String,
+    pub typing: bool,
+    // Empty when user doesn't type in a thread
+    pub thread_message_id: String,
+}
+
+impl TypingInfo {
+    pub fn new(room_id: String, user_name: String, typing: bool) -> Self {
+        TypingInfo {
+            room_id,
+            user_name,
+            typing,
+            thread_message_id: String::default(),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -30,6 +44,7 @@ enum Command {
     Disconnect,
     SendMessage(String, String), /* message, room_id */
     ChangeDefaultStatus(String),
+    SetAway(bool),
     ChangeTypingStatus(TypingInfo),
     SubscribeRoom(String),
 }
@@ -257,6 +272,9 @@ impl DDpClientTask {
                     ChangeDefaultStatus(status) => {
                         self.send_default_status(status).await
                     }
+                    SetAway(away) => {
+                        self.send_user_presence(away).await
+                    }
                     ChangeTypingStatus(info) => {
                         self.send_change_typing_info(info).await
                     }
@@ -283,8 +301,9 @@ impl DDpClientTask {
         println!("Change typing status {:?}", info);
         let change_typing_str = rocketchatmessage::generate_inform_typing_status(
             info.room_id,
-            info.user_id,
+            info.user_name,
             info.typing,
+            info.thread_message_id,
             &mut self.method_identifier,
         );
         self.to_ws
@@ -305,6 +324,16 @@ impl DDpClientTask {
             .expect("Impossible change status");
     }
 
+    async fn send_user_presence(&mut self, away: bool) {
+        println!("Change user presence away: {}", away);
+        let user_presence_str =
+            rocketchatmessage::generate_method_user_presence(away, &mut self.method_identifier);
+        self.to_ws
+            .send(Message::Text(user_presence_str))
+            .await
+            .expect("Impossible change user presence");
+    }
+
     async fn added_elements(&mut self, elements: ddpmessage::AddElementType) {
         println!("add elements {:?}", elements);
         self.event_sender
@@ -425,6 +454,11 @@ impl DDpClient {
             .unwrap();
     }
 
+    // Used by auto away, default status is not changed
+    pub fn set_away(&self, away: bool) {
+        self.command_sender.send(Command::SetAway(away)).unwrap();
+    }
+
     pub fn change_typing_info(&self, info: TypingInfo) {
         self.command_sender
             .send(Command::ChangeTypingStatus(info))
@@ -74,7 +74,8 @@ pub enum ChangeElementType {
     StreamNotifyUserForceLogout(serde_json::Value),
     StreamNotifyUserUnknown(serde_json::Value),
     StreamNotifyRoomDeleteMessage,
-    StreamNotifyRoomUserActivity,
+    // Room identifier, [username, activities, extra data]
+    StreamNotifyRoomUserActivity(String, serde_json::Value),
     StreamNotifyLogged,
     StreamNotifyRoomUnknown,
     StreamNotifyAllUnknown,
@@ -92,6 +93,7 @@ pub enum ChangeElementType {
     DeleteEmojiCustom(serde_json::Value),
     PermissionsChanged(serde_json::Value),
     PrivateSettingsChanged(serde_json::Value),
+    PublicSettingsChanged(serde_json::Value),
     StreamRoles(serde_json::Value),
     Unknown,
 }
@@ -218,12 +220,12 @@ fn parse_changed_element(json: serde_json::Value) -> ChangeElementType {
                 return ChangeElementType::Unknown;
             }
             let event_name = event_name.unwrap();
+            let contents = fields.unwrap()["args"].to_owned();
             if event_name.ends_with("/deleteMessage") {
                 // TODO roomId and messageId
                 ChangeElementType::StreamNotifyRoomDeleteMessage
-            } else if event_name.ends_with("user-activity") {
-                // TODO parse element
-                ChangeElementType::StreamNotifyRoomUserActivity
+            } else if let Some(room_id) = event_name.strip_suffix("/user-activity") {
+                ChangeElementType::StreamNotifyRoomUserActivity(room_id.to_string(), contents)
             } else {
                 ChangeElementType::StreamNotifyRoomUnknown
             }
@@ -262,6 +264,7 @@ fn parse_changed_element(json: serde_json::Value) -> ChangeElementType {
                 "updateCustomSound" => {
                     ChangeElementType::StreamNotifyAllUpdateCustomSound(contents)
                 }
+                "public-settings-changed" => ChangeElementType::PublicSettingsChanged(contents),
                 _ => ChangeElementType::StreamNotifyAllUnknown,
             }
         }
@@ -371,5 +374,29 @@ mod tests {
                 ))
             );
         }
+        // user activity
+        {
+            let request: &str = r#"{"collection":"stream-notify-room","fields":{"args":["foo",["user-typing"],{}],"eventName":"GENERAL/user-activity"},"id":"id","msg":"changed"}"#;
+            let message = Message::Text(request.to_string());
+            let value = json!(["foo", ["user-typing"], {}]);
+            assert_eq!(
+                ddpmessage::parse_received_message(message),
+                ddpmessage::MessageReceivedType::ElementChanged(
+                    ChangeElementType::StreamNotifyRoomUserActivity("GENERAL".to_string(), value)
+                )
+            );
+        }
+        // public settings
+        {
+            let request: &str = r#"{"collection":"stream-notify-all","fields":{"args":["updated",{"_id":"Message_MaxAllowedSize","value":8000}],"eventName":"public-settings-changed"},"id":"id","msg":"changed"}"#;
+            let message = Message::Text(request.to_string());
+            let value = json!(["updated", {"_id": "Message_MaxAllowedSize", "value": 8000}]);
+            assert_eq!(
+                ddpmessage::parse_received_message(message),
+                ddpmessage::MessageReceivedType::ElementChanged(
+                    ChangeElementType::PublicSettingsChanged(value)
+                )
+            );
+        }
     }
 }
@@ -32,15 +32,39 @@ pub fn generate_method_set_default_status(status_str: String, identifier: &mut u
     )
 }
 
+// Temporary status, default status is restored by "UserPresence:online"
+pub fn generate_method_user_presence(away: bool, identifier: &mut u64) -> String {
+    *identifier = identifier.add(1);
+    let method_name = if away {
+        "UserPresence:away"
+    } else {
+        "UserPresence:online"
+    };
+    authenticationutils::generate_method(String::from(method_name), json!([]), *identifier)
+}
+
+// [<room_id>/user-activity, username, ["user-typing"]|[], {"tmid": …}]
+// thread_message_id is empty when user doesn't type in a thread.
 pub fn generate_inform_typing_status(
     room_id: String,
-    user_id: String,
+    user_name: String,
     typing_status: bool,
+    thread_message_id: String,
     identifier: &mut u64,
 ) -> String {
     let event_name = room_id + "/user-activity";
     *identifier = identifier.add(1);
-    let value = json!([event_name, user_id, typing_status]);
+    let activities = if typing_status {
+        json!(["user-typing"])
+    } else {
+        json!([])
+    };
+    let extras = if thread_message_id.is_empty() {
+        json!({})
+    } else {
+        json!({ "tmid": thread_message_id })
+    };
+    let value = json!([event_name, user_name, activities, extras]);
 
     authenticationutils::generate_method(String::from("stream-notify-room"), value, *identifier)
 }
@@ -75,17 +99,41 @@ mod tests {
             r#"{"id":"5","method":"UserPresence:setDefaultStatus","msg":"method","params":["away"]}"#
         );
     }
+    #[test]
+    fn test_generate_method_user_presence() {
+        let mut identifier = 4;
+        assert_eq!(
+            rocketchatmessage::generate_method_user_presence(true, &mut identifier),
+            r#"{"id":"5","method":"UserPresence:away","msg":"method","params":[]}"#
+        );
+        assert_eq!(
+            rocketchatmessage::generate_method_user_presence(false, &mut identifier),
+            r#"{"id":"6","method":"UserPresence:online","msg":"method","params":[]}"#
+        );
+    }
+
     #[test]
     fn test_generate_inform_typing_status() {
-        let mut value = 5;
+        let mut value = 4;
         assert_eq!(
             rocketchatmessage::generate_inform_typing_status(
                 String::from("room42"),
                 String::from("user42"),
                 true,
+                String::default(),
+                &mut value
+            ),
+            r#"{"id":"5","method":"stream-notify-room","msg":"method","params":["room42/user-activity","user42",["user-typing"],{}]}"#
+        );
+        assert_eq!(
+            rocketchatmessage::generate_inform_typing_status(
+                String::from("room42"),
+                String::from("user42"),
+                false,
+                String::from("thread1"),
                 &mut value
             ),
-            r#"{"id":"5","method":"stream-notify-room","msg":"method","params":["room42/user-activity","user42",true]}"#
+            r#"{"id":"6","method":"stream-notify-room","msg":"method","params":["room42/user-activity","user42",[],{"tmid":"thread1"}]}"#
         );
     }
 
@@ -0,0 +1,298 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::{RestApiUrlExtensionType, RestApiUrlType};
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum UiInteractionType {
+    // Button, overflow or select in a message or a view
+    #[default]
+    BlockAction,
+    // Submit button of a modal
+    ViewSubmit,
+    ViewClosed,
+}
+
+impl UiInteractionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UiInteractionType::BlockAction => "blockAction",
+            UiInteractionType::ViewSubmit => "viewSubmit",
+            UiInteractionType::ViewClosed => "viewClosed",
+        }
+    }
+}
+
+/// Implement POST apps/ui.interaction/:appId
+pub struct AppsUiInteractionMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub app_id: String,
+    pub interaction_type: UiInteractionType,
+    pub trigger_id: String,
+    pub action_id: String,
+    pub block_id: String,
+    pub value: String,
+    // Block action in a message
+    pub room_id: String,
+    pub message_id: String,
+    pub tmid: Option<String>,
+    // Block action in a view, view submit or closed
+    pub view_id: String,
+    // Block id => action id => value, values of input blocks of view
+    pub state: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for AppsUiInteractionMethod {
+    fn default() -> Self {
+        AppsUiInteractionMethod {
+            settings: AuthenticationType::None,
+            server_url: String::new(),
+            app_id: String::new(),
+            interaction_type: UiInteractionType::BlockAction,
+            trigger_id: String::new(),
+            action_id: String::new(),
+            block_id: String::new(),
+            value: String::new(),
+            room_id: String::new(),
+            message_id: String::new(),
+            tmid: None,
+            view_id: String::new(),
+            state: HashMap::new(),
+        }
+    }
+}
+
+impl AppsUiInteractionMethod {
+    fn state_payload(&self) -> PayloadValue<'_> {
+        PayloadValue::Object(
+            self.state
+                .iter()
+                .map(|(block_id, values)| {
+                    (
+                        block_id.clone(),
+                        PayloadValue::Object(
+                            values
+                                .iter()
+                                .map(|(action_id, value)| {
+                                    (action_id.clone(), PayloadValue::String(value))
+                                })
+                                .collect(),
+                        ),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn view_payload(&self) -> PayloadValue<'_> {
+        let mut view: HashMap<String, PayloadValue> = HashMap::new();
+        view.insert("id".to_string(), PayloadValue::String(&self.view_id));
+        view.insert("appId".to_string(), PayloadValue::String(&self.app_id));
+        view.insert("state".to_string(), self.state_payload());
+        PayloadValue::Object(view)
+    }
+}
+
+impl APIMethod for AppsUiInteractionMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::AppsUiInteraction,
+            extension_type: RestApiUrlExtensionType::Apps,
+            url_extension: format!("/{}", self.app_id),
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert(
+            "type".to_string(),
+            PayloadValue::String(self.interaction_type.as_str()),
+        );
+        payload.insert(
+            "triggerId".to_string(),
+            PayloadValue::String(&self.trigger_id),
+        );
+        if !self.action_id.is_empty() {
+            payload.insert(
+                "actionId".to_string(),
+                PayloadValue::String(&self.action_id),
+            );
+        }
+        let mut content: HashMap<String, PayloadValue> = HashMap::new();
+        match self.interaction_type {
+            UiInteractionType::BlockAction => {
+                content.insert("blockId".to_string(), PayloadValue::String(&self.block_id));
+                content.insert("value".to_string(), PayloadValue::String(&self.value));
+                let mut container: HashMap<String, PayloadValue> = HashMap::new();
+                if self.view_id.is_empty() {
+                    container.insert("type".to_string(), PayloadValue::String("message"));
+                    container.insert("id".to_string(), PayloadValue::String(&self.message_id));
+                    payload.insert("mid".to_string(), PayloadValue::String(&self.message_id));
+                    payload.insert("rid".to_string(), PayloadValue::String(&self.room_id));
+                    if let Some(tmid) = &self.tmid {
+                        payload.insert("tmid".to_string(), PayloadValue::String(tmid));
+                    }
+                } else {
+                    container.insert("type".to_string(), PayloadValue::String("view"));
+                    container.insert("id".to_string(), PayloadValue::String(&self.view_id));
+                }
+                payload.insert("container".to_string(), PayloadValue::Object(container));
+            }
+            UiInteractionType::ViewSubmit => {
+                content.insert("view".to_string(), self.view_payload());
+            }
+            UiInteractionType::ViewClosed => {
+                content.insert("viewId".to_string(), PayloadValue::String(&self.view_id));
+                content.insert("view".to_string(), self.view_payload());
+            }
+        }
+        payload.insert("payload".to_string(), PayloadValue::Object(content));
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{APIMethod, AppsUiInteractionMethod, PayloadValue, UiInteractionType};
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+    use std::collections::HashMap;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_block_action_values() {
+        let result = AppsUiInteractionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            app_id: "app1".to_string(),
+            trigger_id: "trigger1".to_string(),
+            action_id: "approve".to_string(),
+            block_id: "block1".to_string(),
+            value: "42".to_string(),
+            room_id: "room1".to_string(),
+            message_id: "msg1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert!(result.query_parameters().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/apps/ui.interaction/app1"
+        );
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("type"), Some(PayloadValue::String("blockAction")));
+            assert_matches!(
+                json.get("triggerId"),
+                Some(PayloadValue::String("trigger1"))
+            );
+            assert_matches!(json.get("actionId"), Some(PayloadValue::String("approve")));
+            assert_matches!(json.get("mid"), Some(PayloadValue::String("msg1")));
+            assert_matches!(json.get("rid"), Some(PayloadValue::String("room1")));
+            assert!(!json.contains_key("tmid"));
+            let Some(PayloadValue::Object(payload)) = json.get("payload") else {
+                panic!("payload expected");
+            };
+            assert_matches!(payload.get("blockId"), Some(PayloadValue::String("block1")));
+            assert_matches!(payload.get("value"), Some(PayloadValue::String("42")));
+            let Some(PayloadValue::Object(container)) = json.get("container") else {
+                panic!("container expected");
+            };
+            assert_matches!(container.get("type"), Some(PayloadValue::String("message")));
+            assert_matches!(container.get("id"), Some(PayloadValue::String("msg1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_view_submit_values() {
+        let mut state = HashMap::new();
+        state.insert(
+            "reason".to_string(),
+            HashMap::from([("reason_input".to_string(), "hotfix".to_string())]),
+        );
+        let result = AppsUiInteractionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            app_id: "app1".to_string(),
+            interaction_type: UiInteractionType::ViewSubmit,
+            trigger_id: "trigger2".to_string(),
+            view_id: "view1".to_string(),
+            state,
+            ..Default::default()
+        };
+        let json = result.json_payload().unwrap();
+        assert_matches!(json.get("type"), Some(PayloadValue::String("viewSubmit")));
+        assert!(!json.contains_key("actionId"));
+        assert!(!json.contains_key("container"));
+        let Some(PayloadValue::Object(payload)) = json.get("payload") else {
+            panic!("payload expected");
+        };
+        let Some(PayloadValue::Object(view)) = payload.get("view") else {
+            panic!("view expected");
+        };
+        assert_matches!(view.get("id"), Some(PayloadValue::String("view1")));
+        let Some(PayloadValue::Object(state)) = view.get("state") else {
+            panic!("state expected");
+        };
+        let Some(PayloadValue::Object(reason)) = state.get("reason") else {
+            panic!("reason expected");
+        };
+        assert_matches!(
+            reason.get("reason_input"),
+            Some(PayloadValue::String("hotfix"))
+        );
+
+        let closed = AppsUiInteractionMethod {
+            interaction_type: UiInteractionType::ViewClosed,
+            ..result
+        };
+        let json = closed.json_payload().unwrap();
+        assert_matches!(json.get("type"), Some(PayloadValue::String("viewClosed")));
+        let Some(PayloadValue::Object(payload)) = json.get("payload") else {
+            panic!("payload expected");
+        };
+        assert_matches!(payload.get("viewId"), Some(PayloadValue::String("view1")));
+    }
+}
@@ -0,0 +1,344 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+use std::collections::HashMap;
+
+/// Implement autotranslate.getSupportedLanguages
+pub struct AutoTranslateGetSupportedLanguagesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    // Language used for names of languages, server language when empty
+    pub target_language: String,
+}
+
+impl Default for AutoTranslateGetSupportedLanguagesMethod {
+    fn default() -> Self {
+        AutoTranslateGetSupportedLanguagesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            target_language: String::default(),
+        }
+    }
+}
+
+impl APIMethod for AutoTranslateGetSupportedLanguagesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::AutoTranslateGetSupportedLanguages,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        if self.target_language.is_empty() {
+            return None;
+        }
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("targetLanguage".to_string(), self.target_language.clone());
+        Some(payload)
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+// Subscription field changed by autotranslate.saveSettings
+#[derive(Clone, Debug, PartialEq)]
+pub enum AutoTranslateSetting {
+    AutoTranslate(bool),
+    // Target language of room ("fr", "pt-BR", …)
+    AutoTranslateLanguage(String),
+}
+
+/// Implement autotranslate.saveSettings
+pub struct AutoTranslateSaveSettingsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub setting: AutoTranslateSetting,
+    // Language used when auto translate is enabled and room has no language
+    pub default_language: Option<String>,
+}
+
+impl Default for AutoTranslateSaveSettingsMethod {
+    fn default() -> Self {
+        AutoTranslateSaveSettingsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            setting: AutoTranslateSetting::AutoTranslate(false),
+            default_language: None,
+        }
+    }
+}
+
+impl APIMethod for AutoTranslateSaveSettingsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::AutoTranslateSaveSettings,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        match &self.setting {
+            AutoTranslateSetting::AutoTranslate(value) => {
+                payload.insert("field".to_string(), PayloadValue::String("autoTranslate"));
+                payload.insert("value".to_string(), PayloadValue::Bool(value));
+            }
+            AutoTranslateSetting::AutoTranslateLanguage(value) => {
+                payload.insert(
+                    "field".to_string(),
+                    PayloadValue::String("autoTranslateLanguage"),
+                );
+                payload.insert("value".to_string(), PayloadValue::String(value));
+            }
+        }
+        if let Some(default_language) = &self.default_language {
+            payload.insert(
+                "defaultLanguage".to_string(),
+                PayloadValue::String(default_language),
+            );
+        }
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+/// Implement autotranslate.translateMessage
+pub struct AutoTranslateTranslateMessageMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub message_id: String,
+    // Server uses language of subscription when empty
+    pub target_language: String,
+}
+
+impl Default for AutoTranslateTranslateMessageMethod {
+    fn default() -> Self {
+        AutoTranslateTranslateMessageMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            message_id: String::default(),
+            target_language: String::default(),
+        }
+    }
+}
+
+impl APIMethod for AutoTranslateTranslateMessageMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::AutoTranslateTranslateMessage,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert(
+            "messageId".to_string(),
+            PayloadValue::String(&self.message_id),
+        );
+        if !self.target_language.is_empty() {
+            payload.insert(
+                "targetLanguage".to_string(),
+                PayloadValue::String(&self.target_language),
+            );
+        }
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, AutoTranslateGetSupportedLanguagesMethod, AutoTranslateSaveSettingsMethod,
+        AutoTranslateSetting, AutoTranslateTranslateMessageMethod, PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_get_supported_languages_values() {
+        let result = AutoTranslateGetSupportedLanguagesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            target_language: "fr".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert!(result.json_payload().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/autotranslate.getSupportedLanguages"
+        );
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("targetLanguage"), Some(&"fr".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        let result = AutoTranslateGetSupportedLanguagesMethod::default();
+        assert!(result.query_parameters().is_none());
+    }
+
+    #[test]
+    fn test_save_settings_values() {
+        let result = AutoTranslateSaveSettingsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room1".to_string(),
+            setting: AutoTranslateSetting::AutoTranslate(true),
+            default_language: Some("de".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert!(result.query_parameters().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/autotranslate.saveSettings"
+        );
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room1")));
+            assert_matches!(
+                json.get("field"),
+                Some(PayloadValue::String("autoTranslate"))
+            );
+            assert_matches!(json.get("value"), Some(PayloadValue::Bool(true)));
+            assert_matches!(
+                json.get("defaultLanguage"),
+                Some(PayloadValue::String("de"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        let result = AutoTranslateSaveSettingsMethod {
+            room_id: "room1".to_string(),
+            setting: AutoTranslateSetting::AutoTranslateLanguage("pt-BR".to_string()),
+            ..Default::default()
+        };
+        let json = result.json_payload().unwrap();
+        assert_matches!(
+            json.get("field"),
+            Some(PayloadValue::String("autoTranslateLanguage"))
+        );
+        assert_matches!(json.get("value"), Some(PayloadValue::String("pt-BR")));
+        assert!(!json.contains_key("defaultLanguage"));
+    }
+
+    #[test]
+    fn test_translate_message_values() {
+        let result = AutoTranslateTranslateMessageMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            message_id: "msg1".to_string(),
+            target_language: "es".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert!(result.query_parameters().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/autotranslate.translateMessage"
+        );
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("messageId"), Some(PayloadValue::String("msg1")));
+            assert_matches!(json.get("targetLanguage"), Some(PayloadValue::String("es")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        let result = AutoTranslateTranslateMessageMethod {
+            message_id: "msg1".to_string(),
+            ..Default::default()
+        };
+        assert!(
+            !result
+                .json_payload()
+                .unwrap()
+                .contains_key("targetLanguage")
+        );
+    }
+}
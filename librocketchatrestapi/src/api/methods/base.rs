@@ -9,8 +9,9 @@ use crate::errors::Error;
 use async_trait::async_trait;
 use libauthenticationbase::authenticationsettings::AuthenticationType;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::multipart::{Form, Part};
 use reqwest::{Client, Method, Response};
-use serde::ser::SerializeSeq;
+use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{Deserialize, Serialize, Serializer};
 
 use super::restapiutils::{self, generate_url};
@@ -49,6 +50,10 @@ pub enum PayloadValue<'a> {
     String(&'a str),
     Bool(&'a bool),
     ListOfString(Vec<&'a str>),
+    CommaSeparatedList(Vec<&'a str>),
+    Integer(&'a i64),
+    Object(HashMap<String, PayloadValue<'a>>),
+    ListOfObject(Vec<HashMap<String, PayloadValue<'a>>>),
 }
 
 impl Serialize for PayloadValue<'_> {
@@ -66,7 +71,56 @@ impl Serialize for PayloadValue<'_> {
                 }
                 seq.end()
             }
+            PayloadValue::CommaSeparatedList(val) => serializer.serialize_str(&val.join(",")),
+            PayloadValue::Integer(val) => serializer.serialize_i64(**val),
+            PayloadValue::Object(val) => {
+                let mut map = serializer.serialize_map(Some(val.len()))?;
+                for (key, value) in val {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            PayloadValue::ListOfObject(val) => {
+                let mut seq = serializer.serialize_seq(Some(val.len()))?;
+                for el in val {
+                    seq.serialize_element(el)?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultipartFile {
+    pub field_name: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+// multipart/form-data body, used to upload files.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MultipartPayload {
+    pub fields: Vec<(String, String)>,
+    pub files: Vec<MultipartFile>,
+}
+
+impl MultipartPayload {
+    // reqwest generates boundary and escapes names.
+    pub fn form(&self) -> Result<Form, Error> {
+        let mut form = Form::new();
+        for (name, value) in &self.fields {
+            form = form.text(name.clone(), value.clone());
+        }
+        for file in &self.files {
+            let part = Part::bytes(file.data.clone())
+                .file_name(file.file_name.clone())
+                .mime_str(&file.mime_type)
+                .map_err(|err| Error::RequestFailed(err.to_string()))?;
+            form = form.part(file.field_name.clone(), part);
         }
+        Ok(form)
     }
 }
 
@@ -81,6 +135,11 @@ pub trait APIMethod {
 
     fn endpointinfo(&self) -> EndPointInfo;
 
+    // Methods uploading files send it instead of json payload.
+    fn multipart_payload(&self) -> Option<MultipartPayload> {
+        None
+    }
+
     fn build_endpoint(&self) -> Result<String, Error> {
         if self.domain().is_empty() {
             return Err(Error::MissingSettings);
@@ -101,6 +160,7 @@ pub trait APIMethod {
         endpoint: String,
         method: Method,
         json_map: Option<&HashMap<String, PayloadValue>>,
+        multipart: Option<&MultipartPayload>,
         auth_data: Option<AuthData>,
         auth_query: Option<&HashMap<String, String>>,
     ) -> Result<Response, Error> {
@@ -125,6 +185,9 @@ pub trait APIMethod {
         if let Some(json_result) = &json_map {
             request = request.json(json_result);
         }
+        if let Some(multipart) = &multipart {
+            request = request.multipart(multipart.form()?);
+        }
         if let Some(query) = &auth_query {
             request = request.query(query);
         }
@@ -159,6 +222,7 @@ pub trait APIMethod {
                 Some(&self.login_payload(username, password)),
                 None,
                 None,
+                None,
             )
             .await?;
 
@@ -199,8 +263,9 @@ pub trait APIMethod {
                 self.build_endpoint()?,
                 self.method(),
                 self.json_payload().as_ref(),
+                self.multipart_payload().as_ref(),
                 Some(auth_data),
-                None,
+                self.query_parameters().as_ref(),
             )
             .await?;
 
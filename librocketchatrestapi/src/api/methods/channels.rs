@@ -5,10 +5,10 @@
  */
 use std::collections::HashMap;
 
+use crate::api::methods::APIMethod;
 use crate::api::methods::base::EndPointInfo;
 use crate::api::methods::base::PayloadValue;
 use crate::api::methods::restapiutils::RestApiUrlType;
-use crate::api::methods::APIMethod;
 use libauthenticationbase::authenticationsettings::AuthenticationType;
 use reqwest::Method;
 pub struct ChannelCreateMethod {
@@ -190,93 +190,2207 @@ impl APIMethod for ChannelRemoveLeaderMethod {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::methods::{
-        APIMethod, ChannelCreateMethod, ChannelRemoveLeaderMethod, ChannelRemoveModeratorJob,
-        PayloadValue,
-    };
-    use assert_matches::assert_matches;
-    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
-    use reqwest::Method;
+// ChannelInviteMethod
+pub struct ChannelInviteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
 
-    pub fn generate_default_settings() -> AuthenticationType {
-        AuthenticationType::Login(LoginSettings {
-            username: "chuck_norris".to_string(),
-            password: "supersecret".to_string(),
-        })
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelInviteMethod {
+    fn default() -> Self {
+        ChannelInviteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
+        }
     }
+}
 
-    #[test]
-    fn test_channels_create_values() {
-        let result = ChannelCreateMethod {
-            settings: generate_default_settings(),
-            name: "some-channel".to_string(),
-            members: Some(vec!["rocket.cat".to_string()]),
+impl APIMethod for ChannelInviteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsInvite,
             ..Default::default()
-        };
-        assert_eq!(result.method(), Method::POST);
-        assert!(result.required_authentication());
+        }
+    }
 
-        // Test Json values.
-        if let Some(json) = &result.json_payload() {
-            assert_matches!(json.get("name"), Some(PayloadValue::String("some-channel")));
-            assert_matches!(json.get("name"), Some(PayloadValue::String("some-channel")));
-            assert_matches!(json.get("readOnly"), Some(PayloadValue::Bool(false)));
-            let _list_members = ["rocket.cat"];
-            assert_matches!(
-                json.get("members"),
-                Some(PayloadValue::ListOfString(_list_members))
-            );
-        } else {
-            panic!("Impossble to get parameters");
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelKickMethod
+pub struct ChannelKickMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelKickMethod {
+    fn default() -> Self {
+        ChannelKickMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
         }
     }
+}
 
-    #[test]
-    fn test_channels_remove_leader_values() {
-        let result = ChannelRemoveLeaderMethod {
-            settings: generate_default_settings(),
-            user_id: "user_id1".to_string(),
-            channel_id: "channel id1".to_string(),
-            server_url: "https://mydomain.com".to_string(),
-        };
-        assert_eq!(result.method(), Method::POST);
-        assert!(result.required_authentication());
+impl APIMethod for ChannelKickMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
 
-        // Test Json values.
-        if let Some(json) = &result.json_payload() {
-            assert_matches!(
-                json.get("userId"),
-                Some(PayloadValue::String(r#"user_id1"#))
-            );
-            assert_matches!(
-                json.get("roomId"),
-                Some(PayloadValue::String("channel id1"))
-            );
-        } else {
-            panic!("Impossble to get parameters");
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsKick,
+            ..Default::default()
         }
     }
 
-    #[test]
-    fn test_channels_remove_moderator_values() {
-        let result = ChannelRemoveModeratorJob {
-            settings: generate_default_settings(),
-            user_id: "user_id1".to_string(),
-            channel_id: "channel id1".to_string(),
-            server_url: "https://mydomain.com".to_string(),
-        };
-        assert_eq!(result.method(), Method::POST);
-        assert!(result.required_authentication());
+    fn required_authentication(&self) -> bool {
+        true
+    }
 
-        // Test Json values.
-        if let Some(json) = &result.json_payload() {
-            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
-            assert_matches!(
-                json.get("roomId"),
-                Some(PayloadValue::String("channel id1"))
-            );
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelLeaveMethod
+pub struct ChannelLeaveMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelLeaveMethod {
+    fn default() -> Self {
+        ChannelLeaveMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelLeaveMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsLeave,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelRenameMethod
+pub struct ChannelRenameMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub name: String,
+}
+
+impl Default for ChannelRenameMethod {
+    fn default() -> Self {
+        ChannelRenameMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            name: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelRenameMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsRename,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+
+        Some(payload)
+    }
+}
+
+// ChannelSetTopicMethod
+pub struct ChannelSetTopicMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub topic: String,
+}
+
+impl Default for ChannelSetTopicMethod {
+    fn default() -> Self {
+        ChannelSetTopicMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            topic: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelSetTopicMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetTopic,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("topic".to_string(), PayloadValue::String(&self.topic));
+
+        Some(payload)
+    }
+}
+
+// ChannelSetDescriptionMethod
+pub struct ChannelSetDescriptionMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub description: String,
+}
+
+impl Default for ChannelSetDescriptionMethod {
+    fn default() -> Self {
+        ChannelSetDescriptionMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            description: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelSetDescriptionMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetDescription,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert(
+            "description".to_string(),
+            PayloadValue::String(&self.description),
+        );
+
+        Some(payload)
+    }
+}
+
+// ChannelSetAnnouncementMethod
+pub struct ChannelSetAnnouncementMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub announcement: String,
+}
+
+impl Default for ChannelSetAnnouncementMethod {
+    fn default() -> Self {
+        ChannelSetAnnouncementMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            announcement: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelSetAnnouncementMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetAnnouncement,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert(
+            "announcement".to_string(),
+            PayloadValue::String(&self.announcement),
+        );
+
+        Some(payload)
+    }
+}
+
+// ChannelSetTypeMethod
+pub struct ChannelSetTypeMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub is_private: bool,
+}
+
+impl Default for ChannelSetTypeMethod {
+    fn default() -> Self {
+        ChannelSetTypeMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            is_private: false,
+        }
+    }
+}
+
+impl APIMethod for ChannelSetTypeMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetType,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        if self.is_private {
+            payload.insert("type".to_string(), PayloadValue::String("p"));
+        } else {
+            payload.insert("type".to_string(), PayloadValue::String("c"));
+        }
+
+        Some(payload)
+    }
+}
+
+// ChannelSetReadOnlyMethod
+pub struct ChannelSetReadOnlyMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub read_only: bool,
+}
+
+impl Default for ChannelSetReadOnlyMethod {
+    fn default() -> Self {
+        ChannelSetReadOnlyMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            read_only: false,
+        }
+    }
+}
+
+impl APIMethod for ChannelSetReadOnlyMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetReadOnly,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("readOnly".to_string(), PayloadValue::Bool(&self.read_only));
+
+        Some(payload)
+    }
+}
+
+// ChannelArchiveMethod
+pub struct ChannelArchiveMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub archive: bool,
+}
+
+impl Default for ChannelArchiveMethod {
+    fn default() -> Self {
+        ChannelArchiveMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            archive: true,
+        }
+    }
+}
+
+impl APIMethod for ChannelArchiveMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: if self.archive {
+                RestApiUrlType::ChannelsArchive
+            } else {
+                RestApiUrlType::ChannelsUnarchive
+            },
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelMembersMethod
+pub struct ChannelMembersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for ChannelMembersMethod {
+    fn default() -> Self {
+        ChannelMembersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ChannelMembersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsMembers,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelRolesMethod
+pub struct ChannelRolesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelRolesMethod {
+    fn default() -> Self {
+        ChannelRolesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelRolesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsRoles,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelHistoryMethod
+pub struct ChannelHistoryMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub latest: Option<String>,
+    pub oldest: Option<String>,
+    pub count: Option<i64>,
+}
+
+impl Default for ChannelHistoryMethod {
+    fn default() -> Self {
+        ChannelHistoryMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            latest: None,
+            oldest: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ChannelHistoryMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        if let Some(latest) = &self.latest {
+            payload.insert("latest".to_string(), latest.clone());
+        }
+        if let Some(oldest) = &self.oldest {
+            payload.insert("oldest".to_string(), oldest.clone());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsHistory,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelFilesMethod
+pub struct ChannelFilesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelFilesMethod {
+    fn default() -> Self {
+        ChannelFilesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelFilesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsFiles,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelCountersMethod
+pub struct ChannelCountersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelCountersMethod {
+    fn default() -> Self {
+        ChannelCountersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelCountersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsCounters,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelAddOwnerMethod
+pub struct ChannelAddOwnerMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelAddOwnerMethod {
+    fn default() -> Self {
+        ChannelAddOwnerMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelAddOwnerMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsAddOwner,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelRemoveOwnerMethod
+pub struct ChannelRemoveOwnerMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelRemoveOwnerMethod {
+    fn default() -> Self {
+        ChannelRemoveOwnerMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelRemoveOwnerMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsRemoveOwner,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelAddModeratorMethod
+pub struct ChannelAddModeratorMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelAddModeratorMethod {
+    fn default() -> Self {
+        ChannelAddModeratorMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelAddModeratorMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsAddModerator,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelAddLeaderMethod
+pub struct ChannelAddLeaderMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub user_id: String,
+}
+
+impl Default for ChannelAddLeaderMethod {
+    fn default() -> Self {
+        ChannelAddLeaderMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelAddLeaderMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsAddLeader,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// ChannelJoinMethod
+pub struct ChannelJoinMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub join_code: Option<String>,
+}
+
+impl Default for ChannelJoinMethod {
+    fn default() -> Self {
+        ChannelJoinMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            join_code: None,
+        }
+    }
+}
+
+impl APIMethod for ChannelJoinMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsJoin,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        if let Some(join_code) = &self.join_code {
+            payload.insert("joinCode".to_string(), PayloadValue::String(join_code));
+        }
+
+        Some(payload)
+    }
+}
+
+// ChannelSetPurposeMethod
+pub struct ChannelSetPurposeMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub purpose: String,
+}
+
+impl Default for ChannelSetPurposeMethod {
+    fn default() -> Self {
+        ChannelSetPurposeMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            purpose: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelSetPurposeMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetPurpose,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert("purpose".to_string(), PayloadValue::String(&self.purpose));
+
+        Some(payload)
+    }
+}
+
+// ChannelSetJoinCodeMethod
+pub struct ChannelSetJoinCodeMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+    pub join_code: String,
+}
+
+impl Default for ChannelSetJoinCodeMethod {
+    fn default() -> Self {
+        ChannelSetJoinCodeMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+            join_code: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelSetJoinCodeMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsSetJoinCode,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.channel_id));
+        payload.insert(
+            "joinCode".to_string(),
+            PayloadValue::String(&self.join_code),
+        );
+
+        Some(payload)
+    }
+}
+
+// ChannelModeratorsMethod
+pub struct ChannelModeratorsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelModeratorsMethod {
+    fn default() -> Self {
+        ChannelModeratorsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelModeratorsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.channel_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsModerators,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelOnlineMethod
+pub struct ChannelOnlineMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelOnlineMethod {
+    fn default() -> Self {
+        ChannelOnlineMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelOnlineMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("_id".to_string(), self.channel_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsOnline,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// ChannelConvertToTeamMethod
+pub struct ChannelConvertToTeamMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub channel_id: String,
+}
+
+impl Default for ChannelConvertToTeamMethod {
+    fn default() -> Self {
+        ChannelConvertToTeamMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            channel_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ChannelConvertToTeamMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ChannelsConvertToTeam,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert(
+            "channelId".to_string(),
+            PayloadValue::String(&self.channel_id),
+        );
+
+        Some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, ChannelAddLeaderMethod, ChannelAddModeratorMethod, ChannelAddOwnerMethod,
+        ChannelArchiveMethod, ChannelConvertToTeamMethod, ChannelCountersMethod,
+        ChannelCreateMethod, ChannelFilesMethod, ChannelHistoryMethod, ChannelInviteMethod,
+        ChannelJoinMethod, ChannelKickMethod, ChannelLeaveMethod, ChannelMembersMethod,
+        ChannelModeratorsMethod, ChannelOnlineMethod, ChannelRemoveLeaderMethod,
+        ChannelRemoveModeratorJob, ChannelRemoveOwnerMethod, ChannelRenameMethod,
+        ChannelRolesMethod, ChannelSetAnnouncementMethod, ChannelSetDescriptionMethod,
+        ChannelSetJoinCodeMethod, ChannelSetPurposeMethod, ChannelSetReadOnlyMethod,
+        ChannelSetTopicMethod, ChannelSetTypeMethod, PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_channels_create_values() {
+        let result = ChannelCreateMethod {
+            settings: generate_default_settings(),
+            name: "some-channel".to_string(),
+            members: Some(vec!["rocket.cat".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("name"), Some(PayloadValue::String("some-channel")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("some-channel")));
+            assert_matches!(json.get("readOnly"), Some(PayloadValue::Bool(false)));
+            let _list_members = ["rocket.cat"];
+            assert_matches!(
+                json.get("members"),
+                Some(PayloadValue::ListOfString(_list_members))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channels_remove_leader_values() {
+        let result = ChannelRemoveLeaderMethod {
+            settings: generate_default_settings(),
+            user_id: "user_id1".to_string(),
+            channel_id: "channel id1".to_string(),
+            server_url: "https://mydomain.com".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(
+                json.get("userId"),
+                Some(PayloadValue::String(r#"user_id1"#))
+            );
+            assert_matches!(
+                json.get("roomId"),
+                Some(PayloadValue::String("channel id1"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channels_remove_moderator_values() {
+        let result = ChannelRemoveModeratorJob {
+            settings: generate_default_settings(),
+            user_id: "user_id1".to_string(),
+            channel_id: "channel id1".to_string(),
+            server_url: "https://mydomain.com".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+            assert_matches!(
+                json.get("roomId"),
+                Some(PayloadValue::String("channel id1"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_invite_values() {
+        let result = ChannelInviteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.invite"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_kick_values() {
+        let result = ChannelKickMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.kick"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_leave_values() {
+        let result = ChannelLeaveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.leave"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_rename_values() {
+        let result = ChannelRenameMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            name: "new-name".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.rename"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("new-name")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_topic_values() {
+        let result = ChannelSetTopicMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            topic: "my topic".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setTopic"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("topic"), Some(PayloadValue::String("my topic")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_description_values() {
+        let result = ChannelSetDescriptionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            description: "my description".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setDescription"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(
+                json.get("description"),
+                Some(PayloadValue::String("my description"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_announcement_values() {
+        let result = ChannelSetAnnouncementMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            announcement: "my announcement".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setAnnouncement"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(
+                json.get("announcement"),
+                Some(PayloadValue::String("my announcement"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_type_values() {
+        let result = ChannelSetTypeMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            is_private: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setType"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("type"), Some(PayloadValue::String("p")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_read_only_values() {
+        let result = ChannelSetReadOnlyMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            read_only: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setReadOnly"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("readOnly"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_archive_values() {
+        let result = ChannelArchiveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            archive: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.archive"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_members_values() {
+        let result = ChannelMembersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.members"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_roles_values() {
+        let result = ChannelRolesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.roles"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_history_values() {
+        let result = ChannelHistoryMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            latest: Some("2026-01-01T00:00:00.000Z".to_string()),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.history"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert_eq!(
+                query.get("latest"),
+                Some(&"2026-01-01T00:00:00.000Z".to_string())
+            );
+            assert!(query.get("oldest").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_files_values() {
+        let result = ChannelFilesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.files"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_counters_values() {
+        let result = ChannelCountersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.counters"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_unarchive_values() {
+        let result = ChannelArchiveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            archive: false,
+        };
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.unarchive"
+        );
+    }
+
+    #[test]
+    fn test_channel_add_owner_values() {
+        let result = ChannelAddOwnerMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.addOwner"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_remove_owner_values() {
+        let result = ChannelRemoveOwnerMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.removeOwner"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_add_moderator_values() {
+        let result = ChannelAddModeratorMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.addModerator"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_add_leader_values() {
+        let result = ChannelAddLeaderMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.addLeader"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_join_values() {
+        let result = ChannelJoinMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            join_code: Some("code1".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.join"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("joinCode"), Some(PayloadValue::String("code1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_purpose_values() {
+        let result = ChannelSetPurposeMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            purpose: "my purpose".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setPurpose"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(
+                json.get("purpose"),
+                Some(PayloadValue::String("my purpose"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_set_join_code_values() {
+        let result = ChannelSetJoinCodeMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            join_code: "code1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.setJoinCode"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("joinCode"), Some(PayloadValue::String("code1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_moderators_values() {
+        let result = ChannelModeratorsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.moderators"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_online_values() {
+        let result = ChannelOnlineMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.online"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("_id"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_convert_to_team_values() {
+        let result = ChannelConvertToTeamMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.convertToTeam"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(
+                json.get("channelId"),
+                Some(PayloadValue::String("room_id1"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_channel_join_without_code_values() {
+        let result = ChannelJoinMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            channel_id: "room_id1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/channels.join"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert!(json.get("joinCode").is_none());
         } else {
             panic!("Impossble to get parameters");
         }
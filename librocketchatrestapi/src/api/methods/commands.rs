@@ -112,7 +112,12 @@ impl APIMethod for GetListCommandsMethod {
 pub struct RunCommandsMethod {
     pub settings: AuthenticationType,
     pub server_url: String,
-    // FIXME add parameters
+
+    pub command: String,
+    pub params: String,
+    pub room_id: String,
+    pub tmid: Option<String>,
+    pub trigger_id: Option<String>,
 }
 
 impl Default for RunCommandsMethod {
@@ -120,6 +125,11 @@ impl Default for RunCommandsMethod {
         RunCommandsMethod {
             settings: AuthenticationType::None,
             server_url: String::new(),
+            command: String::new(),
+            params: String::new(),
+            room_id: String::new(),
+            tmid: None,
+            trigger_id: None,
         }
     }
 }
@@ -149,10 +159,166 @@ impl APIMethod for RunCommandsMethod {
     }
 
     fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
-        // TODO
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("command".to_string(), PayloadValue::String(&self.command));
+        payload.insert("params".to_string(), PayloadValue::String(&self.params));
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        if let Some(tmid) = &self.tmid {
+            payload.insert("tmid".to_string(), PayloadValue::String(tmid));
+        }
+        if let Some(trigger_id) = &self.trigger_id {
+            payload.insert("triggerId".to_string(), PayloadValue::String(trigger_id));
+        }
+
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+/// Implement GET commands.preview
+pub struct CommandsPreviewMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub command: String,
+    pub params: String,
+    pub room_id: String,
+}
+
+impl Default for CommandsPreviewMethod {
+    fn default() -> Self {
+        CommandsPreviewMethod {
+            settings: AuthenticationType::None,
+            server_url: String::new(),
+            command: String::new(),
+            params: String::new(),
+            room_id: String::new(),
+        }
+    }
+}
+
+impl APIMethod for CommandsPreviewMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CommandsPreview,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("command".to_string(), self.command.clone());
+        payload.insert("params".to_string(), self.params.clone());
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        Some(payload)
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+/// Implement POST commands.preview: run the selected preview item
+pub struct RunCommandPreviewMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub command: String,
+    pub params: String,
+    pub room_id: String,
+    pub tmid: Option<String>,
+    pub trigger_id: Option<String>,
+    pub preview_id: String,
+    pub preview_type: String,
+    pub preview_value: String,
+}
+
+impl Default for RunCommandPreviewMethod {
+    fn default() -> Self {
+        RunCommandPreviewMethod {
+            settings: AuthenticationType::None,
+            server_url: String::new(),
+            command: String::new(),
+            params: String::new(),
+            room_id: String::new(),
+            tmid: None,
+            trigger_id: None,
+            preview_id: String::new(),
+            preview_type: String::new(),
+            preview_value: String::new(),
+        }
+    }
+}
+
+impl APIMethod for RunCommandPreviewMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CommandsPreview,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
         None
     }
 
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("command".to_string(), PayloadValue::String(&self.command));
+        payload.insert("params".to_string(), PayloadValue::String(&self.params));
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        if let Some(tmid) = &self.tmid {
+            payload.insert("tmid".to_string(), PayloadValue::String(tmid));
+        }
+        if let Some(trigger_id) = &self.trigger_id {
+            payload.insert("triggerId".to_string(), PayloadValue::String(trigger_id));
+        }
+        let mut preview_item: HashMap<String, PayloadValue> = HashMap::new();
+        preview_item.insert("id".to_string(), PayloadValue::String(&self.preview_id));
+        preview_item.insert("type".to_string(), PayloadValue::String(&self.preview_type));
+        preview_item.insert(
+            "value".to_string(),
+            PayloadValue::String(&self.preview_value),
+        );
+        payload.insert(
+            "previewItem".to_string(),
+            PayloadValue::Object(preview_item),
+        );
+
+        Some(payload)
+    }
+
     fn domain(&self) -> &str {
         &self.server_url
     }
@@ -160,7 +326,11 @@ impl APIMethod for RunCommandsMethod {
 
 #[cfg(test)]
 mod tests {
-    use crate::methods::{APIMethod, GetCommandsMethod, GetListCommandsMethod, RunCommandsMethod};
+    use crate::methods::{
+        APIMethod, CommandsPreviewMethod, GetCommandsMethod, GetListCommandsMethod, PayloadValue,
+        RunCommandPreviewMethod, RunCommandsMethod,
+    };
+    use assert_matches::assert_matches;
     use reqwest::Method;
 
     use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
@@ -201,10 +371,92 @@ mod tests {
         let result = RunCommandsMethod {
             settings: generate_default_settings(),
             server_url: "https://mydomain.com".to_string(),
+            command: "giphy".to_string(),
+            params: "cat".to_string(),
+            room_id: "room_id1".to_string(),
+            tmid: Some("thread1".to_string()),
+            ..Default::default()
         };
         assert_eq!(result.method(), Method::POST);
         assert!(result.required_authentication());
         assert!(result.query_parameters().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/commands.run"
+        );
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("command"), Some(PayloadValue::String("giphy")));
+            assert_matches!(json.get("params"), Some(PayloadValue::String("cat")));
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("tmid"), Some(PayloadValue::String("thread1")));
+            assert!(json.get("triggerId").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_commands_preview_values() {
+        let result = CommandsPreviewMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            command: "giphy".to_string(),
+            params: "cat".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
         assert!(result.json_payload().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/commands.preview"
+        );
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("command"), Some(&"giphy".to_string()));
+            assert_eq!(query.get("params"), Some(&"cat".to_string()));
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_run_command_preview_values() {
+        let result = RunCommandPreviewMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            command: "giphy".to_string(),
+            params: "cat".to_string(),
+            room_id: "room_id1".to_string(),
+            preview_id: "preview1".to_string(),
+            preview_type: "image".to_string(),
+            preview_value: "https://media.giphy.com/cat.gif".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("command"), Some(PayloadValue::String("giphy")));
+            assert!(json.get("tmid").is_none());
+            if let Some(PayloadValue::Object(item)) = json.get("previewItem") {
+                assert_matches!(item.get("id"), Some(PayloadValue::String("preview1")));
+                assert_matches!(item.get("type"), Some(PayloadValue::String("image")));
+                assert_matches!(
+                    item.get("value"),
+                    Some(PayloadValue::String("https://media.giphy.com/cat.gif"))
+                );
+            } else {
+                panic!("Impossble to get parameters");
+            }
+        } else {
+            panic!("Impossble to get parameters");
+        }
     }
 }
@@ -0,0 +1,374 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+/// Implement custom-user-status.list
+pub struct CustomUserStatusListMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for CustomUserStatusListMethod {
+    fn default() -> Self {
+        CustomUserStatusListMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for CustomUserStatusListMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CustomUserStatusList,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement custom-user-status.create
+pub struct CustomUserStatusCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+    pub status_type: String,
+}
+
+impl Default for CustomUserStatusCreateMethod {
+    fn default() -> Self {
+        CustomUserStatusCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+            status_type: String::default(),
+        }
+    }
+}
+
+impl APIMethod for CustomUserStatusCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CustomUserStatusCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        payload.insert(
+            "statusType".to_string(),
+            PayloadValue::String(&self.status_type),
+        );
+
+        Some(payload)
+    }
+}
+
+/// Implement custom-user-status.update
+pub struct CustomUserStatusUpdateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub identifier: String,
+    pub name: String,
+    pub status_type: String,
+}
+
+impl Default for CustomUserStatusUpdateMethod {
+    fn default() -> Self {
+        CustomUserStatusUpdateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            identifier: String::default(),
+            name: String::default(),
+            status_type: String::default(),
+        }
+    }
+}
+
+impl APIMethod for CustomUserStatusUpdateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CustomUserStatusUpdate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("_id".to_string(), PayloadValue::String(&self.identifier));
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        payload.insert(
+            "statusType".to_string(),
+            PayloadValue::String(&self.status_type),
+        );
+
+        Some(payload)
+    }
+}
+
+/// Implement custom-user-status.delete
+pub struct CustomUserStatusDeleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub identifier: String,
+}
+
+impl Default for CustomUserStatusDeleteMethod {
+    fn default() -> Self {
+        CustomUserStatusDeleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            identifier: String::default(),
+        }
+    }
+}
+
+impl APIMethod for CustomUserStatusDeleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::CustomUserStatusDelete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert(
+            "customUserStatusId".to_string(),
+            PayloadValue::String(&self.identifier),
+        );
+
+        Some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, CustomUserStatusCreateMethod, CustomUserStatusDeleteMethod,
+        CustomUserStatusListMethod, CustomUserStatusUpdateMethod, PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_custom_user_status_list_values() {
+        let result = CustomUserStatusListMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            offset: Some(0),
+            count: Some(50),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/custom-user-status.list"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("offset"), Some(&"0".to_string()));
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_custom_user_status_create_values() {
+        let result = CustomUserStatusCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "In a meeting".to_string(),
+            status_type: "busy".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/custom-user-status.create"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("name"), Some(PayloadValue::String("In a meeting")));
+            assert_matches!(json.get("statusType"), Some(PayloadValue::String("busy")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_custom_user_status_update_values() {
+        let result = CustomUserStatusUpdateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            identifier: "status1".to_string(),
+            name: "In a meeting".to_string(),
+            status_type: "busy".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/custom-user-status.update"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("_id"), Some(PayloadValue::String("status1")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("In a meeting")));
+            assert_matches!(json.get("statusType"), Some(PayloadValue::String("busy")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_custom_user_status_delete_values() {
+        let result = CustomUserStatusDeleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            identifier: "status1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/custom-user-status.delete"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(
+                json.get("customUserStatusId"),
+                Some(PayloadValue::String("status1"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
@@ -0,0 +1,514 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::base::{MultipartFile, MultipartPayload};
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+// Image uploaded by emoji-custom.create and emoji-custom.update
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmojiCustomImage {
+    pub file_name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+impl EmojiCustomImage {
+    fn multipart_file(&self) -> MultipartFile {
+        MultipartFile {
+            field_name: "emoji".to_string(),
+            file_name: self.file_name.clone(),
+            mime_type: self.mime_type.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+/// Implement emoji-custom.list
+pub struct EmojiCustomListMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // ISO date, only emojis changed since this date are returned
+    pub updated_since: Option<String>,
+}
+
+impl Default for EmojiCustomListMethod {
+    fn default() -> Self {
+        EmojiCustomListMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            updated_since: None,
+        }
+    }
+}
+
+impl APIMethod for EmojiCustomListMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        if let Some(updated_since) = &self.updated_since {
+            payload.insert("updatedSince".to_string(), updated_since.clone());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::LoadEmojiCustom,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement emoji-custom.all
+pub struct EmojiCustomAllMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for EmojiCustomAllMethod {
+    fn default() -> Self {
+        EmojiCustomAllMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for EmojiCustomAllMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::EmojiCustomAll,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement emoji-custom.create
+pub struct EmojiCustomCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub image: EmojiCustomImage,
+}
+
+impl Default for EmojiCustomCreateMethod {
+    fn default() -> Self {
+        EmojiCustomCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+            aliases: Vec::new(),
+            image: EmojiCustomImage::default(),
+        }
+    }
+}
+
+impl APIMethod for EmojiCustomCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::EmojiCustomCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn multipart_payload(&self) -> Option<MultipartPayload> {
+        Some(MultipartPayload {
+            fields: vec![
+                ("name".to_string(), self.name.clone()),
+                ("aliases".to_string(), self.aliases.join(",")),
+            ],
+            files: vec![self.image.multipart_file()],
+        })
+    }
+}
+
+/// Implement emoji-custom.update
+pub struct EmojiCustomUpdateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub identifier: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+    // Image is kept when it's not set
+    pub image: Option<EmojiCustomImage>,
+}
+
+impl Default for EmojiCustomUpdateMethod {
+    fn default() -> Self {
+        EmojiCustomUpdateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            identifier: String::default(),
+            name: String::default(),
+            aliases: Vec::new(),
+            image: None,
+        }
+    }
+}
+
+impl APIMethod for EmojiCustomUpdateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::EmojiCustomUpdate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn multipart_payload(&self) -> Option<MultipartPayload> {
+        Some(MultipartPayload {
+            fields: vec![
+                ("_id".to_string(), self.identifier.clone()),
+                ("name".to_string(), self.name.clone()),
+                ("aliases".to_string(), self.aliases.join(",")),
+            ],
+            files: self
+                .image
+                .iter()
+                .map(|image| image.multipart_file())
+                .collect(),
+        })
+    }
+}
+
+/// Implement emoji-custom.delete
+pub struct EmojiCustomDeleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub emoji_id: String,
+}
+
+impl Default for EmojiCustomDeleteMethod {
+    fn default() -> Self {
+        EmojiCustomDeleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            emoji_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for EmojiCustomDeleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::EmojiCustomDelete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("emojiId".to_string(), PayloadValue::String(&self.emoji_id));
+        Some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, EmojiCustomAllMethod, EmojiCustomCreateMethod, EmojiCustomDeleteMethod,
+        EmojiCustomImage, EmojiCustomListMethod, EmojiCustomUpdateMethod, PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    fn generate_image() -> EmojiCustomImage {
+        EmojiCustomImage {
+            file_name: "kde.png".to_string(),
+            mime_type: "image/png".to_string(),
+            data: b"PNGDATA".to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_emoji_custom_list_values() {
+        let result = EmojiCustomListMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            updated_since: Some("2026-03-01T10:00:00.000Z".to_string()),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/emoji-custom.list"
+        );
+        assert!(result.json_payload().is_none());
+        assert!(result.multipart_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(
+                query.get("updatedSince"),
+                Some(&"2026-03-01T10:00:00.000Z".to_string())
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_emoji_custom_all_values() {
+        let result = EmojiCustomAllMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            offset: Some(0),
+            count: Some(100),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/emoji-custom.all"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("offset"), Some(&"0".to_string()));
+            assert_eq!(query.get("count"), Some(&"100".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_emoji_custom_create_values() {
+        let result = EmojiCustomCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "kde".to_string(),
+            aliases: vec!["konqi".to_string(), "katie".to_string()],
+            image: generate_image(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/emoji-custom.create"
+        );
+        assert!(result.json_payload().is_none());
+        assert!(result.query_parameters().is_none());
+
+        // Test multipart values.
+        if let Some(multipart) = &result.multipart_payload() {
+            assert_eq!(
+                multipart.fields,
+                vec![
+                    ("name".to_string(), "kde".to_string()),
+                    ("aliases".to_string(), "konqi,katie".to_string())
+                ]
+            );
+            assert_eq!(multipart.files.len(), 1);
+            assert_eq!(multipart.files[0].field_name, "emoji");
+            assert_eq!(multipart.files[0].file_name, "kde.png");
+            assert!(multipart.form().is_ok());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_emoji_custom_update_values() {
+        let mut result = EmojiCustomUpdateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            identifier: "emojiid".to_string(),
+            name: "kde".to_string(),
+            aliases: Vec::new(),
+            image: None,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/emoji-custom.update"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test multipart values.
+        if let Some(multipart) = &result.multipart_payload() {
+            assert_eq!(
+                multipart.fields,
+                vec![
+                    ("_id".to_string(), "emojiid".to_string()),
+                    ("name".to_string(), "kde".to_string()),
+                    ("aliases".to_string(), String::default())
+                ]
+            );
+            assert!(multipart.files.is_empty());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        result.image = Some(generate_image());
+        assert_eq!(result.multipart_payload().unwrap().files.len(), 1);
+    }
+
+    #[test]
+    fn test_emoji_custom_delete_values() {
+        let result = EmojiCustomDeleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            emoji_id: "emojiid".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/emoji-custom.delete"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("emojiId"), Some(PayloadValue::String("emojiid")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
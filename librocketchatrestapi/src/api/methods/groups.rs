@@ -0,0 +1,1394 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+// GroupCreateMethod
+pub struct GroupCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+    pub members: Option<Vec<String>>,
+    pub read_only: Option<bool>,
+}
+
+impl Default for GroupCreateMethod {
+    fn default() -> Self {
+        GroupCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+            members: None,
+            read_only: Some(false),
+        }
+    }
+}
+
+impl APIMethod for GroupCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        if let Some(members) = &self.members {
+            let list = members.iter().map(|el| el.as_ref()).collect();
+            payload.insert("members".to_string(), PayloadValue::ListOfString(list));
+        }
+        if let Some(read_only) = &self.read_only {
+            payload.insert("readOnly".to_string(), PayloadValue::Bool(read_only));
+        }
+
+        Some(payload)
+    }
+}
+
+// GroupInviteMethod
+pub struct GroupInviteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub user_id: String,
+}
+
+impl Default for GroupInviteMethod {
+    fn default() -> Self {
+        GroupInviteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupInviteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsInvite,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// GroupKickMethod
+pub struct GroupKickMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub user_id: String,
+}
+
+impl Default for GroupKickMethod {
+    fn default() -> Self {
+        GroupKickMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupKickMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsKick,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+
+        Some(payload)
+    }
+}
+
+// GroupLeaveMethod
+pub struct GroupLeaveMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for GroupLeaveMethod {
+    fn default() -> Self {
+        GroupLeaveMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupLeaveMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsLeave,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+// GroupRenameMethod
+pub struct GroupRenameMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub name: String,
+}
+
+impl Default for GroupRenameMethod {
+    fn default() -> Self {
+        GroupRenameMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            name: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupRenameMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsRename,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+
+        Some(payload)
+    }
+}
+
+// GroupSetTopicMethod
+pub struct GroupSetTopicMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub topic: String,
+}
+
+impl Default for GroupSetTopicMethod {
+    fn default() -> Self {
+        GroupSetTopicMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            topic: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupSetTopicMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsSetTopic,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("topic".to_string(), PayloadValue::String(&self.topic));
+
+        Some(payload)
+    }
+}
+
+// GroupSetDescriptionMethod
+pub struct GroupSetDescriptionMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub description: String,
+}
+
+impl Default for GroupSetDescriptionMethod {
+    fn default() -> Self {
+        GroupSetDescriptionMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            description: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupSetDescriptionMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsSetDescription,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert(
+            "description".to_string(),
+            PayloadValue::String(&self.description),
+        );
+
+        Some(payload)
+    }
+}
+
+// GroupSetAnnouncementMethod
+pub struct GroupSetAnnouncementMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub announcement: String,
+}
+
+impl Default for GroupSetAnnouncementMethod {
+    fn default() -> Self {
+        GroupSetAnnouncementMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            announcement: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupSetAnnouncementMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsSetAnnouncement,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert(
+            "announcement".to_string(),
+            PayloadValue::String(&self.announcement),
+        );
+
+        Some(payload)
+    }
+}
+
+// GroupSetTypeMethod
+pub struct GroupSetTypeMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub is_private: bool,
+}
+
+impl Default for GroupSetTypeMethod {
+    fn default() -> Self {
+        GroupSetTypeMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            is_private: false,
+        }
+    }
+}
+
+impl APIMethod for GroupSetTypeMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsSetType,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        if self.is_private {
+            payload.insert("type".to_string(), PayloadValue::String("p"));
+        } else {
+            payload.insert("type".to_string(), PayloadValue::String("c"));
+        }
+
+        Some(payload)
+    }
+}
+
+// GroupSetReadOnlyMethod
+pub struct GroupSetReadOnlyMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub read_only: bool,
+}
+
+impl Default for GroupSetReadOnlyMethod {
+    fn default() -> Self {
+        GroupSetReadOnlyMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            read_only: false,
+        }
+    }
+}
+
+impl APIMethod for GroupSetReadOnlyMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsSetReadOnly,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("readOnly".to_string(), PayloadValue::Bool(&self.read_only));
+
+        Some(payload)
+    }
+}
+
+// GroupArchiveMethod
+pub struct GroupArchiveMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub archive: bool,
+}
+
+impl Default for GroupArchiveMethod {
+    fn default() -> Self {
+        GroupArchiveMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            archive: true,
+        }
+    }
+}
+
+impl APIMethod for GroupArchiveMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: if self.archive {
+                RestApiUrlType::GroupsArchive
+            } else {
+                RestApiUrlType::GroupsUnarchive
+            },
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+// GroupMembersMethod
+pub struct GroupMembersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for GroupMembersMethod {
+    fn default() -> Self {
+        GroupMembersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for GroupMembersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsMembers,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// GroupRolesMethod
+pub struct GroupRolesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for GroupRolesMethod {
+    fn default() -> Self {
+        GroupRolesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupRolesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsRoles,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// GroupHistoryMethod
+pub struct GroupHistoryMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub latest: Option<String>,
+    pub oldest: Option<String>,
+    pub count: Option<i64>,
+}
+
+impl Default for GroupHistoryMethod {
+    fn default() -> Self {
+        GroupHistoryMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            latest: None,
+            oldest: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for GroupHistoryMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(latest) = &self.latest {
+            payload.insert("latest".to_string(), latest.clone());
+        }
+        if let Some(oldest) = &self.oldest {
+            payload.insert("oldest".to_string(), oldest.clone());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsHistory,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// GroupFilesMethod
+pub struct GroupFilesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for GroupFilesMethod {
+    fn default() -> Self {
+        GroupFilesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupFilesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsFiles,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+// GroupCountersMethod
+pub struct GroupCountersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for GroupCountersMethod {
+    fn default() -> Self {
+        GroupCountersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for GroupCountersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::GroupsCounters,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, GroupArchiveMethod, GroupCountersMethod, GroupCreateMethod, GroupFilesMethod,
+        GroupHistoryMethod, GroupInviteMethod, GroupKickMethod, GroupLeaveMethod,
+        GroupMembersMethod, GroupRenameMethod, GroupRolesMethod, GroupSetAnnouncementMethod,
+        GroupSetDescriptionMethod, GroupSetReadOnlyMethod, GroupSetTopicMethod, GroupSetTypeMethod,
+        PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_group_create_values() {
+        let result = GroupCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "some-group".to_string(),
+            members: Some(vec!["rocket.cat".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.create"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("name"), Some(PayloadValue::String("some-group")));
+            assert_matches!(
+                json.get("members"),
+                Some(PayloadValue::ListOfString(list)) if list == &vec!["rocket.cat"]
+            );
+            assert_matches!(json.get("readOnly"), Some(PayloadValue::Bool(false)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_invite_values() {
+        let result = GroupInviteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.invite"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_kick_values() {
+        let result = GroupKickMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            user_id: "user_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.kick"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_leave_values() {
+        let result = GroupLeaveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.leave"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_rename_values() {
+        let result = GroupRenameMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            name: "new-name".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.rename"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("new-name")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_set_topic_values() {
+        let result = GroupSetTopicMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            topic: "my topic".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.setTopic"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("topic"), Some(PayloadValue::String("my topic")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_set_description_values() {
+        let result = GroupSetDescriptionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            description: "my description".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.setDescription"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(
+                json.get("description"),
+                Some(PayloadValue::String("my description"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_set_announcement_values() {
+        let result = GroupSetAnnouncementMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            announcement: "my announcement".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.setAnnouncement"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(
+                json.get("announcement"),
+                Some(PayloadValue::String("my announcement"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_set_type_values() {
+        let result = GroupSetTypeMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            is_private: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.setType"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("type"), Some(PayloadValue::String("p")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_set_read_only_values() {
+        let result = GroupSetReadOnlyMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            read_only: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.setReadOnly"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("readOnly"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_archive_values() {
+        let result = GroupArchiveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            archive: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.archive"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_members_values() {
+        let result = GroupMembersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.members"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_roles_values() {
+        let result = GroupRolesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.roles"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_history_values() {
+        let result = GroupHistoryMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            latest: Some("2026-01-01T00:00:00.000Z".to_string()),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.history"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert_eq!(
+                query.get("latest"),
+                Some(&"2026-01-01T00:00:00.000Z".to_string())
+            );
+            assert!(query.get("oldest").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_files_values() {
+        let result = GroupFilesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.files"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_counters_values() {
+        let result = GroupCountersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.counters"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_group_unarchive_values() {
+        let result = GroupArchiveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            archive: false,
+        };
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/groups.unarchive"
+        );
+    }
+}
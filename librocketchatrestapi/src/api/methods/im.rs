@@ -0,0 +1,833 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+/// Implement im.create
+/// One username opens a direct message, several usernames create a multi-user direct message.
+pub struct ImCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub usernames: Vec<String>,
+    pub exclude_self: Option<bool>,
+}
+
+impl Default for ImCreateMethod {
+    fn default() -> Self {
+        ImCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            usernames: Vec::default(),
+            exclude_self: None,
+        }
+    }
+}
+
+impl APIMethod for ImCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        if self.usernames.len() == 1 {
+            payload.insert(
+                "username".to_string(),
+                PayloadValue::String(&self.usernames[0]),
+            );
+        } else {
+            // Server expects a comma separated list here.
+            let usernames = self.usernames.iter().map(|el| el.as_ref()).collect();
+            payload.insert(
+                "usernames".to_string(),
+                PayloadValue::CommaSeparatedList(usernames),
+            );
+        }
+        if let Some(exclude_self) = &self.exclude_self {
+            payload.insert("excludeSelf".to_string(), PayloadValue::Bool(exclude_self));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement im.open
+pub struct ImOpenMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for ImOpenMethod {
+    fn default() -> Self {
+        ImOpenMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ImOpenMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImOpen,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+/// Implement im.close
+pub struct ImCloseMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for ImCloseMethod {
+    fn default() -> Self {
+        ImCloseMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ImCloseMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImClose,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+/// Implement im.delete
+pub struct ImDeleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+}
+
+impl Default for ImDeleteMethod {
+    fn default() -> Self {
+        ImDeleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ImDeleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImDelete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+/// Implement im.setTopic
+pub struct ImSetTopicMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub topic: String,
+}
+
+impl Default for ImSetTopicMethod {
+    fn default() -> Self {
+        ImSetTopicMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            topic: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ImSetTopicMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImSetTopic,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("topic".to_string(), PayloadValue::String(&self.topic));
+
+        Some(payload)
+    }
+}
+
+/// Implement im.history
+pub struct ImHistoryMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub latest: Option<String>,
+    pub oldest: Option<String>,
+    pub count: Option<i64>,
+}
+
+impl Default for ImHistoryMethod {
+    fn default() -> Self {
+        ImHistoryMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            latest: None,
+            oldest: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ImHistoryMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(latest) = &self.latest {
+            payload.insert("latest".to_string(), latest.clone());
+        }
+        if let Some(oldest) = &self.oldest {
+            payload.insert("oldest".to_string(), oldest.clone());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImHistory,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement im.messages
+pub struct ImMessagesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for ImMessagesMethod {
+    fn default() -> Self {
+        ImMessagesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ImMessagesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImMessages,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement im.files
+pub struct ImFilesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for ImFilesMethod {
+    fn default() -> Self {
+        ImFilesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ImFilesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImFiles,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement im.members
+pub struct ImMembersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for ImMembersMethod {
+    fn default() -> Self {
+        ImMembersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for ImMembersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("roomId".to_string(), self.room_id.clone());
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ImMembers,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, ImCloseMethod, ImCreateMethod, ImDeleteMethod, ImFilesMethod, ImHistoryMethod,
+        ImMembersMethod, ImMessagesMethod, ImOpenMethod, ImSetTopicMethod, PayloadValue,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_im_create_values() {
+        let result = ImCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            usernames: vec!["foo".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.create"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("username"), Some(PayloadValue::String("foo")));
+            assert!(json.get("usernames").is_none());
+            assert!(json.get("excludeSelf").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_create_multi_users_values() {
+        let result = ImCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            usernames: vec!["foo".to_string(), "bla".to_string()],
+            exclude_self: Some(true),
+        };
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert!(json.get("username").is_none());
+            assert_matches!(
+                json.get("usernames"),
+                Some(PayloadValue::CommaSeparatedList(list)) if list == &vec!["foo", "bla"]
+            );
+            assert_matches!(json.get("excludeSelf"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_open_values() {
+        let result = ImOpenMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.open"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_close_values() {
+        let result = ImCloseMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.close"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_delete_values() {
+        let result = ImDeleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.delete"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_set_topic_values() {
+        let result = ImSetTopicMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            topic: "my topic".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.setTopic"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id1")));
+            assert_matches!(json.get("topic"), Some(PayloadValue::String("my topic")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_history_values() {
+        let result = ImHistoryMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            latest: Some("2026-01-01T00:00:00.000Z".to_string()),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.history"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert_eq!(
+                query.get("latest"),
+                Some(&"2026-01-01T00:00:00.000Z".to_string())
+            );
+            assert!(query.get("oldest").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_messages_values() {
+        let result = ImMessagesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.messages"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_files_values() {
+        let result = ImFilesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.files"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_im_members_values() {
+        let result = ImMembersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id1".to_string(),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/im.members"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("roomId"), Some(&"room_id1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
@@ -3,29 +3,93 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
-pub use base::{APIMethod, PayloadValue};
-pub use channels::{ChannelCreateMethod, ChannelRemoveLeaderMethod, ChannelRemoveModeratorJob};
+pub use apps::{AppsUiInteractionMethod, UiInteractionType};
+pub use autotranslate::{
+    AutoTranslateGetSupportedLanguagesMethod, AutoTranslateSaveSettingsMethod,
+    AutoTranslateSetting, AutoTranslateTranslateMessageMethod,
+};
+pub use base::{APIMethod, MultipartFile, MultipartPayload, PayloadValue};
+pub use channels::{
+    ChannelAddLeaderMethod, ChannelAddModeratorMethod, ChannelAddOwnerMethod, ChannelArchiveMethod,
+    ChannelConvertToTeamMethod, ChannelCountersMethod, ChannelCreateMethod, ChannelFilesMethod,
+    ChannelHistoryMethod, ChannelInviteMethod, ChannelJoinMethod, ChannelKickMethod,
+    ChannelLeaveMethod, ChannelMembersMethod, ChannelModeratorsMethod, ChannelOnlineMethod,
+    ChannelRemoveLeaderMethod, ChannelRemoveModeratorJob, ChannelRemoveOwnerMethod,
+    ChannelRenameMethod, ChannelRolesMethod, ChannelSetAnnouncementMethod,
+    ChannelSetDescriptionMethod, ChannelSetJoinCodeMethod, ChannelSetPurposeMethod,
+    ChannelSetReadOnlyMethod, ChannelSetTopicMethod, ChannelSetTypeMethod,
+};
 pub use chat::{
     DeleteMessageMethod, FollowMessageMethod, IgnoreUserMethod, PinMessageMethod,
     PostMessageMethod, SnippetedMessagesMethod, StarMessageMethod, UnStarMessageMethod,
 };
-pub use rooms::{ChangeRoomFavoriteMethod, GetDiscussionsMethod, GetRoomsMethod};
+pub use groups::{
+    GroupArchiveMethod, GroupCountersMethod, GroupCreateMethod, GroupFilesMethod,
+    GroupHistoryMethod, GroupInviteMethod, GroupKickMethod, GroupLeaveMethod, GroupMembersMethod,
+    GroupRenameMethod, GroupRolesMethod, GroupSetAnnouncementMethod, GroupSetDescriptionMethod,
+    GroupSetReadOnlyMethod, GroupSetTopicMethod, GroupSetTypeMethod,
+};
+pub use im::{
+    ImCloseMethod, ImCreateMethod, ImDeleteMethod, ImFilesMethod, ImHistoryMethod, ImMembersMethod,
+    ImMessagesMethod, ImOpenMethod, ImSetTopicMethod,
+};
+pub use rooms::{
+    ChangeRoomFavoriteMethod, GetDiscussionsMethod, GetRoomsMethod,
+    RoomsAutocompleteAvailableForTeamsMethod, RoomsAutocompleteChannelAndPrivateMethod,
+    RoomsCreateDiscussionMethod, RoomsSaveNotificationInfo, RoomsSaveNotificationMethod,
+};
 
-pub use commands::{GetCommandsMethod, GetListCommandsMethod, RunCommandsMethod};
+pub use commands::{
+    CommandsPreviewMethod, GetCommandsMethod, GetListCommandsMethod, RunCommandPreviewMethod,
+    RunCommandsMethod,
+};
+pub use customuserstatus::{
+    CustomUserStatusCreateMethod, CustomUserStatusDeleteMethod, CustomUserStatusListMethod,
+    CustomUserStatusUpdateMethod,
+};
+pub use emojicustom::{
+    EmojiCustomAllMethod, EmojiCustomCreateMethod, EmojiCustomDeleteMethod, EmojiCustomImage,
+    EmojiCustomListMethod, EmojiCustomUpdateMethod,
+};
 pub use invite::{InviteListMethod, SendInvitationEmailMethod, ValidateInviteTokenMethod};
-pub use teams::{GetTeamInfoMethod, GetTeamsListMethod};
+pub use teams::{
+    GetTeamInfoMethod, GetTeamsListMethod, TeamAddMembersMethod, TeamAddRoomsMethod,
+    TeamAutocompleteMethod, TeamConvertToChannelMethod, TeamCreateMethod, TeamDeleteMethod,
+    TeamLeaveMethod, TeamListRoomsMethod, TeamListRoomsOfUserMethod, TeamMembersMethod,
+    TeamRemoveMemberMethod, TeamRemoveRoomMethod, TeamUpdateMemberMethod, TeamUpdateRoomMethod,
+};
+pub use users::{
+    AvatarSource, ForgotPasswordMethod, UsersAutocompleteMethod, UsersDeleteOwnAccountMethod,
+    UsersGetStatusMethod, UsersGetUsernameSuggestionMethod, UsersRegisterMethod,
+    UsersResetAvatarMethod, UsersSetAvatarMethod, UsersSetPreferencesInfo,
+    UsersSetPreferencesMethod, UsersSetStatusMethod, UsersUpdateOwnBasicInfoMethod,
+};
 
 pub use licenses::{LicensesIsEnterpriseMethod, LicensesListMethod};
 pub use misc::{OwnMethod, StatisticsMethod};
 pub use moderation::{
     GetModerationDismissUserReports, GetModerationReportInfo, GetModerationReports,
 };
-pub use permissions::PermissionsListAllMethod;
+pub use permissions::{PermissionsListAllMethod, PermissionsUpdateMethod};
 pub use restapiutils::{RestApiUrlExtensionType, RestApiUrlType};
+pub use roles::{
+    RolesAddUserToRoleMethod, RolesCreateMethod, RolesDeleteMethod, RolesGetUsersInRoleMethod,
+    RolesListMethod, RolesRemoveUserFromRoleMethod, RolesSyncMethod, RolesUpdateMethod,
+};
+pub use settings::{
+    AdminSettingValue, SettingsMethod, SettingsOauthMethod, SettingsPublicMethod,
+    UpdateAdminSettingsMethod,
+};
+mod apps;
+mod autotranslate;
 mod base;
 mod channels;
 mod chat;
 mod commands;
+mod customuserstatus;
+mod emojicustom;
+mod groups;
+mod im;
 mod invite;
 mod licenses;
 mod misc;
@@ -33,5 +97,8 @@ mod moderation;
 mod permissions;
 mod personalaccesstoken;
 mod restapiutils;
+mod roles;
 mod rooms;
+mod settings;
 mod teams;
+mod users;
@@ -59,9 +59,81 @@ impl APIMethod for PermissionsListAllMethod {
     }
 }
 
+/// Implement permissions.update
+pub struct PermissionsUpdateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // Permission identifier and its new roles
+    pub permissions: Vec<(String, Vec<String>)>,
+}
+
+impl Default for PermissionsUpdateMethod {
+    fn default() -> Self {
+        PermissionsUpdateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            permissions: Vec::default(),
+        }
+    }
+}
+
+impl APIMethod for PermissionsUpdateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::PermissionsUpdate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        let permissions = self
+            .permissions
+            .iter()
+            .map(|(identifier, roles)| {
+                let mut permission: HashMap<String, PayloadValue> = HashMap::new();
+                permission.insert("_id".to_string(), PayloadValue::String(identifier));
+                permission.insert(
+                    "roles".to_string(),
+                    PayloadValue::ListOfString(roles.iter().map(|r| r.as_str()).collect()),
+                );
+                permission
+            })
+            .collect();
+        payload.insert(
+            "permissions".to_string(),
+            PayloadValue::ListOfObject(permissions),
+        );
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::methods::{APIMethod, PermissionsListAllMethod};
+    use crate::api::methods::base::PayloadValue;
+    use crate::methods::{APIMethod, PermissionsListAllMethod, PermissionsUpdateMethod};
+    use assert_matches::assert_matches;
     use reqwest::Method;
 
     use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
@@ -83,4 +155,42 @@ mod tests {
         assert!(result.query_parameters().is_none());
         assert!(result.json_payload().is_none());
     }
+
+    #[test]
+    fn test_update_permissions_values() {
+        let result = PermissionsUpdateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            permissions: vec![(
+                "pin-message".to_string(),
+                vec!["admin".to_string(), "owner".to_string()],
+            )],
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/permissions.update"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            if let Some(PayloadValue::ListOfObject(permissions)) = json.get("permissions") {
+                assert_eq!(permissions.len(), 1);
+                assert_matches!(
+                    permissions[0].get("_id"),
+                    Some(PayloadValue::String("pin-message"))
+                );
+                assert_matches!(
+                    permissions[0].get("roles"),
+                    Some(PayloadValue::ListOfString(list)) if list == &vec!["admin", "owner"]
+                );
+            } else {
+                panic!("Impossble to get parameters");
+            }
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
 }
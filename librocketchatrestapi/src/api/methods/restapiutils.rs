@@ -33,6 +33,26 @@ pub fn generate_url(
     url_str
 }
 
+// Generate a json selector as {"key":"value"}, used by autocomplete methods.
+// Value is escaped as a json string, control characters included.
+pub fn generate_selector(key: &str, value: &str) -> String {
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            _ => escaped.push(c),
+        }
+    }
+    format!("{{\"{}\":\"{}\"}}", key, escaped)
+}
+
 #[derive(Debug)]
 pub enum RestApiUrlExtensionType {
     NoExtension = 0,
@@ -793,6 +813,7 @@ impl RestApiUrlType {
 
 #[cfg(test)]
 mod tests {
+    use crate::methods::restapiutils::generate_selector;
     use crate::methods::restapiutils::generate_url;
     use crate::methods::restapiutils::RestApiUrlExtensionType;
     use crate::methods::restapiutils::RestApiUrlType;
@@ -4088,4 +4109,18 @@ mod tests {
             ("http://www.kde.org/api/v1/rooms.membersOrderedByRole")
         );
     }
+
+    #[test]
+    fn test_generate_selector() {
+        assert_eq!(generate_selector("term", "foo"), "{\"term\":\"foo\"}");
+        assert_eq!(generate_selector("name", ""), "{\"name\":\"\"}");
+        assert_eq!(
+            generate_selector("term", "f\"o\\o"),
+            "{\"term\":\"f\\\"o\\\\o\"}"
+        );
+        assert_eq!(
+            generate_selector("term", "a\tb\r\nc\u{1}\u{1f}\u{7f}é"),
+            "{\"term\":\"a\\tb\\r\\nc\\u0001\\u001f\u{7f}é\"}"
+        );
+    }
 }
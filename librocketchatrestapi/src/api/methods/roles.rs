@@ -0,0 +1,771 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+/// Implement roles.list
+pub struct RolesListMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+}
+
+impl Default for RolesListMethod {
+    fn default() -> Self {
+        RolesListMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+        }
+    }
+}
+
+impl APIMethod for RolesListMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesList,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement roles.sync
+pub struct RolesSyncMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub updated_since: String,
+}
+
+impl Default for RolesSyncMethod {
+    fn default() -> Self {
+        RolesSyncMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            updated_since: String::default(),
+        }
+    }
+}
+
+impl APIMethod for RolesSyncMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("updatedSince".to_string(), self.updated_since.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesSync,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement roles.create
+pub struct RolesCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+    pub description: String,
+    // "Users" for global roles, "Subscriptions" for room roles
+    pub scope: String,
+    pub mandatory_2fa: bool,
+}
+
+impl Default for RolesCreateMethod {
+    fn default() -> Self {
+        RolesCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+            description: String::default(),
+            scope: "Users".to_string(),
+            mandatory_2fa: false,
+        }
+    }
+}
+
+impl APIMethod for RolesCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        payload.insert(
+            "description".to_string(),
+            PayloadValue::String(&self.description),
+        );
+        payload.insert("scope".to_string(), PayloadValue::String(&self.scope));
+        payload.insert(
+            "mandatory2fa".to_string(),
+            PayloadValue::Bool(&self.mandatory_2fa),
+        );
+
+        Some(payload)
+    }
+}
+
+/// Implement roles.update
+pub struct RolesUpdateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub role_id: String,
+    pub name: String,
+    pub description: String,
+    // "Users" for global roles, "Subscriptions" for room roles
+    pub scope: String,
+    pub mandatory_2fa: bool,
+}
+
+impl Default for RolesUpdateMethod {
+    fn default() -> Self {
+        RolesUpdateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            role_id: String::default(),
+            name: String::default(),
+            description: String::default(),
+            scope: "Users".to_string(),
+            mandatory_2fa: false,
+        }
+    }
+}
+
+impl APIMethod for RolesUpdateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesUpdate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roleId".to_string(), PayloadValue::String(&self.role_id));
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        payload.insert(
+            "description".to_string(),
+            PayloadValue::String(&self.description),
+        );
+        payload.insert("scope".to_string(), PayloadValue::String(&self.scope));
+        payload.insert(
+            "mandatory2fa".to_string(),
+            PayloadValue::Bool(&self.mandatory_2fa),
+        );
+
+        Some(payload)
+    }
+}
+
+/// Implement roles.delete
+pub struct RolesDeleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub role_id: String,
+}
+
+impl Default for RolesDeleteMethod {
+    fn default() -> Self {
+        RolesDeleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            role_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for RolesDeleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesDelete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roleId".to_string(), PayloadValue::String(&self.role_id));
+
+        Some(payload)
+    }
+}
+
+/// Implement roles.addUserToRole
+pub struct RolesAddUserToRoleMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub role_id: String,
+    pub username: String,
+    // Room of a subscription role (owner, moderator, leader)
+    pub room_id: Option<String>,
+}
+
+impl Default for RolesAddUserToRoleMethod {
+    fn default() -> Self {
+        RolesAddUserToRoleMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            role_id: String::default(),
+            username: String::default(),
+            room_id: None,
+        }
+    }
+}
+
+impl APIMethod for RolesAddUserToRoleMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesAddUserToRole,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roleId".to_string(), PayloadValue::String(&self.role_id));
+        payload.insert("username".to_string(), PayloadValue::String(&self.username));
+        if let Some(room_id) = &self.room_id {
+            payload.insert("roomId".to_string(), PayloadValue::String(room_id));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement roles.removeUserFromRole
+pub struct RolesRemoveUserFromRoleMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub role_id: String,
+    pub username: String,
+    // Room of a subscription role (owner, moderator, leader)
+    pub room_id: Option<String>,
+}
+
+impl Default for RolesRemoveUserFromRoleMethod {
+    fn default() -> Self {
+        RolesRemoveUserFromRoleMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            role_id: String::default(),
+            username: String::default(),
+            room_id: None,
+        }
+    }
+}
+
+impl APIMethod for RolesRemoveUserFromRoleMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesRemoveUserFromRole,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roleId".to_string(), PayloadValue::String(&self.role_id));
+        payload.insert("username".to_string(), PayloadValue::String(&self.username));
+        if let Some(room_id) = &self.room_id {
+            payload.insert("scope".to_string(), PayloadValue::String(room_id));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement roles.getUsersInRole
+pub struct RolesGetUsersInRoleMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub role: String,
+    pub room_id: Option<String>,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for RolesGetUsersInRoleMethod {
+    fn default() -> Self {
+        RolesGetUsersInRoleMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            role: String::default(),
+            room_id: None,
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for RolesGetUsersInRoleMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("role".to_string(), self.role.clone());
+        if let Some(room_id) = &self.room_id {
+            payload.insert("roomId".to_string(), room_id.clone());
+        }
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RolesGetUsersInRole,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, PayloadValue, RolesAddUserToRoleMethod, RolesCreateMethod, RolesDeleteMethod,
+        RolesGetUsersInRoleMethod, RolesListMethod, RolesRemoveUserFromRoleMethod, RolesSyncMethod,
+        RolesUpdateMethod,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_roles_list_values() {
+        let result = RolesListMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.list"
+        );
+        assert!(result.query_parameters().is_none());
+        assert!(result.json_payload().is_none());
+    }
+
+    #[test]
+    fn test_roles_sync_values() {
+        let result = RolesSyncMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            updated_since: "2026-01-01T00:00:00.000Z".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.sync"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(
+                query.get("updatedSince"),
+                Some(&"2026-01-01T00:00:00.000Z".to_string())
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_create_values() {
+        let result = RolesCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "support".to_string(),
+            description: "Support team".to_string(),
+            scope: "Subscriptions".to_string(),
+            mandatory_2fa: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.create"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("name"), Some(PayloadValue::String("support")));
+            assert_matches!(
+                json.get("description"),
+                Some(PayloadValue::String("Support team"))
+            );
+            assert_matches!(
+                json.get("scope"),
+                Some(PayloadValue::String("Subscriptions"))
+            );
+            assert_matches!(json.get("mandatory2fa"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_update_values() {
+        let result = RolesUpdateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role_id: "role1".to_string(),
+            name: "support".to_string(),
+            description: "Support team".to_string(),
+            scope: "Subscriptions".to_string(),
+            mandatory_2fa: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.update"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roleId"), Some(PayloadValue::String("role1")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("support")));
+            assert_matches!(
+                json.get("description"),
+                Some(PayloadValue::String("Support team"))
+            );
+            assert_matches!(
+                json.get("scope"),
+                Some(PayloadValue::String("Subscriptions"))
+            );
+            assert_matches!(json.get("mandatory2fa"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_delete_values() {
+        let result = RolesDeleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role_id: "role1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.delete"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roleId"), Some(PayloadValue::String("role1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_add_user_to_role_values() {
+        let result = RolesAddUserToRoleMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role_id: "role1".to_string(),
+            username: "foo".to_string(),
+            room_id: Some("room1".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.addUserToRole"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roleId"), Some(PayloadValue::String("role1")));
+            assert_matches!(json.get("username"), Some(PayloadValue::String("foo")));
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_remove_user_from_role_values() {
+        let result = RolesRemoveUserFromRoleMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role_id: "role1".to_string(),
+            username: "foo".to_string(),
+            room_id: Some("room1".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.removeUserFromRole"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roleId"), Some(PayloadValue::String("role1")));
+            assert_matches!(json.get("username"), Some(PayloadValue::String("foo")));
+            assert_matches!(json.get("scope"), Some(PayloadValue::String("room1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_get_users_in_role_values() {
+        let result = RolesGetUsersInRoleMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role: "moderator".to_string(),
+            room_id: Some("room1".to_string()),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.getUsersInRole"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("role"), Some(&"moderator".to_string()));
+            assert_eq!(query.get("roomId"), Some(&"room1".to_string()));
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_roles_add_user_to_role_without_room_values() {
+        let result = RolesAddUserToRoleMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            role_id: "role1".to_string(),
+            username: "foo".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/roles.addUserToRole"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roleId"), Some(PayloadValue::String("role1")));
+            assert_matches!(json.get("username"), Some(PayloadValue::String("foo")));
+            assert!(json.get("roomId").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 
 use crate::api::methods::base::EndPointInfo;
-use crate::api::methods::restapiutils::RestApiUrlType;
+use crate::api::methods::restapiutils::{RestApiUrlType, generate_selector};
 
 use reqwest::Method;
 
@@ -219,11 +219,309 @@ impl APIMethod for ChangeRoomFavoriteMethod {
     }
 }
 
+/// Implement rooms.autocomplete.channelAndPrivate
+pub struct RoomsAutocompleteChannelAndPrivateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub name: String,
+}
+
+impl Default for RoomsAutocompleteChannelAndPrivateMethod {
+    fn default() -> Self {
+        RoomsAutocompleteChannelAndPrivateMethod {
+            settings: AuthenticationType::None,
+            name: String::default(),
+            server_url: String::default(),
+        }
+    }
+}
+
+impl APIMethod for RoomsAutocompleteChannelAndPrivateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RoomsAutocompleteChannelAndPrivate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert(
+            "selector".to_string(),
+            generate_selector("name", &self.name),
+        );
+        Some(payload)
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+/// Implement rooms.autocomplete.availableForTeams
+pub struct RoomsAutocompleteAvailableForTeamsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub name: String,
+}
+
+impl Default for RoomsAutocompleteAvailableForTeamsMethod {
+    fn default() -> Self {
+        RoomsAutocompleteAvailableForTeamsMethod {
+            settings: AuthenticationType::None,
+            name: String::default(),
+            server_url: String::default(),
+        }
+    }
+}
+
+impl APIMethod for RoomsAutocompleteAvailableForTeamsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RoomsAutocompleteAvailableForTeams,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("name".to_string(), self.name.clone());
+        Some(payload)
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+// Unset values are not modified.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RoomsSaveNotificationInfo {
+    // "default", "all", "mentions" or "nothing"
+    pub desktop_notifications: Option<String>,
+    pub mobile_push_notifications: Option<String>,
+    pub email_notifications: Option<String>,
+    // Sound identifier, "none" or "0" for default sound
+    pub audio_notification_value: Option<String>,
+    pub unread_alert: Option<String>,
+    pub hide_unread_status: Option<bool>,
+    pub mute_group_mentions: Option<bool>,
+    pub disable_notifications: Option<bool>,
+    pub hide_mention_status: Option<bool>,
+}
+
+/// Implement rooms.saveNotification
+pub struct RoomsSaveNotificationMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub notifications: RoomsSaveNotificationInfo,
+}
+
+impl Default for RoomsSaveNotificationMethod {
+    fn default() -> Self {
+        RoomsSaveNotificationMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            notifications: RoomsSaveNotificationInfo::default(),
+        }
+    }
+}
+
+impl APIMethod for RoomsSaveNotificationMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RoomsSaveNotification,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let notifications = &self.notifications;
+        let mut data: HashMap<String, PayloadValue> = HashMap::new();
+        for (key, value) in [
+            ("desktopNotifications", &notifications.desktop_notifications),
+            (
+                "mobilePushNotifications",
+                &notifications.mobile_push_notifications,
+            ),
+            ("emailNotifications", &notifications.email_notifications),
+            (
+                "audioNotificationValue",
+                &notifications.audio_notification_value,
+            ),
+            ("unreadAlert", &notifications.unread_alert),
+        ] {
+            if let Some(value) = value {
+                data.insert(key.to_string(), PayloadValue::String(value));
+            }
+        }
+        // Server expects "1" or "0"
+        for (key, value) in [
+            ("hideUnreadStatus", &notifications.hide_unread_status),
+            ("muteGroupMentions", &notifications.mute_group_mentions),
+            ("disableNotifications", &notifications.disable_notifications),
+            ("hideMentionStatus", &notifications.hide_mention_status),
+        ] {
+            if let Some(value) = value {
+                data.insert(
+                    key.to_string(),
+                    PayloadValue::String(if *value { "1" } else { "0" }),
+                );
+            }
+        }
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("notifications".to_string(), PayloadValue::Object(data));
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
+/// Implement rooms.createDiscussion
+pub struct RoomsCreateDiscussionMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub parent_room_id: String,
+    // Message quoted in discussion when created from a message
+    pub parent_message_id: Option<String>,
+    pub discussion_name: String,
+    // Usernames
+    pub users: Vec<String>,
+    // First message sent in discussion
+    pub reply: Option<String>,
+    pub encrypted: bool,
+}
+
+impl Default for RoomsCreateDiscussionMethod {
+    fn default() -> Self {
+        RoomsCreateDiscussionMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            parent_room_id: String::default(),
+            parent_message_id: None,
+            discussion_name: String::default(),
+            users: Vec::new(),
+            reply: None,
+            encrypted: false,
+        }
+    }
+}
+
+impl APIMethod for RoomsCreateDiscussionMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::RoomsCreateDiscussion,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert(
+            "prid".to_string(),
+            PayloadValue::String(&self.parent_room_id),
+        );
+        payload.insert(
+            "t_name".to_string(),
+            PayloadValue::String(&self.discussion_name),
+        );
+        if let Some(parent_message_id) = &self.parent_message_id {
+            payload.insert("pmid".to_string(), PayloadValue::String(parent_message_id));
+        }
+        if !self.users.is_empty() {
+            let users = self.users.iter().map(|user| user.as_ref()).collect();
+            payload.insert("users".to_string(), PayloadValue::ListOfString(users));
+        }
+        if let Some(reply) = &self.reply {
+            payload.insert("reply".to_string(), PayloadValue::String(reply));
+        }
+        payload.insert("encrypted".to_string(), PayloadValue::Bool(&self.encrypted));
+        Some(payload)
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::methods::APIMethod;
     use crate::methods::{
         ChangeRoomFavoriteMethod, GetDiscussionsMethod, GetRoomsMethod, PayloadValue,
+        RoomsAutocompleteAvailableForTeamsMethod, RoomsAutocompleteChannelAndPrivateMethod,
+        RoomsCreateDiscussionMethod, RoomsSaveNotificationInfo, RoomsSaveNotificationMethod,
     };
     use assert_matches::assert_matches;
     use reqwest::Method;
@@ -312,4 +610,153 @@ mod tests {
             panic!("Impossble to get parameters");
         }
     }
+
+    #[test]
+    fn test_rooms_autocomplete_channel_and_private_values() {
+        let result = RoomsAutocompleteChannelAndPrivateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "foo".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/rooms.autocomplete.channelAndPrivate"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(
+                query.get("selector"),
+                Some(&"{\"name\":\"foo\"}".to_string())
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_rooms_autocomplete_available_for_teams_values() {
+        let result = RoomsAutocompleteAvailableForTeamsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "foo".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/rooms.autocomplete.availableForTeams"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("name"), Some(&"foo".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_rooms_save_notification_values() {
+        let result = RoomsSaveNotificationMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room_id".to_string(),
+            notifications: RoomsSaveNotificationInfo {
+                desktop_notifications: Some("mentions".to_string()),
+                audio_notification_value: Some("beep".to_string()),
+                disable_notifications: Some(true),
+                hide_unread_status: Some(false),
+                ..Default::default()
+            },
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/rooms.saveNotification"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room_id")));
+            let Some(PayloadValue::Object(notifications)) = json.get("notifications") else {
+                panic!("Impossble to get parameters");
+            };
+            assert_eq!(notifications.len(), 4);
+            assert_matches!(
+                notifications.get("desktopNotifications"),
+                Some(PayloadValue::String("mentions"))
+            );
+            assert_matches!(
+                notifications.get("audioNotificationValue"),
+                Some(PayloadValue::String("beep"))
+            );
+            assert_matches!(
+                notifications.get("disableNotifications"),
+                Some(PayloadValue::String("1"))
+            );
+            assert_matches!(
+                notifications.get("hideUnreadStatus"),
+                Some(PayloadValue::String("0"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_rooms_create_discussion_values() {
+        let result = RoomsCreateDiscussionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            parent_room_id: "GENERAL".to_string(),
+            parent_message_id: Some("msg1".to_string()),
+            discussion_name: "release".to_string(),
+            users: vec!["foo".to_string(), "bla".to_string()],
+            reply: Some("Let's talk".to_string()),
+            encrypted: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert!(result.query_parameters().is_none());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/rooms.createDiscussion"
+        );
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("prid"), Some(PayloadValue::String("GENERAL")));
+            assert_matches!(json.get("t_name"), Some(PayloadValue::String("release")));
+            assert_matches!(json.get("pmid"), Some(PayloadValue::String("msg1")));
+            assert_matches!(json.get("reply"), Some(PayloadValue::String("Let's talk")));
+            assert_matches!(json.get("encrypted"), Some(PayloadValue::Bool(true)));
+            let Some(PayloadValue::ListOfString(users)) = json.get("users") else {
+                panic!("users expected");
+            };
+            assert_eq!(users, &vec!["foo", "bla"]);
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_rooms_create_discussion_default_values() {
+        let result = RoomsCreateDiscussionMethod {
+            parent_room_id: "GENERAL".to_string(),
+            discussion_name: "release".to_string(),
+            ..Default::default()
+        };
+        let json = result.json_payload().unwrap();
+        assert!(!json.contains_key("pmid"));
+        assert!(!json.contains_key("users"));
+        assert!(!json.contains_key("reply"));
+        assert_matches!(json.get("encrypted"), Some(PayloadValue::Bool(false)));
+    }
 }
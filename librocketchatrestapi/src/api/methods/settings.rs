@@ -0,0 +1,395 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::restapiutils::RestApiUrlType;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+fn settings_query_parameters(
+    ids: &[String],
+    offset: Option<i64>,
+    count: Option<i64>,
+) -> Option<HashMap<String, String>> {
+    let mut payload: HashMap<String, String> = HashMap::new();
+    if !ids.is_empty() {
+        payload.insert("_id".to_string(), ids.join(","));
+    }
+    if let Some(offset) = offset {
+        payload.insert("offset".to_string(), offset.to_string());
+    }
+    if let Some(count) = count {
+        payload.insert("count".to_string(), count.to_string());
+    }
+    if payload.is_empty() {
+        None
+    } else {
+        Some(payload)
+    }
+}
+
+/// Implement settings.public
+pub struct SettingsPublicMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // Only return these settings, all public settings when empty
+    pub ids: Vec<String>,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for SettingsPublicMethod {
+    fn default() -> Self {
+        SettingsPublicMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            ids: Vec::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for SettingsPublicMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        settings_query_parameters(&self.ids, self.offset, self.count)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::SettingsPublic,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        false
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement settings (private settings visible by logged user)
+pub struct SettingsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub ids: Vec<String>,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for SettingsMethod {
+    fn default() -> Self {
+        SettingsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            ids: Vec::default(),
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for SettingsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        settings_query_parameters(&self.ids, self.offset, self.count)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::Settings,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement settings.oauth
+pub struct SettingsOauthMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+}
+
+impl Default for SettingsOauthMethod {
+    fn default() -> Self {
+        SettingsOauthMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+        }
+    }
+}
+
+impl APIMethod for SettingsOauthMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::SettingsOauth,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        false
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AdminSettingValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+}
+
+/// Implement settings/:_id
+pub struct UpdateAdminSettingsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub setting_id: String,
+    pub value: AdminSettingValue,
+}
+
+impl Default for UpdateAdminSettingsMethod {
+    fn default() -> Self {
+        UpdateAdminSettingsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            setting_id: String::default(),
+            value: AdminSettingValue::String(String::default()),
+        }
+    }
+}
+
+impl APIMethod for UpdateAdminSettingsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UpdateAdminSettings,
+            url_extension: format!("/{}", self.setting_id),
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        let value = match &self.value {
+            AdminSettingValue::String(value) => PayloadValue::String(value),
+            AdminSettingValue::Bool(value) => PayloadValue::Bool(value),
+            AdminSettingValue::Integer(value) => PayloadValue::Integer(value),
+        };
+        payload.insert("value".to_string(), value);
+
+        Some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, AdminSettingValue, PayloadValue, SettingsMethod, SettingsOauthMethod,
+        SettingsPublicMethod, UpdateAdminSettingsMethod,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_settings_public_values() {
+        let result = SettingsPublicMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: "https://mydomain.com".to_string(),
+            ids: vec!["Site_Name".to_string(), "E2E_Enable".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(!result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/settings.public"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("_id"), Some(&"Site_Name,E2E_Enable".to_string()));
+            assert!(query.get("count").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_settings_public_without_ids_values() {
+        let result = SettingsPublicMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: "https://mydomain.com".to_string(),
+            ..Default::default()
+        };
+        assert!(result.query_parameters().is_none());
+    }
+
+    #[test]
+    fn test_settings_values() {
+        let result = SettingsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            offset: Some(50),
+            count: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/settings"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert!(query.get("_id").is_none());
+            assert_eq!(query.get("offset"), Some(&"50".to_string()));
+            assert_eq!(query.get("count"), Some(&"50".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_settings_oauth_values() {
+        let result = SettingsOauthMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: "https://mydomain.com".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(!result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/settings.oauth"
+        );
+        assert!(result.query_parameters().is_none());
+        assert!(result.json_payload().is_none());
+    }
+
+    #[test]
+    fn test_update_admin_settings_values() {
+        let result = UpdateAdminSettingsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            setting_id: "Message_MaxAllowedSize".to_string(),
+            value: AdminSettingValue::Integer(8000),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/settings/Message_MaxAllowedSize"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("value"), Some(PayloadValue::Integer(8000)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        let result = UpdateAdminSettingsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            setting_id: "E2E_Enable".to_string(),
+            value: AdminSettingValue::Bool(true),
+        };
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("value"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
@@ -114,9 +114,897 @@ impl APIMethod for GetTeamInfoMethod {
     }
 }
 
+/// Implement teams.create
+pub struct TeamCreateMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+    pub private: bool,
+    // List of user identifiers
+    pub members: Option<Vec<String>>,
+    pub read_only: Option<bool>,
+}
+
+impl Default for TeamCreateMethod {
+    fn default() -> Self {
+        TeamCreateMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+            private: false,
+            members: None,
+            read_only: None,
+        }
+    }
+}
+
+impl APIMethod for TeamCreateMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsCreate,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        // 0: public team, 1: private team
+        if self.private {
+            payload.insert("type".to_string(), PayloadValue::Integer(&1));
+        } else {
+            payload.insert("type".to_string(), PayloadValue::Integer(&0));
+        }
+        if let Some(members) = &self.members {
+            let list = members.iter().map(|el| el.as_ref()).collect();
+            payload.insert("members".to_string(), PayloadValue::ListOfString(list));
+        }
+        if let Some(read_only) = &self.read_only {
+            let mut room: HashMap<String, PayloadValue> = HashMap::new();
+            room.insert("readOnly".to_string(), PayloadValue::Bool(read_only));
+            payload.insert("room".to_string(), PayloadValue::Object(room));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.addMembers
+/// Users are added with the "member" role.
+pub struct TeamAddMembersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub user_ids: Vec<String>,
+}
+
+impl Default for TeamAddMembersMethod {
+    fn default() -> Self {
+        TeamAddMembersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            user_ids: Vec::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamAddMembersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsAddMembers,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        let members = self
+            .user_ids
+            .iter()
+            .map(|user_id| {
+                let mut member: HashMap<String, PayloadValue> = HashMap::new();
+                member.insert("userId".to_string(), PayloadValue::String(user_id));
+                member.insert(
+                    "roles".to_string(),
+                    PayloadValue::ListOfString(vec!["member"]),
+                );
+                member
+            })
+            .collect();
+        payload.insert("members".to_string(), PayloadValue::ListOfObject(members));
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.updateMember
+pub struct TeamUpdateMemberMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub user_id: String,
+    pub roles: Vec<String>,
+}
+
+impl Default for TeamUpdateMemberMethod {
+    fn default() -> Self {
+        TeamUpdateMemberMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            user_id: String::default(),
+            roles: Vec::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamUpdateMemberMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsUpdateMember,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        let mut member: HashMap<String, PayloadValue> = HashMap::new();
+        member.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+        let roles = self.roles.iter().map(|el| el.as_ref()).collect();
+        member.insert("roles".to_string(), PayloadValue::ListOfString(roles));
+        payload.insert("member".to_string(), PayloadValue::Object(member));
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.addRooms
+pub struct TeamAddRoomsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub rooms: Vec<String>,
+}
+
+impl Default for TeamAddRoomsMethod {
+    fn default() -> Self {
+        TeamAddRoomsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            rooms: Vec::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamAddRoomsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsAddRooms,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        let rooms = self.rooms.iter().map(|el| el.as_ref()).collect();
+        payload.insert("rooms".to_string(), PayloadValue::ListOfString(rooms));
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.removeRoom
+pub struct TeamRemoveRoomMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub room_id: String,
+}
+
+impl Default for TeamRemoveRoomMethod {
+    fn default() -> Self {
+        TeamRemoveRoomMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            room_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamRemoveRoomMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsRemoveRoom,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.updateRoom
+pub struct TeamUpdateRoomMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub room_id: String,
+    pub auto_join: bool,
+}
+
+impl Default for TeamUpdateRoomMethod {
+    fn default() -> Self {
+        TeamUpdateRoomMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            room_id: String::default(),
+            auto_join: false,
+        }
+    }
+}
+
+impl APIMethod for TeamUpdateRoomMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsUpdateRoom,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("roomId".to_string(), PayloadValue::String(&self.room_id));
+        payload.insert("isDefault".to_string(), PayloadValue::Bool(&self.auto_join));
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.listRooms
+pub struct TeamListRoomsMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub filter: Option<String>,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for TeamListRoomsMethod {
+    fn default() -> Self {
+        TeamListRoomsMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            filter: None,
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for TeamListRoomsMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("teamId".to_string(), self.team_id.clone());
+        if let Some(filter) = &self.filter {
+            payload.insert("filter".to_string(), filter.clone());
+        }
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsListRooms,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement teams.listRoomsOfUser
+pub struct TeamListRoomsOfUserMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub user_id: String,
+}
+
+impl Default for TeamListRoomsOfUserMethod {
+    fn default() -> Self {
+        TeamListRoomsOfUserMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            user_id: String::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamListRoomsOfUserMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("teamId".to_string(), self.team_id.clone());
+        payload.insert("userId".to_string(), self.user_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsListRoomsOfUser,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement teams.members
+pub struct TeamMembersMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl Default for TeamMembersMethod {
+    fn default() -> Self {
+        TeamMembersMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            username: None,
+            name: None,
+            offset: None,
+            count: None,
+        }
+    }
+}
+
+impl APIMethod for TeamMembersMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("teamId".to_string(), self.team_id.clone());
+        if let Some(username) = &self.username {
+            payload.insert("username".to_string(), username.clone());
+        }
+        if let Some(name) = &self.name {
+            payload.insert("name".to_string(), name.clone());
+        }
+        if let Some(offset) = &self.offset {
+            payload.insert("offset".to_string(), offset.to_string());
+        }
+        if let Some(count) = &self.count {
+            payload.insert("count".to_string(), count.to_string());
+        }
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsMembers,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement teams.removeMember
+pub struct TeamRemoveMemberMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub user_id: String,
+    pub rooms: Option<Vec<String>>,
+}
+
+impl Default for TeamRemoveMemberMethod {
+    fn default() -> Self {
+        TeamRemoveMemberMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            user_id: String::default(),
+            rooms: None,
+        }
+    }
+}
+
+impl APIMethod for TeamRemoveMemberMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsRemoveMember,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+        if let Some(rooms) = &self.rooms {
+            let list = rooms.iter().map(|el| el.as_ref()).collect();
+            payload.insert("rooms".to_string(), PayloadValue::ListOfString(list));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.leave
+pub struct TeamLeaveMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub rooms: Option<Vec<String>>,
+}
+
+impl Default for TeamLeaveMethod {
+    fn default() -> Self {
+        TeamLeaveMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            rooms: None,
+        }
+    }
+}
+
+impl APIMethod for TeamLeaveMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsLeave,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        if let Some(rooms) = &self.rooms {
+            let list = rooms.iter().map(|el| el.as_ref()).collect();
+            payload.insert("rooms".to_string(), PayloadValue::ListOfString(list));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.delete
+pub struct TeamDeleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub rooms_to_remove: Option<Vec<String>>,
+}
+
+impl Default for TeamDeleteMethod {
+    fn default() -> Self {
+        TeamDeleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            rooms_to_remove: None,
+        }
+    }
+}
+
+impl APIMethod for TeamDeleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsDelete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        if let Some(rooms_to_remove) = &self.rooms_to_remove {
+            let list = rooms_to_remove.iter().map(|el| el.as_ref()).collect();
+            payload.insert(
+                "roomsToRemove".to_string(),
+                PayloadValue::ListOfString(list),
+            );
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement teams.autocomplete
+pub struct TeamAutocompleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub name: String,
+}
+
+impl Default for TeamAutocompleteMethod {
+    fn default() -> Self {
+        TeamAutocompleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            name: String::default(),
+        }
+    }
+}
+
+impl APIMethod for TeamAutocompleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("name".to_string(), self.name.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsAutocomplete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement teams.convertToChannel
+pub struct TeamConvertToChannelMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub team_id: String,
+    pub rooms_to_remove: Option<Vec<String>>,
+}
+
+impl Default for TeamConvertToChannelMethod {
+    fn default() -> Self {
+        TeamConvertToChannelMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            team_id: String::default(),
+            rooms_to_remove: None,
+        }
+    }
+}
+
+impl APIMethod for TeamConvertToChannelMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::TeamsConvertToChannel,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("teamId".to_string(), PayloadValue::String(&self.team_id));
+        if let Some(rooms_to_remove) = &self.rooms_to_remove {
+            let list = rooms_to_remove.iter().map(|el| el.as_ref()).collect();
+            payload.insert(
+                "roomsToRemove".to_string(),
+                PayloadValue::ListOfString(list),
+            );
+        }
+
+        Some(payload)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::methods::{APIMethod, GetTeamInfoMethod, GetTeamsListMethod};
+    use crate::methods::{
+        APIMethod, GetTeamInfoMethod, GetTeamsListMethod, PayloadValue, TeamAddMembersMethod,
+        TeamAddRoomsMethod, TeamAutocompleteMethod, TeamConvertToChannelMethod, TeamCreateMethod,
+        TeamDeleteMethod, TeamLeaveMethod, TeamListRoomsMethod, TeamListRoomsOfUserMethod,
+        TeamMembersMethod, TeamRemoveMemberMethod, TeamRemoveRoomMethod, TeamUpdateMemberMethod,
+        TeamUpdateRoomMethod,
+    };
+    use assert_matches::assert_matches;
     use reqwest::Method;
 
     use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
@@ -158,4 +1046,425 @@ mod tests {
         assert!(result.query_parameters().is_none());
         assert!(result.json_payload().is_none());
     }
+
+    #[test]
+    fn test_team_create_values() {
+        let result = TeamCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "team1".to_string(),
+            private: true,
+            members: Some(vec!["user1".to_string()]),
+            read_only: Some(true),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.create"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("name"), Some(PayloadValue::String("team1")));
+            assert_matches!(json.get("type"), Some(PayloadValue::Integer(1)));
+            assert_matches!(
+                json.get("members"),
+                Some(PayloadValue::ListOfString(list)) if list == &vec!["user1"]
+            );
+            if let Some(PayloadValue::Object(room)) = json.get("room") {
+                assert_matches!(room.get("readOnly"), Some(PayloadValue::Bool(true)));
+            } else {
+                panic!("Impossble to get parameters");
+            }
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_create_public_values() {
+        let result = TeamCreateMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "team1".to_string(),
+            ..Default::default()
+        };
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("type"), Some(PayloadValue::Integer(0)));
+            assert!(json.get("members").is_none());
+            assert!(json.get("room").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_add_members_values() {
+        let result = TeamAddMembersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            user_ids: vec!["user1".to_string(), "user2".to_string()],
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.addMembers"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            if let Some(PayloadValue::ListOfObject(members)) = json.get("members") {
+                assert_eq!(members.len(), 2);
+                assert_matches!(
+                    members[0].get("userId"),
+                    Some(PayloadValue::String("user1"))
+                );
+                assert_matches!(
+                    members[1].get("userId"),
+                    Some(PayloadValue::String("user2"))
+                );
+                assert_matches!(
+                    members[0].get("roles"),
+                    Some(PayloadValue::ListOfString(list)) if list == &vec!["member"]
+                );
+            } else {
+                panic!("Impossble to get parameters");
+            }
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_update_member_values() {
+        let result = TeamUpdateMemberMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            user_id: "user1".to_string(),
+            roles: vec!["owner".to_string(), "member".to_string()],
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.updateMember"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            if let Some(PayloadValue::Object(member)) = json.get("member") {
+                assert_matches!(member.get("userId"), Some(PayloadValue::String("user1")));
+                assert_matches!(
+                    member.get("roles"),
+                    Some(PayloadValue::ListOfString(list)) if list == &vec!["owner", "member"]
+                );
+            } else {
+                panic!("Impossble to get parameters");
+            }
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_add_rooms_values() {
+        let result = TeamAddRoomsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            rooms: vec!["room1".to_string(), "room2".to_string()],
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.addRooms"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert_matches!(
+                json.get("rooms"),
+                Some(PayloadValue::ListOfString(list)) if list == &vec!["room1", "room2"]
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_remove_room_values() {
+        let result = TeamRemoveRoomMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            room_id: "room1".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.removeRoom"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room1")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_update_room_values() {
+        let result = TeamUpdateRoomMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            room_id: "room1".to_string(),
+            auto_join: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.updateRoom"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("roomId"), Some(PayloadValue::String("room1")));
+            assert_matches!(json.get("isDefault"), Some(PayloadValue::Bool(true)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_list_rooms_values() {
+        let result = TeamListRoomsMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            count: Some(25),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.listRooms"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("teamId"), Some(&"team1".to_string()));
+            assert!(query.get("filter").is_none());
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"25".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_list_rooms_of_user_values() {
+        let result = TeamListRoomsOfUserMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            user_id: "user1".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.listRoomsOfUser"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("teamId"), Some(&"team1".to_string()));
+            assert_eq!(query.get("userId"), Some(&"user1".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_members_values() {
+        let result = TeamMembersMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            username: Some("foo".to_string()),
+            count: Some(25),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.members"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("teamId"), Some(&"team1".to_string()));
+            assert_eq!(query.get("username"), Some(&"foo".to_string()));
+            assert!(query.get("name").is_none());
+            assert!(query.get("offset").is_none());
+            assert_eq!(query.get("count"), Some(&"25".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_remove_member_values() {
+        let result = TeamRemoveMemberMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            user_id: "user1".to_string(),
+            rooms: Some(vec!["room1".to_string(), "room2".to_string()]),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.removeMember"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("user1")));
+            assert_matches!(
+                json.get("rooms"),
+                Some(PayloadValue::ListOfString(list)) if list == &vec!["room1", "room2"]
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_leave_values() {
+        let result = TeamLeaveMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            rooms: Some(vec!["room1".to_string(), "room2".to_string()]),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.leave"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert_matches!(
+                json.get("rooms"),
+                Some(PayloadValue::ListOfString(list)) if list == &vec!["room1", "room2"]
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_delete_values() {
+        let result = TeamDeleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.delete"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert!(json.get("roomsToRemove").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_autocomplete_values() {
+        let result = TeamAutocompleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            name: "bla".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.autocomplete"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("name"), Some(&"bla".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_team_convert_to_channel_values() {
+        let result = TeamConvertToChannelMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            team_id: "team1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/teams.convertToChannel"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("teamId"), Some(PayloadValue::String("team1")));
+            assert!(json.get("roomsToRemove").is_none());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
 }
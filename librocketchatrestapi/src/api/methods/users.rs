@@ -0,0 +1,1159 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+use std::collections::HashMap;
+
+use crate::api::methods::APIMethod;
+use crate::api::methods::base::EndPointInfo;
+use crate::api::methods::base::PayloadValue;
+use crate::api::methods::base::{MultipartFile, MultipartPayload};
+use crate::api::methods::restapiutils::{RestApiUrlType, generate_selector};
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::Method;
+
+/// Implement users.autocomplete
+pub struct UsersAutocompleteMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub pattern: String,
+}
+
+impl Default for UsersAutocompleteMethod {
+    fn default() -> Self {
+        UsersAutocompleteMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            pattern: String::default(),
+        }
+    }
+}
+
+impl APIMethod for UsersAutocompleteMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert(
+            "selector".to_string(),
+            generate_selector("term", &self.pattern),
+        );
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersAutocomplete,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement users.register
+pub struct UsersRegisterMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub name: String,
+    // Required when registration form is "Secret URL"
+    pub secret_url: Option<String>,
+    // Required when new users must be approved by an admin
+    pub reason: Option<String>,
+}
+
+impl Default for UsersRegisterMethod {
+    fn default() -> Self {
+        UsersRegisterMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            username: String::default(),
+            email: String::default(),
+            password: String::default(),
+            name: String::default(),
+            secret_url: None,
+            reason: None,
+        }
+    }
+}
+
+impl APIMethod for UsersRegisterMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersRegister,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        false
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("username".to_string(), PayloadValue::String(&self.username));
+        payload.insert("email".to_string(), PayloadValue::String(&self.email));
+        payload.insert("pass".to_string(), PayloadValue::String(&self.password));
+        payload.insert("name".to_string(), PayloadValue::String(&self.name));
+        if let Some(secret_url) = &self.secret_url {
+            payload.insert("secretURL".to_string(), PayloadValue::String(secret_url));
+        }
+        if let Some(reason) = &self.reason {
+            payload.insert("reason".to_string(), PayloadValue::String(reason));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement users.forgotPassword
+pub struct ForgotPasswordMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub email: String,
+}
+
+impl Default for ForgotPasswordMethod {
+    fn default() -> Self {
+        ForgotPasswordMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            email: String::default(),
+        }
+    }
+}
+
+impl APIMethod for ForgotPasswordMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::ForgotPassword,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        false
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("email".to_string(), PayloadValue::String(&self.email));
+
+        Some(payload)
+    }
+}
+
+/// Implement users.getUsernameSuggestion
+pub struct UsersGetUsernameSuggestionMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+}
+
+impl Default for UsersGetUsernameSuggestionMethod {
+    fn default() -> Self {
+        UsersGetUsernameSuggestionMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+        }
+    }
+}
+
+impl APIMethod for UsersGetUsernameSuggestionMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersGetUsernameSuggestion,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement users.updateOwnBasicInfo
+pub struct UsersUpdateOwnBasicInfoMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub username: Option<String>,
+    pub nickname: Option<String>,
+    pub bio: Option<String>,
+    pub status_text: Option<String>,
+    // SHA-256 hex digest, required to change email or password
+    pub current_password: Option<String>,
+    pub new_password: Option<String>,
+}
+
+impl Default for UsersUpdateOwnBasicInfoMethod {
+    fn default() -> Self {
+        UsersUpdateOwnBasicInfoMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            email: None,
+            name: None,
+            username: None,
+            nickname: None,
+            bio: None,
+            status_text: None,
+            current_password: None,
+            new_password: None,
+        }
+    }
+}
+
+impl APIMethod for UsersUpdateOwnBasicInfoMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersUpdateOwnBasicInfo,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut data: HashMap<String, PayloadValue> = HashMap::new();
+        for (key, value) in [
+            ("email", &self.email),
+            ("realname", &self.name),
+            ("username", &self.username),
+            ("nickname", &self.nickname),
+            ("bio", &self.bio),
+            ("statusText", &self.status_text),
+            ("currentPassword", &self.current_password),
+            ("newPassword", &self.new_password),
+        ] {
+            if let Some(value) = value {
+                data.insert(key.to_string(), PayloadValue::String(value));
+            }
+        }
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("data".to_string(), PayloadValue::Object(data));
+
+        Some(payload)
+    }
+}
+
+// Preferences changed by users.setPreferences, unset values are not sent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UsersSetPreferencesInfo {
+    pub highlights: Option<Vec<String>>,
+    pub email_notification_mode: Option<String>,
+    pub desktop_notifications: Option<String>,
+    pub push_notifications: Option<String>,
+    pub new_room_notification: Option<String>,
+    pub new_message_notification: Option<String>,
+    pub sidebar_sort_by: Option<String>,
+    pub sidebar_view_mode: Option<String>,
+    pub use_emojis: Option<bool>,
+    pub convert_ascii_emoji: Option<bool>,
+    pub hide_roles: Option<bool>,
+    pub display_avatars: Option<bool>,
+    pub enable_auto_away: Option<bool>,
+    pub mute_focused_conversations: Option<bool>,
+    pub receive_login_detection_email: Option<bool>,
+    pub idle_time_limit: Option<i64>,
+    pub notifications_sound_volume: Option<i64>,
+}
+
+/// Implement users.setPreferences
+pub struct UsersSetPreferencesMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub user_id: String,
+    pub preferences: UsersSetPreferencesInfo,
+}
+
+impl Default for UsersSetPreferencesMethod {
+    fn default() -> Self {
+        UsersSetPreferencesMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            user_id: String::default(),
+            preferences: UsersSetPreferencesInfo::default(),
+        }
+    }
+}
+
+impl APIMethod for UsersSetPreferencesMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersSetPreferences,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let preferences = &self.preferences;
+        let mut data: HashMap<String, PayloadValue> = HashMap::new();
+        if let Some(highlights) = &preferences.highlights {
+            data.insert(
+                "highlights".to_string(),
+                PayloadValue::ListOfString(highlights.iter().map(|s| s.as_str()).collect()),
+            );
+        }
+        for (key, value) in [
+            (
+                "emailNotificationMode",
+                &preferences.email_notification_mode,
+            ),
+            ("desktopNotifications", &preferences.desktop_notifications),
+            ("pushNotifications", &preferences.push_notifications),
+            ("newRoomNotification", &preferences.new_room_notification),
+            (
+                "newMessageNotification",
+                &preferences.new_message_notification,
+            ),
+            ("sidebarSortby", &preferences.sidebar_sort_by),
+            ("sidebarViewMode", &preferences.sidebar_view_mode),
+        ] {
+            if let Some(value) = value {
+                data.insert(key.to_string(), PayloadValue::String(value));
+            }
+        }
+        for (key, value) in [
+            ("useEmojis", &preferences.use_emojis),
+            ("convertAsciiEmoji", &preferences.convert_ascii_emoji),
+            ("hideRoles", &preferences.hide_roles),
+            ("displayAvatars", &preferences.display_avatars),
+            ("enableAutoAway", &preferences.enable_auto_away),
+            (
+                "muteFocusedConversations",
+                &preferences.mute_focused_conversations,
+            ),
+            (
+                "receiveLoginDetectionEmail",
+                &preferences.receive_login_detection_email,
+            ),
+        ] {
+            if let Some(value) = value {
+                data.insert(key.to_string(), PayloadValue::Bool(value));
+            }
+        }
+        for (key, value) in [
+            ("idleTimeLimit", &preferences.idle_time_limit),
+            (
+                "notificationsSoundVolume",
+                &preferences.notifications_sound_volume,
+            ),
+        ] {
+            if let Some(value) = value {
+                data.insert(key.to_string(), PayloadValue::Integer(value));
+            }
+        }
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("userId".to_string(), PayloadValue::String(&self.user_id));
+        payload.insert("data".to_string(), PayloadValue::Object(data));
+
+        Some(payload)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AvatarSource {
+    // Server downloads avatar from this url
+    Url(String),
+    // Uploaded as multipart "image" field
+    Image {
+        file_name: String,
+        mime_type: String,
+        data: Vec<u8>,
+    },
+}
+
+/// Implement users.setAvatar
+pub struct UsersSetAvatarMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    pub avatar: AvatarSource,
+}
+
+impl Default for UsersSetAvatarMethod {
+    fn default() -> Self {
+        UsersSetAvatarMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            avatar: AvatarSource::Url(String::default()),
+        }
+    }
+}
+
+impl APIMethod for UsersSetAvatarMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersSetAvatar,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let AvatarSource::Url(url) = &self.avatar else {
+            return None;
+        };
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("avatarUrl".to_string(), PayloadValue::String(url));
+
+        Some(payload)
+    }
+
+    fn multipart_payload(&self) -> Option<MultipartPayload> {
+        let AvatarSource::Image {
+            file_name,
+            mime_type,
+            data,
+        } = &self.avatar
+        else {
+            return None;
+        };
+        Some(MultipartPayload {
+            files: vec![MultipartFile {
+                field_name: "image".to_string(),
+                file_name: file_name.clone(),
+                mime_type: mime_type.clone(),
+                data: data.clone(),
+            }],
+            ..Default::default()
+        })
+    }
+}
+
+/// Implement users.resetAvatar
+pub struct UsersResetAvatarMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // Own avatar when empty
+    pub user_id: Option<String>,
+}
+
+impl Default for UsersResetAvatarMethod {
+    fn default() -> Self {
+        UsersResetAvatarMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            user_id: None,
+        }
+    }
+}
+
+impl APIMethod for UsersResetAvatarMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersResetAvatar,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        if let Some(user_id) = &self.user_id {
+            payload.insert("userId".to_string(), PayloadValue::String(user_id));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement users.setStatus
+pub struct UsersSetStatusMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // "online", "away", "busy" or "offline"
+    pub status: Option<String>,
+    pub message: Option<String>,
+}
+
+impl Default for UsersSetStatusMethod {
+    fn default() -> Self {
+        UsersSetStatusMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            status: None,
+            message: None,
+        }
+    }
+}
+
+impl APIMethod for UsersSetStatusMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersSetStatus,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        if let Some(status) = &self.status {
+            payload.insert("status".to_string(), PayloadValue::String(status));
+        }
+        if let Some(message) = &self.message {
+            payload.insert("message".to_string(), PayloadValue::String(message));
+        }
+
+        Some(payload)
+    }
+}
+
+/// Implement users.getStatus
+pub struct UsersGetStatusMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // Own status when empty
+    pub user_id: Option<String>,
+}
+
+impl Default for UsersGetStatusMethod {
+    fn default() -> Self {
+        UsersGetStatusMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            user_id: None,
+        }
+    }
+}
+
+impl APIMethod for UsersGetStatusMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        let user_id = self.user_id.as_ref()?;
+        let mut payload: HashMap<String, String> = HashMap::new();
+        payload.insert("userId".to_string(), user_id.clone());
+        Some(payload)
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersGetStatus,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        None
+    }
+}
+
+/// Implement users.deleteOwnAccount
+pub struct UsersDeleteOwnAccountMethod {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+
+    // SHA-256 hex digest of password
+    pub password: String,
+    // Transfer room ownership to other users
+    pub confirm_relinquish: bool,
+}
+
+impl Default for UsersDeleteOwnAccountMethod {
+    fn default() -> Self {
+        UsersDeleteOwnAccountMethod {
+            settings: AuthenticationType::None,
+            server_url: String::default(),
+            password: String::default(),
+            confirm_relinquish: false,
+        }
+    }
+}
+
+impl APIMethod for UsersDeleteOwnAccountMethod {
+    fn settings(&self) -> &AuthenticationType {
+        &self.settings
+    }
+
+    fn domain(&self) -> &str {
+        &self.server_url
+    }
+
+    fn query_parameters(&self) -> Option<HashMap<String, String>> {
+        None
+    }
+
+    fn endpointinfo(&self) -> EndPointInfo {
+        EndPointInfo {
+            endpoint_type: RestApiUrlType::UsersDeleteOwnAccount,
+            ..Default::default()
+        }
+    }
+
+    fn required_authentication(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn json_payload(&self) -> Option<HashMap<String, PayloadValue<'_>>> {
+        let mut payload: HashMap<String, PayloadValue> = HashMap::new();
+        payload.insert("password".to_string(), PayloadValue::String(&self.password));
+        payload.insert(
+            "confirmRelinquish".to_string(),
+            PayloadValue::Bool(&self.confirm_relinquish),
+        );
+
+        Some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::{
+        APIMethod, AvatarSource, ForgotPasswordMethod, PayloadValue, UsersAutocompleteMethod,
+        UsersDeleteOwnAccountMethod, UsersGetStatusMethod, UsersGetUsernameSuggestionMethod,
+        UsersRegisterMethod, UsersResetAvatarMethod, UsersSetAvatarMethod, UsersSetPreferencesInfo,
+        UsersSetPreferencesMethod, UsersSetStatusMethod, UsersUpdateOwnBasicInfoMethod,
+    };
+    use assert_matches::assert_matches;
+    use libauthenticationbase::authenticationsettings::{AuthenticationType, LoginSettings};
+    use reqwest::Method;
+
+    pub fn generate_default_settings() -> AuthenticationType {
+        AuthenticationType::Login(LoginSettings {
+            username: "chuck_norris".to_string(),
+            password: "supersecret".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_users_autocomplete_values() {
+        let result = UsersAutocompleteMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            pattern: "foo".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.autocomplete"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(
+                query.get("selector"),
+                Some(&"{\"term\":\"foo\"}".to_string())
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_register_values() {
+        let result = UsersRegisterMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: "https://mydomain.com".to_string(),
+            username: "foo".to_string(),
+            email: "foo@kde.org".to_string(),
+            password: "Secret-42".to_string(),
+            name: "Foo Bla".to_string(),
+            reason: Some("KDE contributor".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(!result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.register"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("username"), Some(PayloadValue::String("foo")));
+            assert_matches!(json.get("email"), Some(PayloadValue::String("foo@kde.org")));
+            assert_matches!(json.get("pass"), Some(PayloadValue::String("Secret-42")));
+            assert_matches!(json.get("name"), Some(PayloadValue::String("Foo Bla")));
+            assert!(json.get("secretURL").is_none());
+            assert_matches!(
+                json.get("reason"),
+                Some(PayloadValue::String("KDE contributor"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_forgot_password_values() {
+        let result = ForgotPasswordMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: "https://mydomain.com".to_string(),
+            email: "foo@kde.org".to_string(),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(!result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.forgotPassword"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("email"), Some(PayloadValue::String("foo@kde.org")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_get_username_suggestion_values() {
+        let result = UsersGetUsernameSuggestionMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.getUsernameSuggestion"
+        );
+        assert!(result.query_parameters().is_none());
+        assert!(result.json_payload().is_none());
+    }
+
+    #[test]
+    fn test_users_update_own_basic_info_values() {
+        let result = UsersUpdateOwnBasicInfoMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            nickname: Some("foo".to_string()),
+            bio: Some("KDE developer".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.updateOwnBasicInfo"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            let Some(PayloadValue::Object(data)) = json.get("data") else {
+                panic!("Impossble to get parameters");
+            };
+            assert_eq!(data.len(), 2);
+            assert_matches!(data.get("nickname"), Some(PayloadValue::String("foo")));
+            assert_matches!(data.get("bio"), Some(PayloadValue::String("KDE developer")));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_set_preferences_values() {
+        let result = UsersSetPreferencesMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            user_id: "foo".to_string(),
+            preferences: UsersSetPreferencesInfo {
+                highlights: Some(vec!["kde".to_string(), "ruqola".to_string()]),
+                desktop_notifications: Some("mentions".to_string()),
+                enable_auto_away: Some(true),
+                idle_time_limit: Some(300),
+                ..Default::default()
+            },
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.setPreferences"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("userId"), Some(PayloadValue::String("foo")));
+            let Some(PayloadValue::Object(data)) = json.get("data") else {
+                panic!("Impossble to get parameters");
+            };
+            assert_eq!(data.len(), 4);
+            assert_matches!(data.get("highlights"), Some(PayloadValue::ListOfString(v)) if v == &vec!["kde", "ruqola"]);
+            assert_matches!(
+                data.get("desktopNotifications"),
+                Some(PayloadValue::String("mentions"))
+            );
+            assert_matches!(data.get("enableAutoAway"), Some(PayloadValue::Bool(true)));
+            assert_matches!(data.get("idleTimeLimit"), Some(PayloadValue::Integer(300)));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_set_avatar_url_values() {
+        let result = UsersSetAvatarMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            avatar: AvatarSource::Url("https://kde.org/avatar.png".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.setAvatar"
+        );
+        assert!(result.query_parameters().is_none());
+        assert!(result.multipart_payload().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(
+                json.get("avatarUrl"),
+                Some(PayloadValue::String("https://kde.org/avatar.png"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_set_avatar_image_values() {
+        let result = UsersSetAvatarMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            avatar: AvatarSource::Image {
+                file_name: "avatar.png".to_string(),
+                mime_type: "image/png".to_string(),
+                data: b"PNGDATA".to_vec(),
+            },
+        };
+        assert!(result.json_payload().is_none());
+
+        // Test multipart values.
+        if let Some(multipart) = &result.multipart_payload() {
+            assert!(multipart.fields.is_empty());
+            assert_eq!(multipart.files.len(), 1);
+            assert_eq!(multipart.files[0].field_name, "image");
+            assert_eq!(multipart.files[0].file_name, "avatar.png");
+            assert_eq!(multipart.files[0].mime_type, "image/png");
+            assert!(!multipart.form().unwrap().boundary().is_empty());
+        } else {
+            panic!("Impossble to get parameters");
+        }
+
+        // Names are escaped by reqwest, invalid mime type is an error
+        let result = UsersSetAvatarMethod {
+            avatar: AvatarSource::Image {
+                file_name: "my \"avatar\"\r\n.png".to_string(),
+                mime_type: "image/png".to_string(),
+                data: b"PNGDATA".to_vec(),
+            },
+            ..Default::default()
+        };
+        assert!(result.multipart_payload().unwrap().form().is_ok());
+        let result = UsersSetAvatarMethod {
+            avatar: AvatarSource::Image {
+                file_name: "avatar.png".to_string(),
+                mime_type: "not a mime type".to_string(),
+                data: b"PNGDATA".to_vec(),
+            },
+            ..Default::default()
+        };
+        assert!(result.multipart_payload().unwrap().form().is_err());
+    }
+
+    #[test]
+    fn test_users_reset_avatar_values() {
+        let result = UsersResetAvatarMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.resetAvatar"
+        );
+        assert!(result.query_parameters().is_none());
+        assert!(result.json_payload().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_users_set_status_values() {
+        let result = UsersSetStatusMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            status: Some("busy".to_string()),
+            message: Some("In a meeting".to_string()),
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.setStatus"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(json.get("status"), Some(PayloadValue::String("busy")));
+            assert_matches!(
+                json.get("message"),
+                Some(PayloadValue::String("In a meeting"))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_get_status_values() {
+        let result = UsersGetStatusMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            user_id: Some("foo".to_string()),
+        };
+        assert_eq!(result.method(), Method::GET);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.getStatus"
+        );
+        assert!(result.json_payload().is_none());
+
+        // Test query values.
+        if let Some(query) = &result.query_parameters() {
+            assert_eq!(query.get("userId"), Some(&"foo".to_string()));
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+
+    #[test]
+    fn test_users_delete_own_account_values() {
+        let result = UsersDeleteOwnAccountMethod {
+            settings: generate_default_settings(),
+            server_url: "https://mydomain.com".to_string(),
+            password: "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+                .to_string(),
+            confirm_relinquish: true,
+        };
+        assert_eq!(result.method(), Method::POST);
+        assert!(result.required_authentication());
+        assert_eq!(
+            result.build_endpoint().unwrap(),
+            "https://mydomain.com/api/v1/users.deleteOwnAccount"
+        );
+        assert!(result.query_parameters().is_none());
+
+        // Test Json values.
+        if let Some(json) = &result.json_payload() {
+            assert_matches!(
+                json.get("password"),
+                Some(PayloadValue::String(
+                    "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+                ))
+            );
+            assert_matches!(
+                json.get("confirmRelinquish"),
+                Some(PayloadValue::Bool(true))
+            );
+        } else {
+            panic!("Impossble to get parameters");
+        }
+    }
+}
@@ -4,6 +4,7 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 pub use crate::api::methods;
+pub use crate::errors::Error;
 extern crate assert_matches;
 
 mod api;
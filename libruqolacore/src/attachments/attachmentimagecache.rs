@@ -0,0 +1,355 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::urlpreview::previewimagecache::{PreviewImageCacheError, cache_file_name, read_limited};
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, LOCATION};
+use std::fmt;
+use std::path::PathBuf;
+
+// Uploads are limited by server settings, don't trust it.
+pub const DEFAULT_MAXIMUM_IMAGE_SIZE: u64 = 20 * 1024 * 1024;
+
+// Uploads can be stored on S3 or a CDN, server redirects to it.
+const MAXIMUM_REDIRECTS: usize = 10;
+
+#[derive(Debug)]
+pub enum AttachmentImageCacheError {
+    NoCacheDirectory,
+    InvalidUrl(String),
+    TooLarge(u64),
+    Download(String),
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for AttachmentImageCacheError {
+    fn from(error: std::io::Error) -> Self {
+        AttachmentImageCacheError::Io(error)
+    }
+}
+
+impl From<reqwest::Error> for AttachmentImageCacheError {
+    fn from(error: reqwest::Error) -> Self {
+        AttachmentImageCacheError::Download(error.to_string())
+    }
+}
+
+impl From<PreviewImageCacheError> for AttachmentImageCacheError {
+    fn from(error: PreviewImageCacheError) -> Self {
+        match error {
+            PreviewImageCacheError::TooLarge(size) => AttachmentImageCacheError::TooLarge(size),
+            error => AttachmentImageCacheError::Download(error.to_string()),
+        }
+    }
+}
+
+/* Debug output for AttachmentImageCacheError */
+impl fmt::Display for AttachmentImageCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AttachmentImageCacheError::NoCacheDirectory => write!(f, "No cache directory"),
+            AttachmentImageCacheError::InvalidUrl(url) => write!(f, "Invalid url: {}", url),
+            AttachmentImageCacheError::TooLarge(size) => {
+                write!(f, "Image too large: {} bytes", size)
+            }
+            AttachmentImageCacheError::Download(error) => write!(f, "Download error: {}", error),
+            AttachmentImageCacheError::Io(error) => write!(f, "Io error: {}", error),
+        }
+    }
+}
+
+// Uploaded images of messages stored on disk.
+// Uploads are private, so they are downloaded with user credentials.
+#[derive(Clone, Debug)]
+pub struct AttachmentImageCache {
+    pub settings: AuthenticationType,
+    // Credentials are only sent to this server
+    pub server_url: String,
+    // Images are not cached when it's empty
+    pub cache_directory: PathBuf,
+    // In bytes, larger images are not downloaded
+    pub maximum_image_size: u64,
+}
+
+impl Default for AttachmentImageCache {
+    fn default() -> Self {
+        AttachmentImageCache::new(AuthenticationType::None, String::default(), PathBuf::new())
+    }
+}
+
+impl AttachmentImageCache {
+    pub fn new(settings: AuthenticationType, server_url: String, cache_directory: PathBuf) -> Self {
+        AttachmentImageCache {
+            settings,
+            server_url,
+            cache_directory,
+            maximum_image_size: DEFAULT_MAXIMUM_IMAGE_SIZE,
+        }
+    }
+
+    fn image_path(&self, url: &str) -> Option<PathBuf> {
+        if self.cache_directory.as_os_str().is_empty() || url.is_empty() {
+            return None;
+        }
+        Some(self.cache_directory.join(cache_file_name(url)))
+    }
+
+    pub fn cached_image(&self, url: &str) -> Option<PathBuf> {
+        self.image_path(url).filter(|path| path.is_file())
+    }
+
+    pub fn store_image(
+        &self,
+        url: &str,
+        data: &[u8],
+    ) -> Result<PathBuf, AttachmentImageCacheError> {
+        let path = self
+            .image_path(url)
+            .ok_or(AttachmentImageCacheError::NoCacheDirectory)?;
+        std::fs::create_dir_all(&self.cache_directory)?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    // "x-auth-token" and "x-user-id", as rest api.
+    fn auth_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let AuthenticationType::Auth(auth) = &self.settings
+            && let Ok(auth_token) = HeaderValue::from_str(&auth.auth_token)
+            && let Ok(user_id) = HeaderValue::from_str(&auth.user_id)
+        {
+            headers.insert(HeaderName::from_static("x-auth-token"), auth_token);
+            headers.insert(HeaderName::from_static("x-user-id"), user_id);
+        }
+        headers
+    }
+
+    // Same scheme, host and port as server, server url can be stored without scheme.
+    fn is_server_url(&self, url: &reqwest::Url) -> bool {
+        let server_url =
+            if self.server_url.starts_with("https://") || self.server_url.starts_with("http://") {
+                self.server_url.clone()
+            } else {
+                format!("https://{}", self.server_url)
+            };
+        match reqwest::Url::parse(&server_url) {
+            Ok(server_url) => server_url.origin() == url.origin(),
+            Err(_) => false,
+        }
+    }
+
+    // Attachments can link to external images, they must not receive user credentials.
+    fn request_headers(&self, url: &reqwest::Url) -> HeaderMap {
+        if self.is_server_url(url) {
+            self.auth_headers()
+        } else {
+            HeaderMap::new()
+        }
+    }
+
+    // Redirects are followed by hand: reqwest keeps custom headers on a redirect to
+    // another host, credentials are only added when target is still the server.
+    async fn download(&self, url: reqwest::Url) -> Result<Vec<u8>, AttachmentImageCacheError> {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        let mut url = url;
+        for _ in 0..=MAXIMUM_REDIRECTS {
+            let response = client
+                .get(url.clone())
+                .headers(self.request_headers(&url))
+                .send()
+                .await?;
+            if response.status().is_redirection()
+                && let Some(location) = response.headers().get(LOCATION)
+            {
+                url = location
+                    .to_str()
+                    .ok()
+                    .and_then(|location| url.join(location).ok())
+                    .ok_or(AttachmentImageCacheError::InvalidUrl(url.to_string()))?;
+                continue;
+            }
+            let response = response.error_for_status()?;
+            return Ok(read_limited(response, self.maximum_image_size).await?);
+        }
+        Err(AttachmentImageCacheError::Download(
+            "Too many redirects".to_string(),
+        ))
+    }
+
+    // Returns cached image, downloads it when it's missing.
+    pub async fn image(&self, url: &str) -> Result<PathBuf, AttachmentImageCacheError> {
+        if let Some(path) = self.cached_image(url) {
+            return Ok(path);
+        }
+        if self.image_path(url).is_none() {
+            return Err(AttachmentImageCacheError::NoCacheDirectory);
+        }
+        let image_url = reqwest::Url::parse(url)
+            .map_err(|_| AttachmentImageCacheError::InvalidUrl(url.to_string()))?;
+        let data = self.download(image_url).await?;
+        self.store_image(url, &data)
+    }
+
+    pub fn remove_image(&self, url: &str) {
+        if let Some(path) = self.image_path(url) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::attachments::attachmentimagecache::{
+        AttachmentImageCache, AttachmentImageCacheError,
+    };
+    use libauthenticationbase::authenticationsettings::{AuthSettings, AuthenticationType};
+
+    #[test]
+    fn test_default_values() {
+        let cache = AttachmentImageCache::default();
+        assert!(cache.cache_directory.as_os_str().is_empty());
+        assert!(cache.auth_headers().is_empty());
+        assert!(cache.cached_image("https://kde.org/a.png").is_none());
+        assert!(matches!(
+            cache.store_image("https://kde.org/a.png", b"PNG"),
+            Err(AttachmentImageCacheError::NoCacheDirectory)
+        ));
+    }
+
+    #[test]
+    fn test_auth_headers() {
+        let cache = AttachmentImageCache::new(
+            AuthenticationType::Auth(AuthSettings {
+                auth_token: "token1".to_string(),
+                user_id: "user1".to_string(),
+            }),
+            "open.rocket.chat".to_string(),
+            std::path::PathBuf::new(),
+        );
+        let headers = cache.auth_headers();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["x-auth-token"], "token1");
+        assert_eq!(headers["x-user-id"], "user1");
+
+        let request_headers = |url: &str| cache.request_headers(&reqwest::Url::parse(url).unwrap());
+        let headers = request_headers("https://open.rocket.chat/file-upload/id/konqi.png");
+        assert_eq!(headers["x-auth-token"], "token1");
+        assert_eq!(headers["x-user-id"], "user1");
+
+        // External images
+        for url in [
+            "https://kde.org/konqi.png",
+            "http://open.rocket.chat/file-upload/id/konqi.png",
+            "https://open.rocket.chat:8443/file-upload/id/konqi.png",
+            "https://open.rocket.chat.kde.org/konqi.png",
+        ] {
+            let headers = request_headers(url);
+            assert!(!headers.contains_key("x-auth-token"), "{}", url);
+            assert!(!headers.contains_key("x-user-id"), "{}", url);
+        }
+    }
+
+    // Minimal http server, returns request of each connection.
+    fn start_server(responses: Vec<String>) -> (u16, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0; 4096];
+                let size = stream.read(&mut buffer).unwrap();
+                requests.push(String::from_utf8_lossy(&buffer[..size]).to_lowercase());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_other_host() {
+        let directory = std::env::temp_dir().join(format!(
+            "ruqola-attachmentimagecache-redirect-{}",
+            std::process::id()
+        ));
+        let (storage_port, storage) = start_server(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 3\r\nConnection: close\r\n\r\nPNG".to_string(),
+        ]);
+        let (server_port, server) = start_server(vec![format!(
+            "HTTP/1.1 302 Found\r\nLocation: http://127.0.0.1:{}/konqi.png\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            storage_port
+        )]);
+        let cache = AttachmentImageCache::new(
+            AuthenticationType::Auth(AuthSettings {
+                auth_token: "token1".to_string(),
+                user_id: "user1".to_string(),
+            }),
+            format!("http://127.0.0.1:{}", server_port),
+            directory.clone(),
+        );
+        let url = format!("http://127.0.0.1:{}/file-upload/id/konqi.png", server_port);
+        let path = cache.image(&url).await.unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"PNG");
+
+        let server_requests = server.join().unwrap();
+        assert!(server_requests[0].contains("x-auth-token: token1"));
+        let storage_requests = storage.join().unwrap();
+        assert!(storage_requests[0].starts_with("get /konqi.png"));
+        assert!(!storage_requests[0].contains("x-auth-token"));
+        assert!(!storage_requests[0].contains("x-user-id"));
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[tokio::test]
+    async fn test_too_large_image() {
+        let directory = std::env::temp_dir().join(format!(
+            "ruqola-attachmentimagecache-large-{}",
+            std::process::id()
+        ));
+        let (port, server) = start_server(vec![
+            "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\nPNGPNGPNG".to_string(),
+        ]);
+        let mut cache = AttachmentImageCache::new(
+            AuthenticationType::None,
+            String::default(),
+            directory.clone(),
+        );
+        cache.maximum_image_size = 4;
+        let url = format!("http://127.0.0.1:{}/konqi.png", port);
+        assert!(matches!(
+            cache.image(&url).await,
+            Err(AttachmentImageCacheError::TooLarge(_))
+        ));
+        assert!(cache.cached_image(&url).is_none());
+        server.join().unwrap();
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_store_image() {
+        let directory = std::env::temp_dir().join(format!(
+            "ruqola-attachmentimagecache-{}",
+            std::process::id()
+        ));
+        let cache = AttachmentImageCache::new(
+            AuthenticationType::None,
+            String::default(),
+            directory.clone(),
+        );
+        let url = "https://open.rocket.chat/file-upload/id/konqi.png";
+        assert!(cache.cached_image(url).is_none());
+        let path = cache.store_image(url, b"PNG").unwrap();
+        assert!(path.starts_with(&directory));
+        assert_eq!(cache.cached_image(url), Some(path));
+        cache.remove_image(url);
+        assert!(cache.cached_image(url).is_none());
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,342 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::message::messageattachment::{AttachmentType, MessageAttachment};
+use crate::message::messageattachmentfield::MessageAttachmentField;
+use crate::messageinfo::MessageInfo;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttachmentContent {
+    Image {
+        url: String,
+        // Base64 jpeg, empty when server didn't generate it
+        preview: String,
+        width: i64,
+        height: i64,
+        animated: bool,
+        size: i64,
+    },
+    Audio {
+        url: String,
+        mime_type: String,
+        size: i64,
+    },
+    Video {
+        url: String,
+        mime_type: String,
+        size: i64,
+    },
+    File {
+        url: String,
+        format: String,
+        size: i64,
+    },
+    Quote {
+        // Empty when link doesn't contain it
+        message_identifier: String,
+        message_link: String,
+        author_name: String,
+        author_icon: String,
+        text: String,
+        attachments: Vec<AttachmentViewModel>,
+    },
+    // Generated by apps/integrations
+    Text {
+        text: String,
+        field_rows: Vec<Vec<MessageAttachmentField>>,
+    },
+}
+
+// Attachment ready to be displayed, urls are absolute.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttachmentViewModel {
+    pub title: String,
+    pub title_url: String,
+    pub description: String,
+    // Border color of app attachments ("#ff0000")
+    pub color: String,
+    pub collapsed: bool,
+    pub content: AttachmentContent,
+}
+
+impl AttachmentViewModel {
+    // None for attachments which don't contain anything to show.
+    pub fn new(attachment: &MessageAttachment, server_url: &str) -> Option<Self> {
+        let resolve = |url: &str| resolve_url(server_url, url);
+        let content = match attachment.attachment_type() {
+            AttachmentType::Unknown => return None,
+            AttachmentType::Image => {
+                let dimensions = attachment.image_dimensions.unwrap_or_default();
+                AttachmentContent::Image {
+                    url: resolve(&attachment.image_url),
+                    preview: attachment.image_preview.clone(),
+                    width: if dimensions.width > 0 {
+                        dimensions.width
+                    } else {
+                        -1
+                    },
+                    height: if dimensions.height > 0 {
+                        dimensions.height
+                    } else {
+                        -1
+                    },
+                    animated: attachment.is_animated_image(),
+                    size: attachment.image_size,
+                }
+            }
+            AttachmentType::Audio => AttachmentContent::Audio {
+                url: resolve(&attachment.audio_url),
+                mime_type: attachment.audio_type.clone(),
+                size: attachment.audio_size,
+            },
+            AttachmentType::Video => AttachmentContent::Video {
+                url: resolve(&attachment.video_url),
+                mime_type: attachment.video_type.clone(),
+                size: attachment.video_size,
+            },
+            AttachmentType::File => AttachmentContent::File {
+                url: resolve(&attachment.link),
+                format: attachment.format.clone(),
+                size: attachment.size,
+            },
+            AttachmentType::Quote => AttachmentContent::Quote {
+                message_identifier: quoted_message_identifier(&attachment.message_link),
+                message_link: resolve(&attachment.message_link),
+                author_name: attachment.author_name.clone(),
+                author_icon: resolve(&attachment.author_icon),
+                text: attachment.text.clone(),
+                attachments: attachment
+                    .attachments
+                    .iter()
+                    .filter_map(|attachment| AttachmentViewModel::new(attachment, server_url))
+                    .collect(),
+            },
+            AttachmentType::NormalText => AttachmentContent::Text {
+                text: attachment.text.clone(),
+                field_rows: field_rows(&attachment.fields),
+            },
+        };
+        Some(AttachmentViewModel {
+            title: attachment.title.clone(),
+            title_url: resolve(&attachment.link),
+            description: attachment.description.clone(),
+            color: attachment.color.clone(),
+            collapsed: attachment.collapsed,
+            content,
+        })
+    }
+
+    // Image to download and cache, None for other kinds.
+    pub fn thumbnail_url(&self) -> Option<&str> {
+        match &self.content {
+            AttachmentContent::Image { url, .. } if !url.is_empty() => Some(url),
+            _ => None,
+        }
+    }
+}
+
+pub fn attachment_view_models(message: &MessageInfo, server_url: &str) -> Vec<AttachmentViewModel> {
+    message
+        .attachments
+        .iter()
+        .filter_map(|attachment| AttachmentViewModel::new(attachment, server_url))
+        .collect()
+}
+
+// Upload and avatar urls are relative to server ("/file-upload/…").
+pub fn resolve_url(server_url: &str, url: &str) -> String {
+    if url.is_empty() || url.starts_with("https://") || url.starts_with("http://") {
+        return url.to_string();
+    }
+    let server_url = if server_url.starts_with("https://") || server_url.starts_with("http://") {
+        server_url.to_string()
+    } else {
+        format!("https://{}", server_url)
+    };
+    match reqwest::Url::parse(&server_url).and_then(|base| base.join(url)) {
+        Ok(url) => url.to_string(),
+        Err(_) => url.to_string(),
+    }
+}
+
+// "https://server/channel/general?msg=HkTrJuSKnxBMsWcPL"
+fn quoted_message_identifier(message_link: &str) -> String {
+    message_link
+        .split_once('?')
+        .and_then(|(_, query)| query.split('&').find_map(|pair| pair.strip_prefix("msg=")))
+        .unwrap_or_default()
+        .to_string()
+}
+
+// Two short fields share a row, long fields use a full row.
+pub fn field_rows(fields: &[MessageAttachmentField]) -> Vec<Vec<MessageAttachmentField>> {
+    let mut rows: Vec<Vec<MessageAttachmentField>> = Vec::new();
+    for field in fields {
+        match rows.last_mut() {
+            Some(row) if field.short && row.len() == 1 && row[0].short => row.push(field.clone()),
+            _ => rows.push(vec![field.clone()]),
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::attachments::attachmentviewmodel::{
+        AttachmentContent, AttachmentViewModel, attachment_view_models, field_rows,
+        quoted_message_identifier, resolve_url,
+    };
+    use crate::message::messageattachment::MessageAttachment;
+    use crate::message::messageattachmentfield::MessageAttachmentField;
+    use crate::messageinfo::MessageInfo;
+    use std::fs::File;
+
+    const SERVER_URL: &str = "https://open.rocket.chat";
+
+    fn parse(filename: &str) -> Vec<AttachmentViewModel> {
+        let file = File::open(filename).expect("Failed to open file");
+        let attachments: Vec<MessageAttachment> =
+            serde_json::from_reader(file).expect("JSON was not well-formatted");
+        let message = MessageInfo {
+            attachments,
+            ..Default::default()
+        };
+        attachment_view_models(&message, SERVER_URL)
+    }
+
+    fn field(title: &str, short: bool) -> MessageAttachmentField {
+        MessageAttachmentField {
+            title: title.to_string(),
+            short,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        assert_eq!(resolve_url(SERVER_URL, ""), "");
+        assert_eq!(
+            resolve_url(SERVER_URL, "/file-upload/id/a.png"),
+            "https://open.rocket.chat/file-upload/id/a.png"
+        );
+        assert_eq!(
+            resolve_url("open.rocket.chat/", "/avatar/foo"),
+            "https://open.rocket.chat/avatar/foo"
+        );
+        assert_eq!(
+            resolve_url(SERVER_URL, "http://kde.org/a.png"),
+            "http://kde.org/a.png"
+        );
+    }
+
+    #[test]
+    fn test_quoted_message_identifier() {
+        assert_eq!(quoted_message_identifier(""), "");
+        assert_eq!(
+            quoted_message_identifier("https://open.rocket.chat/channel/general?msg=HkTr"),
+            "HkTr"
+        );
+        assert_eq!(
+            quoted_message_identifier("https://open.rocket.chat/group/dev?a=1&msg=HkTr"),
+            "HkTr"
+        );
+    }
+
+    #[test]
+    fn test_field_rows() {
+        assert!(field_rows(&[]).is_empty());
+        let rows = field_rows(&[
+            field("a", true),
+            field("b", true),
+            field("c", true),
+            field("d", false),
+            field("e", true),
+        ]);
+        let titles: Vec<Vec<&str>> = rows
+            .iter()
+            .map(|row| row.iter().map(|field| field.title.as_str()).collect())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![vec!["a", "b"], vec!["c"], vec!["d"], vec!["e"]]
+        );
+    }
+
+    #[test]
+    fn test_view_models() {
+        let models = parse("src/data/messageattachment/messageattachments.json");
+        assert_eq!(models.len(), 6);
+
+        let image = &models[0];
+        assert_eq!(image.title, "konqi.png");
+        assert_eq!(
+            image.title_url,
+            "https://open.rocket.chat/file-upload/6CvLxgRcJHgvPfhrk/konqi.png"
+        );
+        assert_eq!(image.thumbnail_url(), Some(image.title_url.as_str()));
+        assert!(matches!(
+            image.content,
+            AttachmentContent::Image {
+                width: 800,
+                height: 600,
+                animated: false,
+                size: 52431,
+                ..
+            }
+        ));
+
+        assert!(matches!(
+            &models[1].content,
+            AttachmentContent::Audio { mime_type, .. } if mime_type == "audio/ogg"
+        ));
+        assert!(models[1].thumbnail_url().is_none());
+        assert!(matches!(
+            &models[2].content,
+            AttachmentContent::Video { url, .. } if url.starts_with("https://open.rocket.chat/file-upload/")
+        ));
+        assert!(matches!(
+            &models[3].content,
+            AttachmentContent::File { format, size: 120034, .. } if format == "PDF"
+        ));
+
+        let AttachmentContent::Quote {
+            message_identifier,
+            message_link,
+            author_name,
+            author_icon,
+            text,
+            attachments,
+        } = &models[4].content
+        else {
+            panic!("Quote expected");
+        };
+        assert_eq!(message_identifier, "HkTrJuSKnxBMsWcPL");
+        assert_eq!(
+            message_link,
+            "https://open.rocket.chat/channel/general?msg=HkTrJuSKnxBMsWcPL"
+        );
+        assert_eq!(author_name, "foo");
+        assert_eq!(author_icon, "https://open.rocket.chat/avatar/foo");
+        assert_eq!(text, "Release is *tomorrow*");
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(
+            attachments[0].thumbnail_url(),
+            Some("https://open.rocket.chat/file-upload/Zs2v4zkTm9zyS6kqp/screenshot.png")
+        );
+
+        let app = &models[5];
+        assert_eq!(app.color, "#ff0000");
+        assert!(app.collapsed);
+        let AttachmentContent::Text { text, field_rows } = &app.content else {
+            panic!("Text expected");
+        };
+        assert_eq!(text, "Pipeline #1234 failed on master");
+        assert_eq!(field_rows.len(), 2);
+        assert_eq!(field_rows[0].len(), 2);
+
+        assert!(AttachmentViewModel::new(&MessageAttachment::new(), SERVER_URL).is_none());
+    }
+}
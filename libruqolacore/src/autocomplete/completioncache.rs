@@ -0,0 +1,235 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::autocomplete::completioncandidate::CompletionCandidate;
+use crate::autocomplete::completiontrigger::CompletionType;
+use crate::command::commands::Commands;
+use crate::emoticons::customemoji::CustomEmoji;
+use crate::emoticons::unicodeemoji::UnicodeEmoji;
+use crate::room::RoomType;
+use crate::rooms::Rooms;
+use crate::user::User;
+
+// Local data used before asking the server.
+#[derive(Clone, Debug, Default)]
+pub struct CompletionCache {
+    pub users: Vec<CompletionCandidate>,
+    pub rooms: Vec<CompletionCandidate>,
+    pub emojis: Vec<CompletionCandidate>,
+    pub commands: Vec<CompletionCandidate>,
+}
+
+impl CompletionCache {
+    pub fn new() -> Self {
+        CompletionCache {
+            ..Default::default()
+        }
+    }
+
+    pub fn candidates(&self, completion_type: CompletionType) -> &Vec<CompletionCandidate> {
+        match completion_type {
+            CompletionType::User => &self.users,
+            CompletionType::Room => &self.rooms,
+            CompletionType::Emoji => &self.emojis,
+            CompletionType::Command => &self.commands,
+        }
+    }
+
+    // Channels and groups are used for "#", direct rooms give known usernames for "@".
+    pub fn set_rooms(&mut self, rooms: &Rooms) {
+        self.rooms.clear();
+        for room in &rooms.rooms {
+            match room.channel_type {
+                RoomType::Channel | RoomType::Private => {
+                    let mut candidate = CompletionCandidate::new(CompletionType::Room, &room.name);
+                    candidate.identifier = room.room_id.clone();
+                    candidate.display_name = room.fname.clone();
+                    candidate.description = room.topic.clone();
+                    self.rooms.push(candidate);
+                }
+                RoomType::Direct => {
+                    if !self.users.iter().any(|c| c.name == room.name) {
+                        let mut candidate =
+                            CompletionCandidate::new(CompletionType::User, &room.name);
+                        candidate.display_name = room.fname.clone();
+                        self.users.push(candidate);
+                    }
+                }
+                RoomType::Unknown => {}
+            }
+        }
+    }
+
+    pub fn add_user(&mut self, user: &User) {
+        if let Some(candidate) = self.users.iter_mut().find(|c| c.name == user.username) {
+            candidate.identifier = user.user_id.clone();
+            candidate.display_name = user.name.clone();
+        } else {
+            let mut candidate = CompletionCandidate::new(CompletionType::User, &user.username);
+            candidate.identifier = user.user_id.clone();
+            candidate.display_name = user.name.clone();
+            self.users.push(candidate);
+        }
+    }
+
+    pub fn set_commands(&mut self, commands: &Commands) {
+        self.commands = commands
+            .commands
+            .iter()
+            .map(|command| {
+                let mut candidate =
+                    CompletionCandidate::new(CompletionType::Command, &command.command);
+                candidate.display_name = command.params.clone();
+                candidate.description = command.description.clone();
+                candidate
+            })
+            .collect();
+    }
+
+    // Each alias is a separate candidate.
+    pub fn set_custom_emojis(&mut self, emojis: &[CustomEmoji]) {
+        self.emojis
+            .retain(|candidate| candidate.identifier.is_empty());
+        for emoji in emojis {
+            for name in std::iter::once(&emoji.name).chain(emoji.aliases.iter()) {
+                let mut candidate = CompletionCandidate::new(CompletionType::Emoji, name);
+                candidate.identifier = emoji.identifier.clone();
+                self.emojis.push(candidate);
+            }
+        }
+    }
+
+    // Identifier and aliases, custom emojis are kept.
+    pub fn set_unicode_emojis(&mut self, emojis: &[UnicodeEmoji]) {
+        self.emojis
+            .retain(|candidate| !candidate.identifier.is_empty());
+        for emoji in emojis {
+            for name in std::iter::once(&emoji.identifier).chain(emoji.aliases.iter()) {
+                self.add_emoji(name, &emoji.unicode);
+            }
+        }
+    }
+
+    // Unicode emoji shortcode (without ":").
+    pub fn add_emoji(&mut self, shortcode: &str, display: &str) {
+        let mut candidate = CompletionCandidate::new(CompletionType::Emoji, shortcode);
+        candidate.display_name = display.to_string();
+        self.emojis.push(candidate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autocomplete::completioncache::CompletionCache;
+    use crate::autocomplete::completiontrigger::CompletionType;
+    use crate::command::commands::Commands;
+    use crate::emoticons::customemoji::CustomEmoji;
+    use crate::emoticons::unicodeemoji::UnicodeEmoji;
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+    use crate::room::{Room, RoomType};
+    use crate::rooms::Rooms;
+    use crate::user::User;
+
+    fn room(room_id: &str, name: &str, channel_type: RoomType) -> Room {
+        Room {
+            room_id: room_id.to_string(),
+            name: name.to_string(),
+            fname: name.to_uppercase(),
+            channel_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_empty_by_default() {
+        let b = CompletionCache::new();
+        assert!(b.users.is_empty());
+        assert!(b.rooms.is_empty());
+        assert!(b.emojis.is_empty());
+        assert!(b.commands.is_empty());
+    }
+
+    #[test]
+    fn test_set_rooms() {
+        let mut b = CompletionCache::new();
+        let mut rooms = Rooms::new();
+        rooms.rooms = vec![
+            room("1", "kde", RoomType::Channel),
+            room("2", "private", RoomType::Private),
+            room("3", "foo", RoomType::Direct),
+            room("4", "unknown", RoomType::Unknown),
+        ];
+        b.set_rooms(&rooms);
+        assert_eq!(b.rooms.len(), 2);
+        assert_eq!(b.rooms[0].identifier, "1");
+        assert_eq!(b.rooms[0].display_name, "KDE");
+        assert_eq!(b.users.len(), 1);
+        assert_eq!(b.users[0].name, "foo");
+        assert_eq!(b.candidates(CompletionType::Room).len(), 2);
+
+        // No duplicate user
+        b.set_rooms(&rooms);
+        assert_eq!(b.users.len(), 1);
+
+        let mut user = User::new();
+        user.user_id = "user1".to_string();
+        user.username = "foo".to_string();
+        user.name = "Foo Bla".to_string();
+        b.add_user(&user);
+        assert_eq!(b.users.len(), 1);
+        assert_eq!(b.users[0].identifier, "user1");
+        assert_eq!(b.users[0].display_name, "Foo Bla");
+    }
+
+    #[test]
+    fn test_set_commands() {
+        let mut b = CompletionCache::new();
+        let mut commands = Commands::new();
+        commands.parse_elements(
+            &std::fs::read_to_string("src/data/commands/command2.json")
+                .expect("Failed to open file"),
+        );
+        b.set_commands(&commands);
+        assert_eq!(b.commands.len(), 25);
+        assert!(
+            b.commands
+                .iter()
+                .all(|c| c.completion_type == CompletionType::Command)
+        );
+    }
+
+    #[test]
+    fn test_add_emoji() {
+        let mut b = CompletionCache::new();
+        b.add_emoji("smile", "😄");
+        assert_eq!(b.emojis.len(), 1);
+        b.set_custom_emojis(&[]);
+        assert_eq!(b.emojis.len(), 1);
+        assert_eq!(b.emojis[0].display_name, "😄");
+    }
+
+    #[test]
+    fn test_set_unicode_emojis() {
+        let mut b = CompletionCache::new();
+        b.set_custom_emojis(&[CustomEmoji {
+            identifier: "HxnYB2Fd6sDkyeRS2".to_string(),
+            name: "kde".to_string(),
+            ..Default::default()
+        }]);
+        let manager = UnicodeEmojiManager::new();
+        let food: Vec<UnicodeEmoji> = manager
+            .emojis_for_category("food")
+            .into_iter()
+            .cloned()
+            .collect();
+        b.set_unicode_emojis(&food);
+        assert!(b.emojis.iter().any(|c| c.name == "kde"));
+        let pizza = b.emojis.iter().find(|c| c.name == "pizza").unwrap();
+        assert_eq!(pizza.display_name, "🍕");
+        assert!(pizza.identifier.is_empty());
+        assert!(b.emojis.iter().all(|c| c.name != "smile"));
+    }
+}
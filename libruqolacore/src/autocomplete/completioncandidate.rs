@@ -0,0 +1,175 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::autocomplete::completiontrigger::CompletionType;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionCandidate {
+    pub completion_type: CompletionType,
+    // User/room/emoji identifier, can be empty (unicode emoji, command)
+    pub identifier: String,
+    // username, room name, emoji shortcode without ":", command without "/"
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+}
+
+impl CompletionCandidate {
+    pub fn new(completion_type: CompletionType, name: &str) -> Self {
+        CompletionCandidate {
+            completion_type,
+            identifier: String::default(),
+            name: name.to_string(),
+            display_name: String::default(),
+            description: String::default(),
+        }
+    }
+
+    // Text which replaces the trigger and the typed word.
+    pub fn insert_text(&self) -> String {
+        match self.completion_type {
+            CompletionType::User => format!("@{} ", self.name),
+            CompletionType::Room => format!("#{} ", self.name),
+            CompletionType::Emoji => format!(":{}: ", self.name),
+            CompletionType::Command => format!("/{} ", self.name),
+        }
+    }
+
+    // Lower is better, None when pattern doesn't match.
+    // 0: exact name, 1: name prefix, 2: display name prefix,
+    // 3: start of a word in name or display name, 4: contains
+    pub fn match_rank(&self, pattern: &str) -> Option<u32> {
+        if pattern.is_empty() {
+            return Some(1);
+        }
+        let pattern = pattern.to_lowercase();
+        let name = self.name.to_lowercase();
+        let display_name = self.display_name.to_lowercase();
+        if name == pattern {
+            Some(0)
+        } else if name.starts_with(&pattern) {
+            Some(1)
+        } else if display_name.starts_with(&pattern) {
+            Some(2)
+        } else if has_word_starting_with(&name, &pattern)
+            || has_word_starting_with(&display_name, &pattern)
+        {
+            Some(3)
+        } else if name.contains(&pattern) || display_name.contains(&pattern) {
+            Some(4)
+        } else {
+            None
+        }
+    }
+}
+
+fn has_word_starting_with(text: &str, pattern: &str) -> bool {
+    text.split([' ', '-', '_', '.'])
+        .skip(1)
+        .any(|word| word.starts_with(pattern))
+}
+
+// Keep candidates matching pattern, best first, without duplicates.
+pub fn rank_candidates(
+    candidates: Vec<CompletionCandidate>,
+    pattern: &str,
+    max_results: usize,
+) -> Vec<CompletionCandidate> {
+    let mut ranked: Vec<(u32, CompletionCandidate)> = candidates
+        .into_iter()
+        .filter_map(|c| c.match_rank(pattern).map(|rank| (rank, c)))
+        .collect();
+    ranked.sort_by(|(rank_a, a), (rank_b, b)| {
+        rank_a
+            .cmp(rank_b)
+            .then(a.name.len().cmp(&b.name.len()))
+            .then(a.name.cmp(&b.name))
+    });
+    let mut result: Vec<CompletionCandidate> = Vec::new();
+    for (_, candidate) in ranked {
+        if result.len() >= max_results {
+            break;
+        }
+        if !result
+            .iter()
+            .any(|c| c.completion_type == candidate.completion_type && c.name == candidate.name)
+        {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+/* Debug output for CompletionCandidate */
+impl fmt::Display for CompletionCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CompletionCandidate(type: {:?}, identifier: {}, name: {}, display_name: {})",
+            self.completion_type, self.identifier, self.name, self.display_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autocomplete::completioncandidate::{CompletionCandidate, rank_candidates};
+    use crate::autocomplete::completiontrigger::CompletionType;
+
+    fn user(name: &str, display_name: &str) -> CompletionCandidate {
+        let mut c = CompletionCandidate::new(CompletionType::User, name);
+        c.display_name = display_name.to_string();
+        c
+    }
+
+    #[test]
+    fn test_insert_text() {
+        assert_eq!(user("foo", "").insert_text(), "@foo ");
+        assert_eq!(
+            CompletionCandidate::new(CompletionType::Room, "kde").insert_text(),
+            "#kde "
+        );
+        assert_eq!(
+            CompletionCandidate::new(CompletionType::Emoji, "smile").insert_text(),
+            ":smile: "
+        );
+        assert_eq!(
+            CompletionCandidate::new(CompletionType::Command, "invite").insert_text(),
+            "/invite "
+        );
+    }
+
+    #[test]
+    fn test_match_rank() {
+        let c = user("laurent", "Laurent Montel");
+        assert_eq!(c.match_rank("laurent"), Some(0));
+        assert_eq!(c.match_rank("LAU"), Some(1));
+        assert_eq!(c.match_rank("mon"), Some(3));
+        assert_eq!(c.match_rank("ure"), Some(4));
+        assert_eq!(c.match_rank("xyz"), None);
+        assert_eq!(c.match_rank(""), Some(1));
+        assert_eq!(user("bot", "Laurent bot").match_rank("lau"), Some(2));
+    }
+
+    #[test]
+    fn test_rank_candidates() {
+        let candidates = vec![
+            user("alaurent", ""),
+            user("laurentmontel", ""),
+            user("laurent", ""),
+            user("foo", "Laurent Foo"),
+            user("bla", ""),
+            user("laurent", "duplicate"),
+        ];
+        let result = rank_candidates(candidates.clone(), "laurent", 10);
+        let names: Vec<&str> = result.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["laurent", "laurentmontel", "foo", "alaurent"]);
+
+        let result = rank_candidates(candidates, "laurent", 2);
+        assert_eq!(result.len(), 2);
+    }
+}
@@ -0,0 +1,356 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::autocomplete::completioncache::CompletionCache;
+use crate::autocomplete::completioncandidate::{CompletionCandidate, rank_candidates};
+use crate::autocomplete::completiontrigger::{CompletionTrigger, CompletionType};
+use crate::teams::teamcompleter::TeamCompleter;
+use crate::teams::teamroomcompleter::TeamRoomCompleter;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::methods::{
+    APIMethod, RoomsAutocompleteAvailableForTeamsMethod, RoomsAutocompleteChannelAndPrivateMethod,
+    TeamAutocompleteMethod, UsersAutocompleteMethod,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct UserItem {
+    #[serde(rename = "_id")]
+    identifier: String,
+    #[serde(default)]
+    username: String,
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct RoomItem {
+    #[serde(rename = "_id")]
+    identifier: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    fname: String,
+}
+
+#[derive(Deserialize)]
+struct ItemsResult<T> {
+    #[serde(default = "Vec::new")]
+    items: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct TeamsResult {
+    #[serde(default)]
+    teams: Vec<TeamCompleter>,
+}
+
+// Parse users.autocomplete result.
+pub fn parse_users_autocomplete(json: &str) -> Vec<CompletionCandidate> {
+    serde_json::from_str::<ItemsResult<UserItem>>(json)
+        .map(|result| {
+            result
+                .items
+                .into_iter()
+                .map(|item| {
+                    let mut candidate =
+                        CompletionCandidate::new(CompletionType::User, &item.username);
+                    candidate.identifier = item.identifier;
+                    candidate.display_name = item.name;
+                    candidate
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Parse rooms.autocomplete.channelAndPrivate result.
+pub fn parse_rooms_autocomplete(json: &str) -> Vec<CompletionCandidate> {
+    serde_json::from_str::<ItemsResult<RoomItem>>(json)
+        .map(|result| {
+            result
+                .items
+                .into_iter()
+                .map(|item| {
+                    let mut candidate = CompletionCandidate::new(CompletionType::Room, &item.name);
+                    candidate.identifier = item.identifier;
+                    candidate.display_name = item.fname;
+                    candidate
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Parse teams.autocomplete result.
+pub fn parse_teams_autocomplete(json: &str) -> Vec<TeamCompleter> {
+    serde_json::from_str::<TeamsResult>(json)
+        .map(|result| result.teams)
+        .unwrap_or_default()
+}
+
+// Parse rooms.autocomplete.availableForTeams result.
+pub fn parse_rooms_available_for_teams(json: &str) -> Vec<TeamRoomCompleter> {
+    serde_json::from_str::<ItemsResult<TeamRoomCompleter>>(json)
+        .map(|result| result.items)
+        .unwrap_or_default()
+}
+
+#[derive(Clone)]
+pub struct CompletionEngine {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub cache: CompletionCache,
+    pub debounce_delay: Duration,
+    pub max_results: usize,
+    // Incremented when text changes, an older server request is dropped after debounce
+    generation: Arc<AtomicU64>,
+}
+
+impl Default for CompletionEngine {
+    fn default() -> Self {
+        CompletionEngine::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl CompletionEngine {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        CompletionEngine {
+            settings,
+            server_url,
+            cache: CompletionCache::new(),
+            debounce_delay: Duration::from_millis(300),
+            max_results: 10,
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn local_candidates(&self, trigger: &CompletionTrigger) -> Vec<CompletionCandidate> {
+        rank_candidates(
+            self.cache.candidates(trigger.completion_type).clone(),
+            &trigger.word,
+            self.max_results,
+        )
+    }
+
+    // Called when text changes, local caches are displayed at once, then
+    // complete_server() result when there is one.
+    // Returns None when there is nothing to complete.
+    pub fn complete_local(
+        &self,
+        text: &str,
+        cursor: usize,
+    ) -> Option<(CompletionTrigger, Vec<CompletionCandidate>)> {
+        // Pending server request is obsolete
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        let trigger = CompletionTrigger::detect(text, cursor)?;
+        let local = self.local_candidates(&trigger);
+        Some((trigger, local))
+    }
+
+    // Local candidates merged with server candidates, after debounce.
+    // Returns None when server is not needed or when text changed meanwhile.
+    pub async fn complete_server(
+        &self,
+        trigger: &CompletionTrigger,
+        local: &[CompletionCandidate],
+    ) -> Option<Vec<CompletionCandidate>> {
+        // Emojis and commands are only known locally
+        let has_server_completion = matches!(
+            trigger.completion_type,
+            CompletionType::User | CompletionType::Room
+        );
+        if !has_server_completion || local.len() >= self.max_results {
+            return None;
+        }
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        tokio::time::sleep(self.debounce_delay).await;
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return None;
+        }
+
+        let remote = self.server_candidates(trigger).await;
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return None;
+        }
+        let mut candidates = local.to_vec();
+        candidates.extend(remote);
+        Some(rank_candidates(candidates, &trigger.word, self.max_results))
+    }
+
+    async fn server_candidates(&self, trigger: &CompletionTrigger) -> Vec<CompletionCandidate> {
+        if self.server_url.is_empty() {
+            return Vec::new();
+        }
+        match trigger.completion_type {
+            CompletionType::User => {
+                let result = UsersAutocompleteMethod {
+                    settings: self.settings.clone(),
+                    server_url: self.server_url.clone(),
+                    pattern: trigger.word.clone(),
+                }
+                .call()
+                .await;
+                result
+                    .map(|json| parse_users_autocomplete(&json))
+                    .unwrap_or_default()
+            }
+            CompletionType::Room => {
+                let result = RoomsAutocompleteChannelAndPrivateMethod {
+                    settings: self.settings.clone(),
+                    server_url: self.server_url.clone(),
+                    name: trigger.word.clone(),
+                }
+                .call()
+                .await;
+                result
+                    .map(|json| parse_rooms_autocomplete(&json))
+                    .unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    // Used when selecting a team (e.g. move room to team).
+    pub async fn search_teams(&self, name: &str) -> Vec<TeamCompleter> {
+        TeamAutocompleteMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            name: name.to_string(),
+        }
+        .call()
+        .await
+        .map(|json| parse_teams_autocomplete(&json))
+        .unwrap_or_default()
+    }
+
+    // Used when adding existing rooms to a team.
+    pub async fn search_rooms_available_for_teams(&self, name: &str) -> Vec<TeamRoomCompleter> {
+        RoomsAutocompleteAvailableForTeamsMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            name: name.to_string(),
+        }
+        .call()
+        .await
+        .map(|json| parse_rooms_available_for_teams(&json))
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autocomplete::completionengine::{
+        CompletionEngine, parse_rooms_autocomplete, parse_rooms_available_for_teams,
+        parse_teams_autocomplete, parse_users_autocomplete,
+    };
+    use crate::autocomplete::completiontrigger::CompletionType;
+    use crate::user::User;
+    use libauthenticationbase::authenticationsettings::AuthenticationType;
+    use std::time::Duration;
+
+    fn read(filename: &str) -> String {
+        std::fs::read_to_string(filename).expect("Failed to open file")
+    }
+
+    fn generate_engine() -> CompletionEngine {
+        let mut engine = CompletionEngine::new(AuthenticationType::None, String::default());
+        engine.debounce_delay = Duration::from_millis(20);
+        engine.cache.add_emoji("smile", "😄");
+        engine.cache.add_emoji("smiley", "😃");
+        engine.cache.add_emoji("heart", "❤️");
+        engine
+    }
+
+    #[test]
+    fn test_parse_users_autocomplete() {
+        let result =
+            parse_users_autocomplete(&read("src/data/autocomplete/usersautocomplete.json"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].completion_type, CompletionType::User);
+        assert_eq!(result[0].identifier, "Lymsiu4Mn6xjTAan4");
+        assert_eq!(result[0].name, "laurent");
+        assert_eq!(result[0].display_name, "Laurent Montel");
+        assert!(parse_users_autocomplete("invalid").is_empty());
+    }
+
+    #[test]
+    fn test_parse_rooms_autocomplete() {
+        let result =
+            parse_rooms_autocomplete(&read("src/data/autocomplete/roomsautocomplete.json"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].completion_type, CompletionType::Room);
+        assert_eq!(result[1].name, "kde-devel");
+        assert_eq!(result[1].display_name, "KDE Devel");
+
+        let result =
+            parse_rooms_available_for_teams(&read("src/data/autocomplete/roomsautocomplete.json"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].identifier, "GENERAL");
+    }
+
+    #[test]
+    fn test_parse_teams_autocomplete() {
+        let result =
+            parse_teams_autocomplete(&read("src/data/autocomplete/teamsautocomplete.json"));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "kde-team");
+        assert_eq!(result[0].identifier, "6783afc4f2c4d5a1e0a8b002");
+    }
+
+    #[tokio::test]
+    async fn test_complete_local() {
+        let engine = generate_engine();
+        let (trigger, candidates) = engine.complete_local("foo :smi", 8).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::Emoji);
+        let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["smile", "smiley"]);
+        // Emojis are only local
+        assert!(
+            engine
+                .complete_server(&trigger, &candidates)
+                .await
+                .is_none()
+        );
+
+        assert!(engine.complete_local("foo", 3).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_complete_local_users_first() {
+        let mut engine = generate_engine();
+        engine.cache.add_user(&User {
+            user_id: "Lymsiu4Mn6xjTAan4".to_string(),
+            username: "laurent".to_string(),
+            ..User::new()
+        });
+        // Local users are returned without waiting for server
+        let (trigger, candidates) = engine.complete_local("@lau", 4).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::User);
+        let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["laurent"]);
+        let merged = engine.complete_server(&trigger, &candidates).await.unwrap();
+        assert_eq!(merged, candidates);
+    }
+
+    #[tokio::test]
+    async fn test_complete_debounce() {
+        let engine = generate_engine();
+        let (trigger, local) = engine.complete_local("@la", 3).unwrap();
+        let (first, second) = tokio::join!(engine.complete_server(&trigger, &local), async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            let (trigger, local) = engine.complete_local("@lau", 4).unwrap();
+            assert_eq!(trigger.word, "lau");
+            engine.complete_server(&trigger, &local).await
+        });
+        assert!(first.is_none());
+        assert!(second.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompletionType {
+    User,
+    Room,
+    Emoji,
+    Command,
+}
+
+impl CompletionType {
+    pub fn from_trigger(c: char) -> Option<CompletionType> {
+        match c {
+            '@' => Some(CompletionType::User),
+            '#' => Some(CompletionType::Room),
+            ':' => Some(CompletionType::Emoji),
+            '/' => Some(CompletionType::Command),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionTrigger {
+    pub completion_type: CompletionType,
+    // Word typed after the trigger character
+    pub word: String,
+    // Byte range of the trigger character + word in the text
+    pub start: usize,
+    pub end: usize,
+}
+
+// Emoji completion starts after ":" + 2 characters, to avoid popups for ":)" or ":D"
+const EMOJI_MINIMUM_LENGTH: usize = 2;
+
+impl CompletionTrigger {
+    // Find the completion to show for text with cursor at byte position cursor.
+    pub fn detect(text: &str, cursor: usize) -> Option<CompletionTrigger> {
+        if cursor > text.len() || !text.is_char_boundary(cursor) {
+            return None;
+        }
+        let before = &text[..cursor];
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(0);
+        let current = &before[start..];
+        let trigger = current.chars().next()?;
+        let completion_type = CompletionType::from_trigger(trigger)?;
+        let word = &current[trigger.len_utf8()..];
+        match completion_type {
+            // Commands are only available at the beginning of the message
+            CompletionType::Command if start != 0 => return None,
+            CompletionType::Emoji if word.chars().count() < EMOJI_MINIMUM_LENGTH => return None,
+            // ":smile:" is already complete
+            CompletionType::Emoji if word.contains(':') => return None,
+            _ => {}
+        }
+        Some(CompletionTrigger {
+            completion_type,
+            word: word.to_string(),
+            start,
+            end: cursor,
+        })
+    }
+
+    // Replace the trigger + word by insert_text, returns new text and new cursor position.
+    pub fn apply(&self, text: &str, insert_text: &str) -> (String, usize) {
+        let mut result = String::with_capacity(text.len() + insert_text.len());
+        result.push_str(&text[..self.start]);
+        result.push_str(insert_text);
+        let cursor = result.len();
+        result.push_str(&text[self.end..]);
+        (result, cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autocomplete::completiontrigger::{CompletionTrigger, CompletionType};
+
+    #[test]
+    fn test_detect_user() {
+        let text = "hello @lau";
+        let trigger = CompletionTrigger::detect(text, text.len()).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::User);
+        assert_eq!(trigger.word, "lau");
+        assert_eq!(trigger.start, 6);
+        assert_eq!(trigger.end, 10);
+
+        let trigger = CompletionTrigger::detect("@", 1).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::User);
+        assert!(trigger.word.is_empty());
+    }
+
+    #[test]
+    fn test_detect_room() {
+        let text = "see #kde-dev and";
+        let trigger = CompletionTrigger::detect(text, 12).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::Room);
+        assert_eq!(trigger.word, "kde-dev");
+        assert!(CompletionTrigger::detect(text, text.len()).is_none());
+    }
+
+    #[test]
+    fn test_detect_emoji() {
+        assert!(CompletionTrigger::detect(":s", 2).is_none());
+        assert!(CompletionTrigger::detect("foo :)", 6).is_none());
+        assert!(CompletionTrigger::detect("12:30", 5).is_none());
+        assert!(CompletionTrigger::detect(":smile:", 7).is_none());
+        let trigger = CompletionTrigger::detect("foo :sm", 7).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::Emoji);
+        assert_eq!(trigger.word, "sm");
+    }
+
+    #[test]
+    fn test_detect_command() {
+        let trigger = CompletionTrigger::detect("/inv", 4).unwrap();
+        assert_eq!(trigger.completion_type, CompletionType::Command);
+        assert_eq!(trigger.word, "inv");
+        assert!(CompletionTrigger::detect("foo /inv", 8).is_none());
+        assert!(CompletionTrigger::detect("/invite @foo bla", 16).is_none());
+    }
+
+    #[test]
+    fn test_detect_invalid() {
+        assert!(CompletionTrigger::detect("", 0).is_none());
+        assert!(CompletionTrigger::detect("foo", 3).is_none());
+        assert!(CompletionTrigger::detect("foo", 10).is_none());
+        assert!(CompletionTrigger::detect("foo@bar", 7).is_none());
+        // Not a char boundary
+        assert!(CompletionTrigger::detect("@é", 2).is_none());
+        assert!(CompletionTrigger::detect("@é", 3).is_some());
+    }
+
+    #[test]
+    fn test_apply() {
+        let text = "hello @lau how are you";
+        let trigger = CompletionTrigger::detect(text, 10).unwrap();
+        let (result, cursor) = trigger.apply(text, "@laurent ");
+        assert_eq!(result, "hello @laurent  how are you");
+        assert_eq!(cursor, 15);
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use serde::Deserialize;
+use std::fmt;
+
+// Language supported by translation provider of server.
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct AutoTranslateLanguage {
+    // Code used in subscription and message translations ("fr", "pt-BR")
+    pub language: String,
+    // Translated in language asked to server
+    pub name: String,
+}
+
+impl AutoTranslateLanguage {
+    pub fn new() -> Self {
+        AutoTranslateLanguage {
+            language: String::default(),
+            name: String::default(),
+        }
+    }
+
+    // Some providers don't send names.
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.language
+        } else {
+            &self.name
+        }
+    }
+}
+
+/*
+Debug output for AutoTranslateLanguage
+*/
+impl fmt::Display for AutoTranslateLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AutoTranslateLanguage(language: {}, name: {})",
+            self.language, self.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autotranslate::autotranslatelanguage::AutoTranslateLanguage;
+
+    #[test]
+    fn test_is_empty() {
+        let b = AutoTranslateLanguage::new();
+        assert!(b.language.is_empty());
+        assert!(b.name.is_empty());
+        assert!(b.display_name().is_empty());
+    }
+
+    #[test]
+    fn test_display_name() {
+        let b: AutoTranslateLanguage = serde_json::from_str(r#"{"language": "fr"}"#).unwrap();
+        assert_eq!(b.display_name(), "fr");
+        let b: AutoTranslateLanguage =
+            serde_json::from_str(r#"{"language": "fr", "name": "French"}"#).unwrap();
+        assert_eq!(b.display_name(), "French");
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::autotranslate::autotranslatelanguage::AutoTranslateLanguage;
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct AutoTranslateLanguages {
+    pub languages: Vec<AutoTranslateLanguage>,
+}
+
+impl Default for AutoTranslateLanguages {
+    fn default() -> Self {
+        AutoTranslateLanguages::new()
+    }
+}
+
+impl AutoTranslateLanguages {
+    pub fn new() -> Self {
+        AutoTranslateLanguages {
+            languages: Vec::<AutoTranslateLanguage>::default(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty()
+    }
+
+    pub fn parse_elements(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<AutoTranslateLanguages>(json) {
+            *self = val
+        }
+    }
+
+    pub fn find(&self, language: &str) -> Option<&AutoTranslateLanguage> {
+        self.languages.iter().find(|info| info.language == language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autotranslate::autotranslatelanguages::AutoTranslateLanguages;
+
+    // For test !
+    pub fn parse(filename: &str) -> AutoTranslateLanguages {
+        let mut languages = AutoTranslateLanguages::new();
+        languages.parse_elements(&std::fs::read_to_string(filename).expect("Failed to open file"));
+        languages
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = AutoTranslateLanguages::new();
+        assert!(b.is_empty());
+        assert!(b.find("fr").is_none());
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let b = parse("src/data/autotranslate/supportedlanguages.json");
+        assert_eq!(b.languages.len(), 5);
+        assert_eq!(b.find("fr").unwrap().name, "Français");
+        assert_eq!(
+            b.find("pt-BR").unwrap().display_name(),
+            "Portugais (Brésil)"
+        );
+        assert!(b.find("it").is_none());
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::autotranslate::autotranslatelanguages::AutoTranslateLanguages;
+use crate::autotranslate::roomautotranslatesettings::RoomAutoTranslateSettings;
+use crate::message::messagetranslations::MessageTranslations;
+use crate::messageinfo::MessageInfo;
+use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, AutoTranslateGetSupportedLanguagesMethod, AutoTranslateSaveSettingsMethod,
+    AutoTranslateSetting, AutoTranslateTranslateMessageMethod,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct TranslateMessageResult {
+    message: MessageTranslations,
+}
+
+// Parse autotranslate.translateMessage result.
+pub fn parse_translated_message(json: &str) -> Option<MessageTranslations> {
+    serde_json::from_str::<TranslateMessageResult>(json)
+        .map(|result| result.message)
+        .ok()
+}
+
+#[derive(Clone)]
+pub struct AutoTranslateManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    // Language of user preferences, used by rooms without language
+    pub user_language: String,
+    pub languages: AutoTranslateLanguages,
+}
+
+impl Default for AutoTranslateManager {
+    fn default() -> Self {
+        AutoTranslateManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl AutoTranslateManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        AutoTranslateManager {
+            settings,
+            server_url,
+            user_language: String::default(),
+            languages: AutoTranslateLanguages::new(),
+        }
+    }
+
+    pub fn set_preferences(&mut self, preferences: &OwnUserPreferences) {
+        self.user_language = preferences.language.clone();
+    }
+
+    pub fn target_language<'a>(
+        &'a self,
+        room_settings: &'a RoomAutoTranslateSettings,
+    ) -> Option<&'a str> {
+        room_settings.target_language(&self.user_language)
+    }
+
+    // Translation when room is translated and server already translated message,
+    // original text otherwise.
+    pub fn translated_text<'a>(
+        &self,
+        message: &'a MessageInfo,
+        room_settings: &RoomAutoTranslateSettings,
+    ) -> &'a str {
+        self.target_language(room_settings)
+            .and_then(|language| message.translations.translated_text(language))
+            .unwrap_or(&message.message)
+    }
+
+    // Languages names are translated in user language.
+    pub async fn load_languages(&mut self) -> Result<&AutoTranslateLanguages, Error> {
+        let result = AutoTranslateGetSupportedLanguagesMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            target_language: self.user_language.clone(),
+        }
+        .call()
+        .await?;
+        self.languages.parse_elements(&result);
+        Ok(&self.languages)
+    }
+
+    pub async fn save_room_setting(
+        &self,
+        room_id: &str,
+        setting: AutoTranslateSetting,
+    ) -> Result<String, Error> {
+        // Server needs a language when room is translated for first time
+        let default_language = match setting {
+            AutoTranslateSetting::AutoTranslate(true) if !self.user_language.is_empty() => {
+                Some(self.user_language.clone())
+            }
+            _ => None,
+        };
+        AutoTranslateSaveSettingsMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            room_id: room_id.to_string(),
+            setting,
+            default_language,
+        }
+        .call()
+        .await
+    }
+
+    // Translate a message sent before auto translate was enabled, result is merged in
+    // message translations.
+    pub async fn translate_message(
+        &self,
+        message: &mut MessageInfo,
+        target_language: &str,
+    ) -> Result<(), Error> {
+        let result = AutoTranslateTranslateMessageMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            message_id: message.identifier.clone(),
+            target_language: target_language.to_string(),
+        }
+        .call()
+        .await?;
+        let translations = parse_translated_message(&result).ok_or(Error::JsonDecode(result))?;
+        message.translations.merge(translations);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autotranslate::autotranslatemanager::{
+        AutoTranslateManager, parse_translated_message,
+    };
+    use crate::autotranslate::roomautotranslatesettings::RoomAutoTranslateSettings;
+    use crate::message::messagetranslations::MessageTranslations;
+    use crate::messageinfo::MessageInfo;
+    use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+
+    fn generate_message() -> MessageInfo {
+        let translations = parse_translated_message(
+            &std::fs::read_to_string("src/data/autotranslate/translatemessage.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        MessageInfo {
+            message: "Release is tomorrow".to_string(),
+            translations,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_translated_message() {
+        let message = generate_message();
+        assert_eq!(
+            message.translations.translated_text("fr"),
+            Some("La version sort demain")
+        );
+        assert!(parse_translated_message(r#"{"success": false}"#).is_none());
+        assert_eq!(
+            parse_translated_message(r#"{"message": {"_id": "a"}}"#),
+            Some(MessageTranslations::new())
+        );
+    }
+
+    #[test]
+    fn test_translated_text() {
+        let mut manager = AutoTranslateManager::default();
+        manager.set_preferences(&OwnUserPreferences {
+            language: "de".to_string(),
+            ..Default::default()
+        });
+        let message = generate_message();
+
+        // Room not translated
+        let mut room_settings = RoomAutoTranslateSettings::default();
+        assert_eq!(
+            manager.translated_text(&message, &room_settings),
+            "Release is tomorrow"
+        );
+
+        // User language
+        room_settings.auto_translate = true;
+        assert_eq!(manager.target_language(&room_settings), Some("de"));
+        assert_eq!(
+            manager.translated_text(&message, &room_settings),
+            "Die Veröffentlichung ist morgen"
+        );
+
+        room_settings.auto_translate_language = "fr-CA".to_string();
+        assert_eq!(
+            manager.translated_text(&message, &room_settings),
+            "La version sort demain"
+        );
+
+        // Not translated yet
+        room_settings.auto_translate_language = "es".to_string();
+        assert_eq!(
+            manager.translated_text(&message, &room_settings),
+            "Release is tomorrow"
+        );
+    }
+}
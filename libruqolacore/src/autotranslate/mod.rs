@@ -0,0 +1,9 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+pub mod autotranslatelanguage;
+pub mod autotranslatelanguages;
+pub mod autotranslatemanager;
+pub mod roomautotranslatesettings;
@@ -0,0 +1,58 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use serde::Deserialize;
+
+// Auto translate settings of a room, stored in subscription.
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct RoomAutoTranslateSettings {
+    #[serde(rename = "autoTranslate")]
+    pub auto_translate: bool,
+    // Empty when user didn't choose a language, user language is used
+    #[serde(rename = "autoTranslateLanguage")]
+    pub auto_translate_language: String,
+}
+
+impl RoomAutoTranslateSettings {
+    // Language of translations to display, None when messages are not translated.
+    pub fn target_language<'a>(&'a self, user_language: &'a str) -> Option<&'a str> {
+        if !self.auto_translate {
+            return None;
+        }
+        let language = if self.auto_translate_language.is_empty() {
+            user_language
+        } else {
+            &self.auto_translate_language
+        };
+        (!language.is_empty()).then_some(language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::autotranslate::roomautotranslatesettings::RoomAutoTranslateSettings;
+
+    #[test]
+    fn test_default_values() {
+        let settings = RoomAutoTranslateSettings::default();
+        assert!(!settings.auto_translate);
+        assert!(settings.auto_translate_language.is_empty());
+        assert!(settings.target_language("fr").is_none());
+    }
+
+    #[test]
+    fn test_target_language() {
+        let mut settings = RoomAutoTranslateSettings {
+            auto_translate: true,
+            auto_translate_language: String::new(),
+        };
+        assert_eq!(settings.target_language("de"), Some("de"));
+        assert!(settings.target_language("").is_none());
+        settings.auto_translate_language = "es".to_string();
+        assert_eq!(settings.target_language("de"), Some("es"));
+    }
+}
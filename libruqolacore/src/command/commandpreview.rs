@@ -23,6 +23,20 @@ pub enum TypePreview {
     Other,
 }
 
+impl TypePreview {
+    // Value sent back to the server when running a preview
+    pub fn as_str(&self) -> &str {
+        match self {
+            TypePreview::Unknown => "",
+            TypePreview::Image => "image",
+            TypePreview::Video => "video",
+            TypePreview::Audio => "audio",
+            TypePreview::Text => "text",
+            TypePreview::Other => "other",
+        }
+    }
+}
+
 fn typepreview_unknown() -> TypePreview {
     TypePreview::Unknown
 }
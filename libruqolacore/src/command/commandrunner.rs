@@ -0,0 +1,338 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::command::commandinfo::CommandInfo;
+use crate::command::commandpreview::CommandPreview;
+use crate::command::commands::Commands;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, CommandsPreviewMethod, RunCommandPreviewMethod, RunCommandsMethod,
+};
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParsedCommand {
+    // Command name without "/"
+    pub command: String,
+    pub params: String,
+}
+
+impl ParsedCommand {
+    // Parse "/command args" typed in composer.
+    pub fn parse(text: &str) -> Option<ParsedCommand> {
+        let text = text.trim_start();
+        let text = text.strip_prefix('/')?;
+        let (command, params) = match text.split_once(char::is_whitespace) {
+            Some((command, params)) => (command, params.trim()),
+            None => (text, ""),
+        };
+        // "//" or "/ " is not a command
+        if command.is_empty() || command.starts_with('/') {
+            return None;
+        }
+        Some(ParsedCommand {
+            command: command.to_string(),
+            params: params.to_string(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    UnknownCommand(String),
+    // Command name and expected params
+    MissingParameters(String, String),
+    // Command must be handled by client
+    ClientOnly(String),
+    RestApi(Error),
+}
+
+impl From<Error> for CommandError {
+    fn from(error: Error) -> Self {
+        CommandError::RestApi(error)
+    }
+}
+
+/* Debug output for CommandError */
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::UnknownCommand(command) => write!(f, "Unknown command: /{}", command),
+            CommandError::MissingParameters(command, params) => {
+                write!(f, "Missing parameters: /{} {}", command, params)
+            }
+            CommandError::ClientOnly(command) => write!(f, "Client only command: /{}", command),
+            CommandError::RestApi(error) => write!(f, "Rest api error: {:?}", error),
+        }
+    }
+}
+
+// Params hint is a placeholder ("@username", "#channel", "your_message_optional") or a
+// translation key. Only known placeholders are checked.
+fn requires_parameters(params: &str) -> bool {
+    if params.is_empty() || params.contains("optional") {
+        return false;
+    }
+    params.starts_with('@') || params.starts_with('#') || params == "your_message"
+}
+
+#[derive(Deserialize)]
+struct PreviewResult {
+    #[serde(default)]
+    items: Vec<CommandPreview>,
+}
+
+#[derive(Deserialize)]
+struct CommandsPreviewResult {
+    preview: PreviewResult,
+}
+
+// Parse commands.preview result, invalid items are dropped.
+pub fn parse_previews(json: &str) -> Vec<CommandPreview> {
+    serde_json::from_str::<CommandsPreviewResult>(json)
+        .map(|result| {
+            result
+                .preview
+                .items
+                .into_iter()
+                .filter(|preview| preview.is_valid())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CommandContext {
+    pub room_id: String,
+    // Thread message id when command is typed in a thread
+    pub tmid: Option<String>,
+    pub trigger_id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct CommandRunner {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub commands: Commands,
+}
+
+impl Default for CommandRunner {
+    fn default() -> Self {
+        CommandRunner::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl CommandRunner {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        CommandRunner {
+            settings,
+            server_url,
+            commands: Commands::new(),
+        }
+    }
+
+    pub fn find(&self, command: &str) -> Option<&CommandInfo> {
+        self.commands
+            .commands
+            .iter()
+            .find(|info| info.command == command)
+    }
+
+    // Parse and validate text, returns None when text is not a command.
+    pub fn prepare(
+        &self,
+        text: &str,
+    ) -> Option<Result<(ParsedCommand, &CommandInfo), CommandError>> {
+        let parsed = ParsedCommand::parse(text)?;
+        let Some(info) = self.find(&parsed.command) else {
+            return Some(Err(CommandError::UnknownCommand(parsed.command)));
+        };
+        if info.client_only {
+            return Some(Err(CommandError::ClientOnly(parsed.command)));
+        }
+        if parsed.params.is_empty() && requires_parameters(&info.params) {
+            return Some(Err(CommandError::MissingParameters(
+                parsed.command,
+                info.params.clone(),
+            )));
+        }
+        Some(Ok((parsed, info)))
+    }
+
+    // Previews are only fetched for commands which provide them.
+    pub async fn previews(
+        &self,
+        parsed: &ParsedCommand,
+        context: &CommandContext,
+    ) -> Result<Vec<CommandPreview>, CommandError> {
+        let provides_preview = self
+            .find(&parsed.command)
+            .is_some_and(|info| info.provides_preview);
+        if !provides_preview {
+            return Ok(Vec::new());
+        }
+        let result = CommandsPreviewMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            command: parsed.command.clone(),
+            params: parsed.params.clone(),
+            room_id: context.room_id.clone(),
+        }
+        .call()
+        .await?;
+        Ok(parse_previews(&result))
+    }
+
+    pub async fn run(
+        &self,
+        parsed: &ParsedCommand,
+        context: &CommandContext,
+    ) -> Result<String, CommandError> {
+        Ok(RunCommandsMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            command: parsed.command.clone(),
+            params: parsed.params.clone(),
+            room_id: context.room_id.clone(),
+            tmid: context.tmid.clone(),
+            trigger_id: context.trigger_id.clone(),
+        }
+        .call()
+        .await?)
+    }
+
+    pub async fn run_preview(
+        &self,
+        parsed: &ParsedCommand,
+        context: &CommandContext,
+        preview: &CommandPreview,
+    ) -> Result<String, CommandError> {
+        Ok(RunCommandPreviewMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            command: parsed.command.clone(),
+            params: parsed.params.clone(),
+            room_id: context.room_id.clone(),
+            tmid: context.tmid.clone(),
+            trigger_id: context.trigger_id.clone(),
+            preview_id: preview.id.clone(),
+            preview_type: preview.type_preview.as_str().to_string(),
+            preview_value: preview.value.clone(),
+        }
+        .call()
+        .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::commandpreview::TypePreview;
+    use crate::command::commandrunner::{
+        CommandContext, CommandError, CommandRunner, ParsedCommand, parse_previews,
+    };
+
+    fn generate_runner() -> CommandRunner {
+        let mut runner = CommandRunner::default();
+        runner.commands.parse_elements(
+            &std::fs::read_to_string("src/data/commands/command2.json")
+                .expect("Failed to open file"),
+        );
+        runner
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            ParsedCommand::parse("/invite @foo"),
+            Some(ParsedCommand {
+                command: "invite".to_string(),
+                params: "@foo".to_string(),
+            })
+        );
+        assert_eq!(
+            ParsedCommand::parse("  /giphy  funny cat  "),
+            Some(ParsedCommand {
+                command: "giphy".to_string(),
+                params: "funny cat".to_string(),
+            })
+        );
+        let parsed = ParsedCommand::parse("/leave").unwrap();
+        assert_eq!(parsed.command, "leave");
+        assert!(parsed.params.is_empty());
+
+        assert!(ParsedCommand::parse("hello /leave").is_none());
+        assert!(ParsedCommand::parse("/").is_none());
+        assert!(ParsedCommand::parse("/ foo").is_none());
+        assert!(ParsedCommand::parse("//foo").is_none());
+        assert!(ParsedCommand::parse("").is_none());
+    }
+
+    #[test]
+    fn test_prepare() {
+        let runner = generate_runner();
+        assert!(runner.prepare("hello").is_none());
+
+        let (parsed, info) = runner.prepare("/invite @foo").unwrap().unwrap();
+        assert_eq!(parsed.params, "@foo");
+        assert_eq!(info.params, "@username");
+
+        assert!(matches!(
+            runner.prepare("/invite"),
+            Some(Err(CommandError::MissingParameters(command, params))) if command == "invite" && params == "@username"
+        ));
+        assert!(matches!(
+            runner.prepare("/me"),
+            Some(Err(CommandError::MissingParameters(_, _)))
+        ));
+        // Optional params or unknown placeholders
+        assert!(matches!(runner.prepare("/shrug"), Some(Ok(_))));
+        assert!(matches!(runner.prepare("/leave"), Some(Ok(_))));
+        assert!(matches!(runner.prepare("/topic"), Some(Ok(_))));
+        assert!(matches!(
+            runner.prepare("/jira PROJ-1"),
+            Some(Err(CommandError::UnknownCommand(command))) if command == "jira"
+        ));
+    }
+
+    #[test]
+    fn test_prepare_client_only() {
+        let mut runner = generate_runner();
+        runner.commands.commands[0].client_only = true;
+        assert!(matches!(
+            runner.prepare("/slackbridge-import"),
+            Some(Err(CommandError::ClientOnly(_)))
+        ));
+    }
+
+    #[test]
+    fn test_parse_previews() {
+        let previews = parse_previews(
+            &std::fs::read_to_string("src/data/commands/commandpreview.json")
+                .expect("Failed to open file"),
+        );
+        assert_eq!(previews.len(), 3);
+        assert_eq!(previews[0].id, "JIX9t2j0ZTN9S");
+        assert_eq!(previews[0].type_preview, TypePreview::Image);
+        assert_eq!(previews[1].type_preview, TypePreview::Video);
+        assert_eq!(previews[2].type_preview, TypePreview::Text);
+        assert_eq!(previews[2].value, "Cat facts");
+
+        assert!(parse_previews("{}").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_previews_without_provider() {
+        let runner = generate_runner();
+        let parsed = ParsedCommand::parse("/invite @foo").unwrap();
+        let previews = runner
+            .previews(&parsed, &CommandContext::default())
+            .await
+            .unwrap();
+        assert!(previews.is_empty());
+    }
+}
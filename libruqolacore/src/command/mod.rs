@@ -5,4 +5,5 @@
  */
 pub mod commandinfo;
 pub mod commandpreview;
+pub mod commandrunner;
 pub mod commands;
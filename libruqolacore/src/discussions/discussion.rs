@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::fmt;
+
+// Discussion room as returned by rooms.getDiscussions and rooms.createDiscussion.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct Discussion {
+    #[serde(rename = "_id")]
+    pub discussion_room_id: String,
+    #[serde(rename = "prid")]
+    pub parent_room_id: String,
+    // Display name, "name" is generated by server
+    #[serde(rename = "fname")]
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "msgs")]
+    pub number_of_messages: u64,
+    #[serde(rename = "usersCount")]
+    pub users_count: u64,
+    #[serde(rename = "ts")]
+    pub time_stamp: Option<DateTime<Utc>>,
+    // Not set when nobody wrote in discussion
+    #[serde(rename = "lm")]
+    pub last_message: Option<DateTime<Utc>>,
+}
+
+impl Default for Discussion {
+    fn default() -> Self {
+        Discussion::new()
+    }
+}
+
+impl Discussion {
+    pub fn new() -> Self {
+        Discussion {
+            discussion_room_id: String::default(),
+            parent_room_id: String::default(),
+            name: String::default(),
+            description: String::default(),
+            number_of_messages: 0,
+            users_count: 0,
+            time_stamp: None,
+            last_message: None,
+        }
+    }
+
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.last_message.or(self.time_stamp)
+    }
+}
+
+/*
+Debug output for Discussion
+*/
+impl fmt::Display for Discussion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(discussion room id: {}, parent room id: {}, name: {}, description: {}, number of messages: {}, last message: {:?})",
+            self.discussion_room_id,
+            self.parent_room_id,
+            self.name,
+            self.description,
+            self.number_of_messages,
+            self.last_message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::discussions::discussion::Discussion;
+    use chrono::DateTime;
+
+    #[test]
+    fn test_is_empty() {
+        let b = Discussion::new();
+        assert!(b.discussion_room_id.is_empty());
+        assert!(b.parent_room_id.is_empty());
+        assert!(b.name.is_empty());
+        assert!(b.description.is_empty());
+        assert_eq!(b.number_of_messages, 0);
+        assert!(b.last_message.is_none());
+        assert!(b.last_activity().is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let b: Discussion = serde_json::from_value(serde_json::json!({
+            "_id": "7gkGrmQ2TyXBj8ZfN", "fname": "Release 2.6", "prid": "GENERAL",
+            "msgs": 12, "usersCount": 4, "ts": "2026-01-02T09:00:00.000Z",
+            "lm": "2026-01-10T18:30:00.000Z"
+        }))
+        .unwrap();
+        assert_eq!(b.discussion_room_id, "7gkGrmQ2TyXBj8ZfN");
+        assert_eq!(b.parent_room_id, "GENERAL");
+        assert_eq!(b.name, "Release 2.6");
+        assert_eq!(b.number_of_messages, 12);
+        assert_eq!(b.users_count, 4);
+        assert_eq!(
+            b.last_activity(),
+            DateTime::from_timestamp_millis(1768069800000)
+        );
+
+        let b: Discussion = serde_json::from_value(serde_json::json!({
+            "_id": "Kq2NdYbFvC5Hs7TmW", "ts": "2026-01-05T12:00:00.000Z"
+        }))
+        .unwrap();
+        assert_eq!(b.last_activity(), b.time_stamp);
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::discussions::discussion::Discussion;
+use serde::Deserialize;
+
+// Discussions of a room, most recently active first.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct Discussions {
+    pub discussions: Vec<Discussion>,
+    pub offset: u64,
+    pub count: u64,
+    pub total: u64,
+}
+
+impl Default for Discussions {
+    fn default() -> Self {
+        Discussions::new()
+    }
+}
+
+impl Discussions {
+    pub fn new() -> Self {
+        Discussions {
+            discussions: Vec::<Discussion>::default(),
+            offset: 0,
+            count: 0,
+            total: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.discussions.is_empty()
+    }
+
+    pub fn parse_elements(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<Discussions>(json) {
+            *self = val;
+            self.sort_by_last_activity();
+        }
+    }
+
+    pub fn sort_by_last_activity(&mut self) {
+        // None is lower than any date
+        self.discussions
+            .sort_by_key(|discussion| std::cmp::Reverse(discussion.last_activity()));
+    }
+
+    pub fn find(&self, discussion_room_id: &str) -> Option<&Discussion> {
+        self.discussions
+            .iter()
+            .find(|discussion| discussion.discussion_room_id == discussion_room_id)
+    }
+
+    // New or updated discussion (created by us, or message counter changed).
+    pub fn insert(&mut self, discussion: Discussion) {
+        match self
+            .discussions
+            .iter_mut()
+            .find(|d| d.discussion_room_id == discussion.discussion_room_id)
+        {
+            Some(existing) => *existing = discussion,
+            None => {
+                self.discussions.push(discussion);
+                self.total += 1;
+                self.count += 1;
+            }
+        }
+        self.sort_by_last_activity();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::discussions::discussion::Discussion;
+    use crate::discussions::discussionlist::Discussions;
+
+    // For test !
+    pub fn parse(filename: &str) -> Discussions {
+        let mut discussions = Discussions::new();
+        discussions
+            .parse_elements(&std::fs::read_to_string(filename).expect("Failed to open file"));
+        discussions
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = Discussions::new();
+        assert!(b.is_empty());
+        assert_eq!(b.total, 0);
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let b = parse("src/data/discussions/discussions.json");
+        assert_eq!(b.total, 3);
+        let names: Vec<&str> = b.discussions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["Release 2.6", "CI failures", "New logo"]);
+        assert_eq!(b.discussions[0].number_of_messages, 12);
+        assert_eq!(b.discussions[0].description, "Planning of next release");
+        assert!(b.discussions[1].last_message.is_none());
+        assert_eq!(b.find("Pz4WcXhJ3aLm9RtQe").unwrap().users_count, 7);
+        assert!(b.find("unknown").is_none());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut b = parse("src/data/discussions/discussions.json");
+        let mut logo = b.find("Pz4WcXhJ3aLm9RtQe").unwrap().clone();
+        logo.number_of_messages = 46;
+        logo.last_message = chrono::DateTime::from_timestamp_millis(1768300000000);
+        b.insert(logo);
+        assert_eq!(b.total, 3);
+        assert_eq!(b.discussions[0].name, "New logo");
+        assert_eq!(b.discussions[0].number_of_messages, 46);
+
+        b.insert(Discussion {
+            discussion_room_id: "new".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(b.total, 4);
+        assert_eq!(b.discussions[3].discussion_room_id, "new");
+    }
+}
@@ -0,0 +1,224 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::discussions::discussion::Discussion;
+use crate::discussions::discussionlist::Discussions;
+use crate::messageinfo::MessageInfo;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{APIMethod, GetDiscussionsMethod, RoomsCreateDiscussionMethod};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CreateDiscussionInfo {
+    pub parent_room_id: String,
+    // Set when discussion is started from a message
+    pub parent_message_id: Option<String>,
+    pub name: String,
+    // Usernames invited in discussion
+    pub users: Vec<String>,
+    pub first_message: Option<String>,
+    pub encrypted: bool,
+}
+
+#[derive(Deserialize)]
+struct CreateDiscussionResult {
+    discussion: Discussion,
+}
+
+pub fn parse_created_discussion(json: &str) -> Option<Discussion> {
+    serde_json::from_str::<CreateDiscussionResult>(json)
+        .map(|result| result.discussion)
+        .ok()
+}
+
+// Discussions loaded per parent room.
+#[derive(Clone)]
+pub struct DiscussionsManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub discussions: HashMap<String, Discussions>,
+}
+
+impl Default for DiscussionsManager {
+    fn default() -> Self {
+        DiscussionsManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl DiscussionsManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        DiscussionsManager {
+            settings,
+            server_url,
+            discussions: HashMap::new(),
+        }
+    }
+
+    pub fn discussions(&self, room_id: &str) -> Option<&Discussions> {
+        self.discussions.get(room_id)
+    }
+
+    pub fn find_discussion(&self, discussion_room_id: &str) -> Option<&Discussion> {
+        self.discussions
+            .values()
+            .find_map(|discussions| discussions.find(discussion_room_id))
+    }
+
+    // Discussion opened from a "discussion-created" system message.
+    pub fn discussion_for_message(&self, message: &MessageInfo) -> Option<&Discussion> {
+        let discussion_room_id = message.discussion_room_id()?;
+        self.discussions
+            .get(&message.rid)
+            .and_then(|discussions| discussions.find(discussion_room_id))
+    }
+
+    // System message is updated by server when a message is posted in discussion.
+    pub fn apply_message(&mut self, message: &MessageInfo) {
+        let Some(discussion_room_id) = message.discussion_room_id() else {
+            return;
+        };
+        if let Some(discussions) = self.discussions.get_mut(&message.rid)
+            && let Some(discussion) = discussions.find(discussion_room_id)
+            && discussion.number_of_messages != message.dcount as u64
+        {
+            let discussion = Discussion {
+                number_of_messages: message.dcount as u64,
+                ..discussion.clone()
+            };
+            discussions.insert(discussion);
+        }
+    }
+
+    pub fn add_discussion(&mut self, discussion: Discussion) {
+        self.discussions
+            .entry(discussion.parent_room_id.clone())
+            .or_default()
+            .insert(discussion);
+    }
+
+    pub async fn load_discussions(&mut self, room_id: &str) -> Result<&Discussions, Error> {
+        let result = GetDiscussionsMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            room_id: room_id.to_string(),
+        }
+        .call()
+        .await?;
+        let discussions = self.discussions.entry(room_id.to_string()).or_default();
+        discussions.parse_elements(&result);
+        Ok(discussions)
+    }
+
+    pub async fn create_discussion(
+        &mut self,
+        info: &CreateDiscussionInfo,
+    ) -> Result<Discussion, Error> {
+        let result = RoomsCreateDiscussionMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            parent_room_id: info.parent_room_id.clone(),
+            parent_message_id: info.parent_message_id.clone(),
+            discussion_name: info.name.clone(),
+            users: info.users.clone(),
+            reply: info.first_message.clone(),
+            encrypted: info.encrypted,
+        }
+        .call()
+        .await?;
+        let discussion = parse_created_discussion(&result).ok_or(Error::JsonDecode(result))?;
+        self.add_discussion(discussion.clone());
+        Ok(discussion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::discussions::discussionlist::Discussions;
+    use crate::discussions::discussionsmanager::{DiscussionsManager, parse_created_discussion};
+    use crate::messageinfo::{MessageInfo, SystemMessageType};
+
+    fn generate_manager() -> DiscussionsManager {
+        let mut discussions = Discussions::new();
+        discussions.parse_elements(
+            &std::fs::read_to_string("src/data/discussions/discussions.json")
+                .expect("Failed to open file"),
+        );
+        let mut manager = DiscussionsManager::default();
+        manager
+            .discussions
+            .insert("GENERAL".to_string(), discussions);
+        manager
+    }
+
+    fn discussion_created_message(drid: &str, dcount: i64) -> MessageInfo {
+        MessageInfo {
+            rid: "GENERAL".to_string(),
+            drid: drid.to_string(),
+            dcount,
+            message_type: SystemMessageType::DiscussionCreated,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_created_discussion() {
+        let discussion = parse_created_discussion(
+            &std::fs::read_to_string("src/data/discussions/creatediscussion.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        assert_eq!(discussion.discussion_room_id, "Ym8LrT2hXwN6dQsVb");
+        assert_eq!(discussion.parent_room_id, "GENERAL");
+        assert_eq!(discussion.name, "Deploy");
+        assert!(parse_created_discussion(r#"{"success": false}"#).is_none());
+
+        let mut manager = generate_manager();
+        manager.add_discussion(discussion);
+        let discussions = manager.discussions("GENERAL").unwrap();
+        assert_eq!(discussions.total, 4);
+        assert_eq!(discussions.discussions[0].name, "Deploy");
+    }
+
+    #[test]
+    fn test_discussion_for_message() {
+        let manager = generate_manager();
+        let message = discussion_created_message("7gkGrmQ2TyXBj8ZfN", 12);
+        assert_eq!(
+            manager.discussion_for_message(&message).unwrap().name,
+            "Release 2.6"
+        );
+        assert_eq!(
+            manager.find_discussion("Kq2NdYbFvC5Hs7TmW").unwrap().name,
+            "CI failures"
+        );
+
+        // Not a discussion created message
+        let message = MessageInfo {
+            message_type: SystemMessageType::Unknown,
+            ..message
+        };
+        assert!(manager.discussion_for_message(&message).is_none());
+        assert!(manager.discussions("unknown").is_none());
+    }
+
+    #[test]
+    fn test_apply_message() {
+        let mut manager = generate_manager();
+        manager.apply_message(&discussion_created_message("Kq2NdYbFvC5Hs7TmW", 3));
+        assert_eq!(
+            manager
+                .find_discussion("Kq2NdYbFvC5Hs7TmW")
+                .unwrap()
+                .number_of_messages,
+            3
+        );
+        // Unknown discussion is ignored
+        manager.apply_message(&discussion_created_message("unknown", 3));
+        assert_eq!(manager.discussions("GENERAL").unwrap().total, 3);
+    }
+}
@@ -0,0 +1,8 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+pub mod discussion;
+pub mod discussionlist;
+pub mod discussionsmanager;
@@ -4,16 +4,22 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
+use crate::urlpreview::previewimagecache::{cache_file_name, hash_name};
 use serde::Deserialize;
 use std::fmt;
 #[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
 pub struct CustomEmoji {
+    // ":name:", set by update_emoji_identifier
+    #[serde(skip)]
     pub emoji_identifier: String,
     #[serde(rename = "_id")]
     pub identifier: String,
     pub extension: String,
     pub name: String,
     pub aliases: Vec<String>,
+    // Changed each time image is modified
+    pub etag: String,
     /*
     String cachedHtml = '';
     int updatedAt = 0;
@@ -27,15 +33,58 @@ impl Default for CustomEmoji {
 }
 
 impl CustomEmoji {
-    fn new() -> Self {
+    pub fn new() -> Self {
         CustomEmoji {
             emoji_identifier: String::default(),
             identifier: String::default(),
             extension: String::default(),
             name: String::default(),
             aliases: Vec::default(),
+            etag: String::default(),
         }
     }
+
+    pub fn is_valid(&self) -> bool {
+        !self.identifier.is_empty() && !self.name.is_empty()
+    }
+
+    pub fn update_emoji_identifier(&mut self) {
+        self.emoji_identifier = format!(":{}:", self.name);
+    }
+
+    // Shortcode without ":", name or alias.
+    pub fn has_shortcode(&self, shortcode: &str) -> bool {
+        self.name == shortcode || self.aliases.iter().any(|alias| alias == shortcode)
+    }
+
+    // Image is served by "<server_url>/emoji-custom/<name>.<extension>".
+    pub fn image_url(&self, server_url: &str) -> Option<String> {
+        let mut url = reqwest::Url::parse(server_url).ok()?;
+        url.path_segments_mut()
+            .ok()?
+            .pop_if_empty()
+            .push("emoji-custom")
+            .push(&format!("{}.{}", self.name, self.extension));
+        if !self.etag.is_empty() {
+            url.query_pairs_mut().append_pair("etag", &self.etag);
+        }
+        Some(url.to_string())
+    }
+
+    // Name of the cached image, it changes with etag.
+    pub fn cache_file_name(&self) -> String {
+        format!(
+            "{}{}",
+            cache_file_prefix(&self.identifier),
+            cache_file_name(&format!("{}.{}", self.etag, self.extension))
+        )
+    }
+}
+
+// Cached images of an emoji start with this prefix.
+// Server values are hashed as they can contain "/" or "..".
+pub fn cache_file_prefix(identifier: &str) -> String {
+    format!("{}-", hash_name(identifier))
 }
 
 /*
@@ -46,3 +95,74 @@ impl fmt::Display for CustomEmoji {
         write!(f, "CustomEmoji(emojiIdentifier {})", self.emoji_identifier)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::customemoji::{CustomEmoji, cache_file_prefix};
+
+    fn generate_emoji() -> CustomEmoji {
+        let mut emoji: CustomEmoji = serde_json::from_value(serde_json::json!({
+            "_id": "HxnYB2Fd6sDkyeRS2", "name": "kde", "aliases": ["konqi", "katie"],
+            "extension": "png", "etag": "8zWTqR5C", "_updatedAt": "2026-03-01T10:00:00.000Z"
+        }))
+        .unwrap();
+        emoji.update_emoji_identifier();
+        emoji
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = CustomEmoji::new();
+        assert!(!b.is_valid());
+        assert!(b.emoji_identifier.is_empty());
+        assert!(b.identifier.is_empty());
+        assert!(b.extension.is_empty());
+        assert!(b.name.is_empty());
+        assert!(b.aliases.is_empty());
+        assert!(b.etag.is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let b = generate_emoji();
+        assert!(b.is_valid());
+        assert_eq!(b.emoji_identifier, ":kde:");
+        assert_eq!(b.identifier, "HxnYB2Fd6sDkyeRS2");
+        assert_eq!(b.aliases, vec!["konqi", "katie"]);
+        assert!(b.has_shortcode("kde"));
+        assert!(b.has_shortcode("katie"));
+        assert!(!b.has_shortcode(":kde:"));
+    }
+
+    #[test]
+    fn test_image_url() {
+        let mut b = generate_emoji();
+        assert_eq!(
+            b.image_url("https://mydomain.com").unwrap(),
+            "https://mydomain.com/emoji-custom/kde.png?etag=8zWTqR5C"
+        );
+        let file_name = b.cache_file_name();
+        assert!(file_name.starts_with(&cache_file_prefix("HxnYB2Fd6sDkyeRS2")));
+        assert!(file_name.ends_with(".png"));
+        b.name = "party parrot".to_string();
+        b.etag.clear();
+        assert_eq!(
+            b.image_url("https://mydomain.com/").unwrap(),
+            "https://mydomain.com/emoji-custom/party%20parrot.png"
+        );
+        assert_ne!(b.cache_file_name(), file_name);
+        assert!(b.image_url("foo").is_none());
+    }
+
+    #[test]
+    fn test_cache_file_name() {
+        let mut b = generate_emoji();
+        b.identifier = "../../.config/autostart/foo".to_string();
+        b.etag = "a/../b".to_string();
+        b.extension = "desktop/..".to_string();
+        let file_name = b.cache_file_name();
+        assert!(!file_name.contains('/'));
+        assert!(!file_name.contains(".."));
+        assert!(file_name.ends_with(".img"));
+    }
+}
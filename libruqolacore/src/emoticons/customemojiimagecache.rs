@@ -0,0 +1,169 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::emoticons::customemoji::{CustomEmoji, cache_file_prefix};
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum CustomEmojiImageCacheError {
+    NoCacheDirectory,
+    InvalidUrl(String),
+    Download(String),
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for CustomEmojiImageCacheError {
+    fn from(error: std::io::Error) -> Self {
+        CustomEmojiImageCacheError::Io(error)
+    }
+}
+
+/* Debug output for CustomEmojiImageCacheError */
+impl fmt::Display for CustomEmojiImageCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomEmojiImageCacheError::NoCacheDirectory => write!(f, "No cache directory"),
+            CustomEmojiImageCacheError::InvalidUrl(url) => write!(f, "Invalid url: {}", url),
+            CustomEmojiImageCacheError::Download(error) => write!(f, "Download error: {}", error),
+            CustomEmojiImageCacheError::Io(error) => write!(f, "Io error: {}", error),
+        }
+    }
+}
+
+// Images of custom emojis stored on disk.
+// File name contains etag, so a modified emoji is downloaded again.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomEmojiImageCache {
+    // Images are not cached when it's empty
+    pub cache_directory: PathBuf,
+}
+
+impl CustomEmojiImageCache {
+    pub fn new(cache_directory: PathBuf) -> Self {
+        CustomEmojiImageCache { cache_directory }
+    }
+
+    fn image_path(&self, emoji: &CustomEmoji) -> Option<PathBuf> {
+        if self.cache_directory.as_os_str().is_empty() {
+            return None;
+        }
+        Some(self.cache_directory.join(emoji.cache_file_name()))
+    }
+
+    pub fn cached_image(&self, emoji: &CustomEmoji) -> Option<PathBuf> {
+        self.image_path(emoji).filter(|path| path.is_file())
+    }
+
+    pub fn store_image(
+        &self,
+        emoji: &CustomEmoji,
+        data: &[u8],
+    ) -> Result<PathBuf, CustomEmojiImageCacheError> {
+        let path = self
+            .image_path(emoji)
+            .ok_or(CustomEmojiImageCacheError::NoCacheDirectory)?;
+        // Remove image with old etag
+        self.remove_images(&emoji.identifier);
+        std::fs::create_dir_all(&self.cache_directory)?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    // Returns cached image, downloads it when it's missing.
+    pub async fn image(
+        &self,
+        emoji: &CustomEmoji,
+        server_url: &str,
+    ) -> Result<PathBuf, CustomEmojiImageCacheError> {
+        if let Some(path) = self.cached_image(emoji) {
+            return Ok(path);
+        }
+        let url = emoji
+            .image_url(server_url)
+            .ok_or(CustomEmojiImageCacheError::InvalidUrl(
+                server_url.to_string(),
+            ))?;
+        let data = download(&url)
+            .await
+            .map_err(|error| CustomEmojiImageCacheError::Download(error.to_string()))?;
+        self.store_image(emoji, &data)
+    }
+
+    // Remove all images of an emoji.
+    pub fn remove_images(&self, identifier: &str) {
+        let Ok(entries) = std::fs::read_dir(&self.cache_directory) else {
+            return;
+        };
+        let prefix = cache_file_prefix(identifier);
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.starts_with(&prefix) {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+async fn download(url: &str) -> Result<Vec<u8>, reqwest::Error> {
+    let response = reqwest::get(url).await?.error_for_status()?;
+    Ok(response.bytes().await?.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::customemoji::CustomEmoji;
+    use crate::emoticons::customemojiimagecache::{
+        CustomEmojiImageCache, CustomEmojiImageCacheError,
+    };
+
+    fn generate_emoji(etag: &str) -> CustomEmoji {
+        CustomEmoji {
+            identifier: "HxnYB2Fd6sDkyeRS2".to_string(),
+            name: "kde".to_string(),
+            extension: "png".to_string(),
+            etag: etag.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_cache_directory() {
+        let cache = CustomEmojiImageCache::default();
+        assert!(cache.cached_image(&generate_emoji("etag1")).is_none());
+        assert!(matches!(
+            cache.store_image(&generate_emoji("etag1"), b"PNG"),
+            Err(CustomEmojiImageCacheError::NoCacheDirectory)
+        ));
+    }
+
+    #[test]
+    fn test_store_image() {
+        let directory = std::env::temp_dir().join(format!(
+            "ruqola-customemojiimagecache-{}",
+            std::process::id()
+        ));
+        let cache = CustomEmojiImageCache::new(directory.clone());
+        let emoji = generate_emoji("etag1");
+        assert!(cache.cached_image(&emoji).is_none());
+
+        let path = cache.store_image(&emoji, b"PNG1").unwrap();
+        assert_eq!(path, directory.join(emoji.cache_file_name()));
+        assert_eq!(cache.cached_image(&emoji), Some(path.clone()));
+        assert_eq!(std::fs::read(&path).unwrap(), b"PNG1");
+
+        // New etag replaces old image
+        let updated = generate_emoji("etag2");
+        assert!(cache.cached_image(&updated).is_none());
+        cache.store_image(&updated, b"PNG2").unwrap();
+        assert!(cache.cached_image(&emoji).is_none());
+        assert!(cache.cached_image(&updated).is_some());
+
+        cache.remove_images(&updated.identifier);
+        assert!(cache.cached_image(&updated).is_none());
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
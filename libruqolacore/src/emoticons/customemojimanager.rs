@@ -0,0 +1,344 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::emoticons::customemoji::CustomEmoji;
+use crate::emoticons::customemojiimagecache::{CustomEmojiImageCache, CustomEmojiImageCacheError};
+use crate::emoticons::shortcode;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, EmojiCustomCreateMethod, EmojiCustomDeleteMethod, EmojiCustomImage,
+    EmojiCustomListMethod, EmojiCustomUpdateMethod,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+// Custom emojis of server: list, live updates, images and shortcodes.
+#[derive(Clone)]
+pub struct CustomEmojiManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub emojis: Vec<CustomEmoji>,
+    pub image_cache: CustomEmojiImageCache,
+    // Name and aliases (without ":") => index in emojis
+    shortcodes: HashMap<String, usize>,
+}
+
+impl Default for CustomEmojiManager {
+    fn default() -> Self {
+        CustomEmojiManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl CustomEmojiManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        CustomEmojiManager {
+            settings,
+            server_url,
+            emojis: Vec::new(),
+            image_cache: CustomEmojiImageCache::default(),
+            shortcodes: HashMap::new(),
+        }
+    }
+
+    // Parse emoji-custom.list result: {"emojis": {"update": […], "remove": […]}}
+    // or emoji-custom.all result: {"emojis": […]}
+    pub fn parse_elements(&mut self, json: &str) {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+            return;
+        };
+        let emojis = &value["emojis"];
+        if let Some(list) = emojis.as_array() {
+            self.emojis.clear();
+            for emoji in list {
+                self.apply_emoji(emoji);
+            }
+        } else {
+            for emoji in emojis["update"].as_array().into_iter().flatten() {
+                self.apply_emoji(emoji);
+            }
+            for emoji in emojis["remove"].as_array().into_iter().flatten() {
+                if let Some(identifier) = emoji["_id"].as_str() {
+                    self.remove_emoji(identifier);
+                }
+            }
+        }
+        self.update_shortcodes();
+    }
+
+    fn apply_emoji(&mut self, value: &serde_json::Value) {
+        let Ok(mut emoji) = serde_json::from_value::<CustomEmoji>(value.clone()) else {
+            return;
+        };
+        if !emoji.is_valid() {
+            return;
+        }
+        emoji.update_emoji_identifier();
+        match self
+            .emojis
+            .iter_mut()
+            .find(|existing| existing.identifier == emoji.identifier)
+        {
+            Some(existing) => {
+                if existing.etag != emoji.etag || existing.extension != emoji.extension {
+                    self.image_cache.remove_images(&existing.identifier);
+                }
+                *existing = emoji;
+            }
+            None => self.emojis.push(emoji),
+        }
+    }
+
+    fn remove_emoji(&mut self, identifier: &str) {
+        self.emojis.retain(|emoji| emoji.identifier != identifier);
+        self.image_cache.remove_images(identifier);
+    }
+
+    fn update_shortcodes(&mut self) {
+        self.shortcodes.clear();
+        for (index, emoji) in self.emojis.iter().enumerate() {
+            for shortcode in std::iter::once(&emoji.name).chain(emoji.aliases.iter()) {
+                self.shortcodes.entry(shortcode.clone()).or_insert(index);
+            }
+        }
+    }
+
+    // "updateEmojiCustom" stream: [{"emojiData": {"_id": …, "name": …, "aliases": […], "extension": …, "etag": …}}]
+    pub fn apply_update_emoji_custom(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            self.apply_emoji(&change["emojiData"]);
+        }
+        self.update_shortcodes();
+    }
+
+    // "deleteEmojiCustom" stream: [{"emojiData": {"_id": …}}]
+    pub fn apply_delete_emoji_custom(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            if let Some(identifier) = change["emojiData"]["_id"].as_str() {
+                self.remove_emoji(identifier);
+            }
+        }
+        self.update_shortcodes();
+    }
+
+    // Shortcode with or without ":", name or alias.
+    pub fn find_emoji(&self, shortcode: &str) -> Option<&CustomEmoji> {
+        let shortcode = shortcode
+            .strip_prefix(':')
+            .and_then(|code| code.strip_suffix(':'))
+            .unwrap_or(shortcode);
+        self.shortcodes
+            .get(shortcode)
+            .and_then(|index| self.emojis.get(*index))
+    }
+
+    // Replace ":shortcode:" of custom emojis by render result, unknown shortcodes are kept.
+    pub fn replace_shortcodes<F>(&self, text: &str, render: F) -> String
+    where
+        F: Fn(&CustomEmoji) -> String,
+    {
+        shortcode::replace_shortcodes(text, |code| self.find_emoji(code).map(&render))
+    }
+
+    // Local file when image is cached, otherwise server url.
+    pub fn image_source(&self, emoji: &CustomEmoji) -> Option<String> {
+        match self.image_cache.cached_image(emoji) {
+            Some(path) => Some(format!("file://{}", path.display())),
+            None => emoji.image_url(&self.server_url),
+        }
+    }
+
+    pub async fn load(&mut self) -> Result<(), Error> {
+        let result = EmojiCustomListMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            ..Default::default()
+        }
+        .call()
+        .await?;
+        self.parse_elements(&result);
+        Ok(())
+    }
+
+    pub async fn image(&self, emoji: &CustomEmoji) -> Result<PathBuf, CustomEmojiImageCacheError> {
+        self.image_cache.image(emoji, &self.server_url).await
+    }
+
+    // Download images which are not cached yet, returns number of downloaded images.
+    pub async fn download_missing_images(&self) -> Result<usize, CustomEmojiImageCacheError> {
+        let mut downloaded = 0;
+        for emoji in &self.emojis {
+            if self.image_cache.cached_image(emoji).is_none() {
+                self.image(emoji).await?;
+                downloaded += 1;
+            }
+        }
+        Ok(downloaded)
+    }
+
+    // Admin: changes are received by "updateEmojiCustom" stream.
+    pub async fn create_emoji(
+        &self,
+        name: &str,
+        aliases: Vec<String>,
+        image: EmojiCustomImage,
+    ) -> Result<(), Error> {
+        EmojiCustomCreateMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            name: name.to_string(),
+            aliases,
+            image,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    // Image is kept when it's None.
+    pub async fn update_emoji(
+        &self,
+        identifier: &str,
+        name: &str,
+        aliases: Vec<String>,
+        image: Option<EmojiCustomImage>,
+    ) -> Result<(), Error> {
+        EmojiCustomUpdateMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            identifier: identifier.to_string(),
+            name: name.to_string(),
+            aliases,
+            image,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_emoji(&self, identifier: &str) -> Result<(), Error> {
+        EmojiCustomDeleteMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            emoji_id: identifier.to_string(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::customemojimanager::CustomEmojiManager;
+    use libauthenticationbase::authenticationsettings::AuthenticationType;
+
+    fn generate_manager() -> CustomEmojiManager {
+        let mut m =
+            CustomEmojiManager::new(AuthenticationType::None, "https://mydomain.com".to_string());
+        m.parse_elements(
+            &std::fs::read_to_string("src/data/customemoji/emojicustomlist.json")
+                .expect("Failed to open file"),
+        );
+        m
+    }
+
+    #[test]
+    fn test_default_values() {
+        let m = CustomEmojiManager::default();
+        assert!(m.emojis.is_empty());
+        assert!(m.find_emoji("kde").is_none());
+    }
+
+    #[test]
+    fn test_parse_elements() {
+        let m = generate_manager();
+        assert_eq!(m.emojis.len(), 3);
+        let emoji = m.find_emoji(":kde:").unwrap();
+        assert_eq!(emoji.identifier, "HxnYB2Fd6sDkyeRS2");
+        assert_eq!(emoji.emoji_identifier, ":kde:");
+        assert_eq!(m.find_emoji("konqi").unwrap().name, "kde");
+        assert_eq!(m.find_emoji(":party_parrot:").unwrap().extension, "gif");
+        assert!(m.find_emoji("foo").is_none());
+
+        // emoji-custom.all result
+        let mut m = generate_manager();
+        m.parse_elements(
+            r#"{"emojis": [{"_id": "id1", "name": "rust", "aliases": [], "extension": "svg"}], "success": true}"#,
+        );
+        assert_eq!(m.emojis.len(), 1);
+        assert!(m.find_emoji("kde").is_none());
+        assert!(m.find_emoji("rust").is_some());
+    }
+
+    #[test]
+    fn test_parse_incremental_list() {
+        let mut m = generate_manager();
+        m.parse_elements(
+            r#"{"emojis": {"update": [{"_id": "HxnYB2Fd6sDkyeRS2", "name": "kde", "aliases": ["plasma"], "extension": "png", "etag": "newetag"}],
+                           "remove": [{"_id": "pTqbNbuyP7Mci8iLs"}]}, "success": true}"#,
+        );
+        assert_eq!(m.emojis.len(), 2);
+        assert!(m.find_emoji("konqi").is_none());
+        assert_eq!(m.find_emoji("plasma").unwrap().etag, "newetag");
+        assert!(m.find_emoji("shipit").is_none());
+    }
+
+    #[test]
+    fn test_apply_stream() {
+        let mut m = generate_manager();
+        m.apply_update_emoji_custom(&serde_json::json!([
+            {"emojiData": {"_id": "newemoji", "name": "rust", "aliases": ["ferris"], "extension": "svg", "etag": "abc"}}
+        ]));
+        assert_eq!(m.emojis.len(), 4);
+        assert_eq!(m.find_emoji("ferris").unwrap().identifier, "newemoji");
+
+        m.apply_update_emoji_custom(&serde_json::json!([
+            {"emojiData": {"_id": "newemoji", "name": "rustacean", "aliases": [], "extension": "svg", "previousName": "rust"}}
+        ]));
+        assert_eq!(m.emojis.len(), 4);
+        assert!(m.find_emoji("rust").is_none());
+        assert!(m.find_emoji("rustacean").is_some());
+
+        m.apply_delete_emoji_custom(&serde_json::json!([
+            {"emojiData": {"_id": "HxnYB2Fd6sDkyeRS2", "name": "kde"}}
+        ]));
+        assert_eq!(m.emojis.len(), 3);
+        assert!(m.find_emoji("kde").is_none());
+        assert!(m.find_emoji("konqi").is_none());
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        let m = generate_manager();
+        let render =
+            |emoji: &crate::emoticons::customemoji::CustomEmoji| format!("[{}]", emoji.name);
+        assert_eq!(
+            m.replace_shortcodes("hello :kde: and :konqi:", render),
+            "hello [kde] and [kde]"
+        );
+        assert_eq!(
+            m.replace_shortcodes(":kde::shipit:", render),
+            "[kde][shipit]"
+        );
+        assert_eq!(
+            m.replace_shortcodes("time 10:30 :kde:", render),
+            "time 10:30 [kde]"
+        );
+        assert_eq!(m.replace_shortcodes(":foo:kde:", render), ":foo[kde]");
+        assert_eq!(
+            m.replace_shortcodes(":unknown: :kde", render),
+            ":unknown: :kde"
+        );
+        assert_eq!(m.replace_shortcodes("no emoji", render), "no emoji");
+    }
+}
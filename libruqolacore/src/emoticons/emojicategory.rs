@@ -0,0 +1,51 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use std::fmt;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmojiCategory {
+    // Value of "category" in emoji table
+    pub identifier: String,
+    pub name: String,
+    // Emoji displayed in picker tab
+    pub icon: String,
+}
+
+impl EmojiCategory {
+    pub fn new(identifier: &str, name: &str, icon: &str) -> Self {
+        EmojiCategory {
+            identifier: identifier.to_string(),
+            name: name.to_string(),
+            icon: icon.to_string(),
+        }
+    }
+
+    // Categories of bundled emoji table, in picker order.
+    pub fn categories() -> Vec<EmojiCategory> {
+        vec![
+            EmojiCategory::new("people", "Smileys & People", "😀"),
+            EmojiCategory::new("nature", "Animals & Nature", "🐶"),
+            EmojiCategory::new("food", "Food & Drink", "🍏"),
+            EmojiCategory::new("activity", "Activity", "⚽"),
+            EmojiCategory::new("travel", "Travel & Places", "🚗"),
+            EmojiCategory::new("objects", "Objects", "💡"),
+            EmojiCategory::new("symbols", "Symbols", "❤️"),
+            EmojiCategory::new("flags", "Flags", "🏁"),
+        ]
+    }
+}
+
+/* Debug output for EmojiCategory */
+impl fmt::Display for EmojiCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EmojiCategory(identifier {}, name {}, icon {})",
+            self.identifier, self.name, self.icon
+        )
+    }
+}
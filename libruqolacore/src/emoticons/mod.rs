@@ -4,3 +4,10 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 pub mod customemoji;
+pub mod customemojiimagecache;
+pub mod customemojimanager;
+pub mod emojicategory;
+pub mod recentemojis;
+pub mod shortcode;
+pub mod unicodeemoji;
+pub mod unicodeemojimanager;
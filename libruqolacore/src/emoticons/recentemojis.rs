@@ -0,0 +1,112 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use std::fmt;
+
+const DEFAULT_MAXIMUM_RECENT_EMOJIS: usize = 20;
+
+// Identifiers of last used emojis, most recent first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecentEmojis {
+    pub emojis: Vec<String>,
+    pub maximum: usize,
+}
+
+impl Default for RecentEmojis {
+    fn default() -> Self {
+        RecentEmojis::new()
+    }
+}
+
+impl RecentEmojis {
+    pub fn new() -> Self {
+        RecentEmojis {
+            emojis: Vec::new(),
+            maximum: DEFAULT_MAXIMUM_RECENT_EMOJIS,
+        }
+    }
+
+    pub fn add(&mut self, identifier: &str) {
+        self.emojis.retain(|emoji| emoji != identifier);
+        self.emojis.insert(0, identifier.to_string());
+        self.emojis.truncate(self.maximum);
+    }
+
+    pub fn remove(&mut self, identifier: &str) {
+        self.emojis.retain(|emoji| emoji != identifier);
+    }
+
+    pub fn clear(&mut self) {
+        self.emojis.clear();
+    }
+
+    // Position in list, lower is more recent.
+    pub fn position(&self, identifier: &str) -> Option<usize> {
+        self.emojis.iter().position(|emoji| emoji == identifier)
+    }
+
+    // Stored as "smile,thumbsup,tada" in settings.
+    pub fn to_settings_value(&self) -> String {
+        self.emojis.join(",")
+    }
+
+    pub fn set_settings_value(&mut self, value: &str) {
+        self.emojis = value
+            .split(',')
+            .map(str::trim)
+            .filter(|emoji| !emoji.is_empty())
+            .take(self.maximum)
+            .map(str::to_string)
+            .collect();
+    }
+}
+
+/* Debug output for RecentEmojis */
+impl fmt::Display for RecentEmojis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RecentEmojis({:?})", self.emojis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::recentemojis::RecentEmojis;
+
+    #[test]
+    fn test_is_empty() {
+        let b = RecentEmojis::new();
+        assert!(b.emojis.is_empty());
+        assert_eq!(b.maximum, 20);
+        assert!(b.to_settings_value().is_empty());
+    }
+
+    #[test]
+    fn test_add() {
+        let mut b = RecentEmojis::new();
+        b.maximum = 3;
+        b.add("smile");
+        b.add("tada");
+        b.add("smile");
+        assert_eq!(b.emojis, vec!["smile", "tada"]);
+        b.add("+1");
+        b.add("heart");
+        assert_eq!(b.emojis, vec!["heart", "+1", "smile"]);
+        assert_eq!(b.position("+1"), Some(1));
+        assert_eq!(b.position("tada"), None);
+        b.remove("+1");
+        assert_eq!(b.emojis, vec!["heart", "smile"]);
+    }
+
+    #[test]
+    fn test_settings_value() {
+        let mut b = RecentEmojis::new();
+        b.set_settings_value("smile, tada,,heart");
+        assert_eq!(b.emojis, vec!["smile", "tada", "heart"]);
+        assert_eq!(b.to_settings_value(), "smile,tada,heart");
+        b.set_settings_value("");
+        assert!(b.emojis.is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+// Shortcode without ":", as "smile", "+1" or "thumbsup_tone2".
+pub fn is_valid_shortcode(shortcode: &str) -> bool {
+    !shortcode.is_empty()
+        && shortcode
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_+-.".contains(c))
+}
+
+// Replace each ":shortcode:" by resolve result, shortcodes resolved to None are kept.
+pub fn replace_shortcodes<F>(text: &str, resolve: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut result = String::with_capacity(text.len());
+    let mut remaining = text;
+    while let Some(start) = remaining.find(':') {
+        result.push_str(&remaining[..start]);
+        let after = &remaining[start + 1..];
+        let Some(end) = after.find(':') else {
+            result.push_str(&remaining[start..]);
+            return result;
+        };
+        let shortcode = &after[..end];
+        if is_valid_shortcode(shortcode)
+            && let Some(replacement) = resolve(shortcode)
+        {
+            result.push_str(&replacement);
+            remaining = &after[end + 1..];
+        } else {
+            // Closing ":" can start next shortcode
+            result.push(':');
+            result.push_str(shortcode);
+            remaining = &after[end..];
+        }
+    }
+    result.push_str(remaining);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::shortcode::{is_valid_shortcode, replace_shortcodes};
+
+    #[test]
+    fn test_is_valid_shortcode() {
+        assert!(is_valid_shortcode("smile"));
+        assert!(is_valid_shortcode("+1"));
+        assert!(is_valid_shortcode("thumbsup_tone2"));
+        assert!(!is_valid_shortcode(""));
+        assert!(!is_valid_shortcode("two words"));
+        assert!(!is_valid_shortcode("//"));
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        let resolve = |shortcode: &str| (shortcode == "a").then(|| "[A]".to_string());
+        assert_eq!(replace_shortcodes(":a: :b: :a", resolve), "[A] :b: :a");
+        assert_eq!(replace_shortcodes("::a::", resolve), ":[A]:");
+        assert_eq!(replace_shortcodes("", resolve), "");
+    }
+}
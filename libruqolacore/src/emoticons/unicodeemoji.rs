@@ -0,0 +1,221 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use serde::Deserialize;
+use std::fmt;
+
+// Fitzpatrick modifiers, "_tone1" … "_tone5" in shortcodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SkinTone {
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: [SkinTone; 6] = [
+        SkinTone::Default,
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+
+    // 0 for Default, 1…5 otherwise (used by "_toneN" and settings).
+    pub fn index(&self) -> usize {
+        match self {
+            SkinTone::Default => 0,
+            SkinTone::Light => 1,
+            SkinTone::MediumLight => 2,
+            SkinTone::Medium => 3,
+            SkinTone::MediumDark => 4,
+            SkinTone::Dark => 5,
+        }
+    }
+
+    pub fn from_index(index: usize) -> SkinTone {
+        SkinTone::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    pub fn modifier(&self) -> Option<char> {
+        match self {
+            SkinTone::Default => None,
+            SkinTone::Light => Some('\u{1F3FB}'),
+            SkinTone::MediumLight => Some('\u{1F3FC}'),
+            SkinTone::Medium => Some('\u{1F3FD}'),
+            SkinTone::MediumDark => Some('\u{1F3FE}'),
+            SkinTone::Dark => Some('\u{1F3FF}'),
+        }
+    }
+
+    pub fn suffix(&self) -> String {
+        match self {
+            SkinTone::Default => String::default(),
+            tone => format!("_tone{}", tone.index()),
+        }
+    }
+
+    // "thumbsup_tone2" => ("thumbsup", MediumLight)
+    pub fn split_shortcode(shortcode: &str) -> (&str, SkinTone) {
+        if let Some((name, tone)) = shortcode.rsplit_once("_tone")
+            && let Ok(index) = tone.parse::<usize>()
+            && (1..=5).contains(&index)
+        {
+            return (name, SkinTone::from_index(index));
+        }
+        (shortcode, SkinTone::Default)
+    }
+}
+
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct UnicodeEmoji {
+    pub unicode: String,
+    // Shortcode without ":"
+    #[serde(rename = "shortname")]
+    pub identifier: String,
+    pub name: String,
+    pub category: String,
+    pub aliases: Vec<String>,
+    pub keywords: Vec<String>,
+    // Supports skin tones
+    pub diversity: bool,
+}
+
+impl UnicodeEmoji {
+    pub fn new() -> Self {
+        UnicodeEmoji {
+            ..Default::default()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.unicode.is_empty() && !self.identifier.is_empty()
+    }
+
+    // ":smile:"
+    pub fn shortcode(&self) -> String {
+        format!(":{}:", self.identifier)
+    }
+
+    pub fn has_shortcode(&self, shortcode: &str) -> bool {
+        self.identifier == shortcode || self.aliases.iter().any(|alias| alias == shortcode)
+    }
+
+    // Modifier follows the base character, variation selector is dropped.
+    pub fn unicode_with_skin_tone(&self, tone: SkinTone) -> String {
+        let Some(modifier) = tone.modifier().filter(|_| self.diversity) else {
+            return self.unicode.clone();
+        };
+        let mut chars = self.unicode.chars().filter(|c| *c != '\u{FE0F}');
+        let mut result: String = chars.next().into_iter().collect();
+        result.push(modifier);
+        result.extend(chars);
+        result
+    }
+
+    pub fn shortcode_with_skin_tone(&self, tone: SkinTone) -> String {
+        if self.diversity {
+            format!(":{}{}:", self.identifier, tone.suffix())
+        } else {
+            self.shortcode()
+        }
+    }
+}
+
+/* Debug output for UnicodeEmoji */
+impl fmt::Display for UnicodeEmoji {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UnicodeEmoji(unicode {}, identifier {}, category {})",
+            self.unicode, self.identifier, self.category
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemoji::{SkinTone, UnicodeEmoji};
+
+    fn generate_emoji(unicode: &str, identifier: &str, diversity: bool) -> UnicodeEmoji {
+        UnicodeEmoji {
+            unicode: unicode.to_string(),
+            identifier: identifier.to_string(),
+            diversity,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = UnicodeEmoji::new();
+        assert!(!b.is_valid());
+        assert!(b.unicode.is_empty());
+        assert!(b.identifier.is_empty());
+        assert!(b.category.is_empty());
+        assert!(b.aliases.is_empty());
+        assert!(b.keywords.is_empty());
+        assert!(!b.diversity);
+    }
+
+    #[test]
+    fn test_parse() {
+        let b: UnicodeEmoji = serde_json::from_str(
+            r#"{"unicode": "👍", "shortname": "thumbsup", "name": "thumbs up sign", "category": "people", "aliases": ["+1"], "keywords": ["hand"], "diversity": true}"#,
+        )
+        .unwrap();
+        assert!(b.is_valid());
+        assert_eq!(b.shortcode(), ":thumbsup:");
+        assert!(b.has_shortcode("+1"));
+        assert!(!b.has_shortcode("hand"));
+        assert_eq!(b.keywords, vec!["hand"]);
+    }
+
+    #[test]
+    fn test_skin_tone() {
+        let b = generate_emoji("👍", "thumbsup", true);
+        assert_eq!(b.unicode_with_skin_tone(SkinTone::Default), "👍");
+        assert_eq!(b.unicode_with_skin_tone(SkinTone::Dark), "👍🏿");
+        assert_eq!(
+            b.shortcode_with_skin_tone(SkinTone::MediumLight),
+            ":thumbsup_tone2:"
+        );
+        assert_eq!(b.shortcode_with_skin_tone(SkinTone::Default), ":thumbsup:");
+
+        // Variation selector is replaced by modifier
+        let b = generate_emoji("✌️", "v", true);
+        assert_eq!(b.unicode_with_skin_tone(SkinTone::Light), "✌🏻");
+
+        let b = generate_emoji("😀", "grinning", false);
+        assert_eq!(b.unicode_with_skin_tone(SkinTone::Dark), "😀");
+        assert_eq!(b.shortcode_with_skin_tone(SkinTone::Dark), ":grinning:");
+    }
+
+    #[test]
+    fn test_split_shortcode() {
+        assert_eq!(
+            SkinTone::split_shortcode("thumbsup_tone2"),
+            ("thumbsup", SkinTone::MediumLight)
+        );
+        assert_eq!(
+            SkinTone::split_shortcode("thumbsup"),
+            ("thumbsup", SkinTone::Default)
+        );
+        assert_eq!(
+            SkinTone::split_shortcode("foo_tone9"),
+            ("foo_tone9", SkinTone::Default)
+        );
+        assert_eq!(SkinTone::from_index(5), SkinTone::Dark);
+        assert_eq!(SkinTone::from_index(42), SkinTone::Default);
+        assert_eq!(SkinTone::Medium.index(), 3);
+    }
+}
@@ -0,0 +1,412 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+extern crate ini;
+use crate::emoticons::emojicategory::EmojiCategory;
+use crate::emoticons::recentemojis::RecentEmojis;
+use crate::emoticons::shortcode;
+use crate::emoticons::unicodeemoji::{SkinTone, UnicodeEmoji};
+use ini::Ini;
+use std::collections::HashMap;
+
+// Bundled emoji table: unicode, shortname, name, category, aliases, keywords, diversity
+// Generated from gemoji/CLDR emoji list, emojione shortnames are kept as identifiers
+const EMOJI_TABLE: &str = include_str!("emoji.json");
+
+// Converted when user enabled "convert ascii emoji", only as separate word.
+const ASCII_EMOTICONS: &[(&str, &str)] = &[
+    (":)", "slight_smile"),
+    (":-)", "slight_smile"),
+    ("=)", "slight_smile"),
+    (":D", "smiley"),
+    (":-D", "smiley"),
+    ("=D", "smiley"),
+    ("XD", "laughing"),
+    ("xD", "laughing"),
+    (";)", "wink"),
+    (";-)", "wink"),
+    (":(", "slight_frown"),
+    (":-(", "slight_frown"),
+    (":'(", "cry"),
+    (":P", "stuck_out_tongue"),
+    (":-P", "stuck_out_tongue"),
+    (":p", "stuck_out_tongue"),
+    (";P", "stuck_out_tongue_winking_eye"),
+    (":O", "open_mouth"),
+    (":-O", "open_mouth"),
+    (":o", "open_mouth"),
+    (":|", "neutral_face"),
+    (":-|", "neutral_face"),
+    (":/", "confused"),
+    (":-/", "confused"),
+    (":*", "kissing_heart"),
+    (":-*", "kissing_heart"),
+    ("B)", "sunglasses"),
+    ("B-)", "sunglasses"),
+    (">:(", "angry"),
+    ("O:)", "innocent"),
+    ("<3", "heart"),
+    ("</3", "broken_heart"),
+];
+
+// Unicode emojis shared by emoji picker, reactions and completion.
+#[derive(Clone, Debug)]
+pub struct UnicodeEmojiManager {
+    pub emojis: Vec<UnicodeEmoji>,
+    pub recent_emojis: RecentEmojis,
+    // Used by picker, shortcodes keep their own skin tone
+    pub skin_tone: SkinTone,
+    // Identifier and aliases => index in emojis
+    shortcodes: HashMap<String, usize>,
+}
+
+impl Default for UnicodeEmojiManager {
+    fn default() -> Self {
+        UnicodeEmojiManager::new()
+    }
+}
+
+impl UnicodeEmojiManager {
+    pub fn new() -> Self {
+        let mut manager = UnicodeEmojiManager {
+            emojis: Vec::new(),
+            recent_emojis: RecentEmojis::new(),
+            skin_tone: SkinTone::Default,
+            shortcodes: HashMap::new(),
+        };
+        manager.parse_elements(EMOJI_TABLE);
+        manager
+    }
+
+    pub fn parse_elements(&mut self, json: &str) {
+        self.emojis = serde_json::from_str::<Vec<UnicodeEmoji>>(json)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(UnicodeEmoji::is_valid)
+            .collect();
+        self.shortcodes.clear();
+        for (index, emoji) in self.emojis.iter().enumerate() {
+            for shortcode in std::iter::once(&emoji.identifier).chain(emoji.aliases.iter()) {
+                self.shortcodes.entry(shortcode.clone()).or_insert(index);
+            }
+        }
+    }
+
+    pub fn categories(&self) -> Vec<EmojiCategory> {
+        EmojiCategory::categories()
+    }
+
+    pub fn emojis_for_category(&self, category: &str) -> Vec<&UnicodeEmoji> {
+        self.emojis
+            .iter()
+            .filter(|emoji| emoji.category == category)
+            .collect()
+    }
+
+    // Shortcode with or without ":", identifier or alias, without skin tone.
+    pub fn find_emoji(&self, shortcode: &str) -> Option<&UnicodeEmoji> {
+        let shortcode = shortcode
+            .strip_prefix(':')
+            .and_then(|code| code.strip_suffix(':'))
+            .unwrap_or(shortcode);
+        self.shortcodes
+            .get(shortcode)
+            .and_then(|index| self.emojis.get(*index))
+    }
+
+    pub fn find_emoji_by_unicode(&self, unicode: &str) -> Option<&UnicodeEmoji> {
+        self.emojis.iter().find(|emoji| emoji.unicode == unicode)
+    }
+
+    // "thumbsup_tone3" => "👍🏽"
+    pub fn unicode_for_shortcode(&self, shortcode: &str) -> Option<String> {
+        if let Some(emoji) = self.find_emoji(shortcode) {
+            return Some(emoji.unicode.clone());
+        }
+        let shortcode = shortcode.trim_matches(':');
+        let (name, tone) = SkinTone::split_shortcode(shortcode);
+        self.find_emoji(name)
+            .filter(|emoji| emoji.diversity)
+            .map(|emoji| emoji.unicode_with_skin_tone(tone))
+    }
+
+    // Text inserted by picker, with selected skin tone.
+    pub fn picker_text(&self, emoji: &UnicodeEmoji) -> String {
+        emoji.unicode_with_skin_tone(self.skin_tone)
+    }
+
+    // ":smile:" => "😄", unknown shortcodes are kept.
+    pub fn replace_shortcodes(&self, text: &str) -> String {
+        shortcode::replace_shortcodes(text, |code| self.unicode_for_shortcode(code))
+    }
+
+    // ":)" => "🙂" when it's a separate word, so urls as "http://" are not modified.
+    pub fn convert_ascii_emoticons(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut word_start: Option<usize> = None;
+        for (index, c) in text.char_indices() {
+            if c.is_whitespace() {
+                if let Some(start) = word_start.take() {
+                    result.push_str(&self.convert_ascii_word(&text[start..index]));
+                }
+                result.push(c);
+            } else if word_start.is_none() {
+                word_start = Some(index);
+            }
+        }
+        if let Some(start) = word_start {
+            result.push_str(&self.convert_ascii_word(&text[start..]));
+        }
+        result
+    }
+
+    fn convert_ascii_word(&self, word: &str) -> String {
+        ASCII_EMOTICONS
+            .iter()
+            .find(|(ascii, _)| *ascii == word)
+            .and_then(|(_, identifier)| self.find_emoji(identifier))
+            .map(|emoji| emoji.unicode.clone())
+            .unwrap_or_else(|| word.to_string())
+    }
+
+    // Text of a message, convert_ascii_emoji comes from user preferences.
+    pub fn convert_text(&self, text: &str, convert_ascii_emoji: bool) -> String {
+        let text = self.replace_shortcodes(text);
+        if convert_ascii_emoji {
+            self.convert_ascii_emoticons(&text)
+        } else {
+            text
+        }
+    }
+
+    pub fn emoji_used(&mut self, shortcode: &str) {
+        let (name, _) = SkinTone::split_shortcode(shortcode.trim_matches(':'));
+        if let Some(identifier) = self.find_emoji(name).map(|emoji| emoji.identifier.clone()) {
+            self.recent_emojis.add(&identifier);
+        }
+    }
+
+    pub fn recent(&self) -> Vec<&UnicodeEmoji> {
+        self.recent_emojis
+            .emojis
+            .iter()
+            .filter_map(|identifier| self.find_emoji(identifier))
+            .collect()
+    }
+
+    // Best matches first, recently used emojis win on equal rank.
+    pub fn search(&self, pattern: &str, max_results: usize) -> Vec<&UnicodeEmoji> {
+        let pattern = pattern.trim_matches(':').to_lowercase();
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+        let mut ranked: Vec<(u32, usize, usize, &UnicodeEmoji)> = self
+            .emojis
+            .iter()
+            .enumerate()
+            .filter_map(|(index, emoji)| {
+                let rank = match_rank(emoji, &pattern)?;
+                let recent = self
+                    .recent_emojis
+                    .position(&emoji.identifier)
+                    .unwrap_or(usize::MAX);
+                Some((rank, recent, index, emoji))
+            })
+            .collect();
+        ranked.sort_by_key(|(rank, recent, index, _)| (*rank, *recent, *index));
+        ranked
+            .into_iter()
+            .take(max_results)
+            .map(|(_, _, _, emoji)| emoji)
+            .collect()
+    }
+
+    // Section "Emoji": recentEmojis and skinTone.
+    pub fn load_settings(&mut self, file_name: &str) {
+        let Ok(conf) = Ini::load_from_file(file_name) else {
+            return;
+        };
+        if let Some(section) = conf.section(Some("Emoji")) {
+            if let Some(recent) = section.get("recentEmojis") {
+                self.recent_emojis.set_settings_value(recent);
+            }
+            if let Some(tone) = section.get("skinTone").and_then(|tone| tone.parse().ok()) {
+                self.skin_tone = SkinTone::from_index(tone);
+            }
+        }
+    }
+
+    // Other sections of file are kept.
+    pub fn write_settings(&self, file_name: &str) -> std::io::Result<()> {
+        let mut conf = Ini::load_from_file(file_name).unwrap_or_default();
+        conf.with_section(Some("Emoji"))
+            .set("recentEmojis", self.recent_emojis.to_settings_value())
+            .set("skinTone", self.skin_tone.index().to_string());
+        conf.write_to_file(file_name)
+    }
+}
+
+// Lower is better, None when pattern doesn't match.
+// 0: exact shortcode, 1: shortcode prefix, 2: start of a word in shortcode,
+// 3: exact keyword, 4: keyword or name prefix, 5: contains, 6: fuzzy shortcode
+fn match_rank(emoji: &UnicodeEmoji, pattern: &str) -> Option<u32> {
+    let shortcodes = || std::iter::once(&emoji.identifier).chain(emoji.aliases.iter());
+    if shortcodes().any(|code| code == pattern) {
+        Some(0)
+    } else if shortcodes().any(|code| code.starts_with(pattern)) {
+        Some(1)
+    } else if shortcodes().any(|code| {
+        code.split('_')
+            .skip(1)
+            .any(|word| word.starts_with(pattern))
+    }) {
+        Some(2)
+    } else if emoji.keywords.iter().any(|keyword| keyword == pattern) {
+        Some(3)
+    } else if emoji
+        .keywords
+        .iter()
+        .any(|keyword| keyword.starts_with(pattern))
+        || emoji.name.split(' ').any(|word| word.starts_with(pattern))
+    {
+        Some(4)
+    } else if shortcodes().any(|code| code.contains(pattern)) || emoji.name.contains(pattern) {
+        Some(5)
+    } else if pattern.chars().count() > 1 && shortcodes().any(|code| is_subsequence(pattern, code))
+    {
+        Some(6)
+    } else {
+        None
+    }
+}
+
+// "thmbup" is a subsequence of "thumbsup"
+fn is_subsequence(pattern: &str, text: &str) -> bool {
+    let mut chars = text.chars();
+    pattern.chars().all(|p| chars.any(|c| c == p))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemoji::{SkinTone, UnicodeEmoji};
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+
+    #[test]
+    fn test_bundled_table() {
+        let m = UnicodeEmojiManager::new();
+        assert!(m.emojis.len() > 1800);
+        for category in m.categories() {
+            assert!(
+                !m.emojis_for_category(&category.identifier).is_empty(),
+                "{}",
+                category
+            );
+        }
+        assert!(m.emojis.iter().all(|emoji| !emoji.category.is_empty()));
+        let emoji = m.find_emoji(":smile:").unwrap();
+        assert_eq!(emoji.unicode, "😄");
+        assert_eq!(emoji.category, "people");
+        assert_eq!(m.find_emoji("+1").unwrap().identifier, "thumbsup");
+        assert_eq!(m.find_emoji_by_unicode("🎉").unwrap().identifier, "tada");
+        assert!(m.find_emoji("foo").is_none());
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        let m = UnicodeEmojiManager::new();
+        assert_eq!(m.replace_shortcodes("hello :smile:"), "hello 😄");
+        assert_eq!(m.replace_shortcodes(":+1::tada:"), "👍🎉");
+        assert_eq!(m.replace_shortcodes(":thumbsup_tone5:"), "👍🏿");
+        assert_eq!(m.replace_shortcodes(":smile_tone5:"), ":smile_tone5:");
+        assert_eq!(m.replace_shortcodes(":melting_face:"), "🫠");
+        assert_eq!(m.replace_shortcodes(":flag_be: :be:"), "🇧🇪 🇧🇪");
+        assert_eq!(m.replace_shortcodes(":woman_technologist_tone3:"), "👩🏽‍💻");
+        assert_eq!(m.replace_shortcodes("at 10:30 :foo:"), "at 10:30 :foo:");
+    }
+
+    #[test]
+    fn test_convert_ascii_emoticons() {
+        let m = UnicodeEmojiManager::new();
+        assert_eq!(m.convert_ascii_emoticons("hello :)"), "hello 🙂");
+        assert_eq!(m.convert_ascii_emoticons(":D\n<3  ;)"), "😃\n❤️  😉");
+        assert_eq!(
+            m.convert_ascii_emoticons("see http://kde.org :/"),
+            "see http://kde.org 😕"
+        );
+        assert_eq!(m.convert_ascii_emoticons("(:)"), "(:)");
+        assert_eq!(m.convert_text(":smile: :)", false), "😄 :)");
+        assert_eq!(m.convert_text(":smile: :)", true), "😄 🙂");
+    }
+
+    #[test]
+    fn test_recent_emojis() {
+        let mut m = UnicodeEmojiManager::new();
+        m.emoji_used(":tada:");
+        m.emoji_used("+1");
+        m.emoji_used(":thumbsup_tone2:");
+        m.emoji_used("unknown");
+        assert_eq!(m.recent_emojis.emojis, vec!["thumbsup", "tada"]);
+        let recent: Vec<&str> = m.recent().iter().map(|e| e.unicode.as_str()).collect();
+        assert_eq!(recent, vec!["👍", "🎉"]);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut m = UnicodeEmojiManager::new();
+        assert!(m.search("", 10).is_empty());
+        let result = m.search("smile", 5);
+        assert_eq!(result[0].identifier, "smile");
+        assert!(result.iter().any(|emoji| emoji.identifier == "smiley"));
+
+        // Keyword
+        assert!(
+            m.search("party", 10)
+                .iter()
+                .any(|emoji| emoji.identifier == "tada")
+        );
+        // Fuzzy
+        assert_eq!(m.search("thmbsup", 1)[0].identifier, "thumbsup");
+        assert!(m.search("zzzzqx", 10).is_empty());
+
+        // Recent emoji first on equal rank
+        let position = |result: &[&UnicodeEmoji], identifier: &str| {
+            result.iter().position(|e| e.identifier == identifier)
+        };
+        let before = m.search("heart", 60);
+        assert!(position(&before, "blue_heart").is_some());
+        assert_eq!(before[0].identifier, "heart");
+        assert!(position(&before, "orange_heart") < position(&before, "blue_heart"));
+        m.emoji_used("blue_heart");
+        let after = m.search("heart", 60);
+        assert_eq!(after[0].identifier, "heart");
+        assert!(position(&after, "blue_heart") < position(&after, "orange_heart"));
+    }
+
+    #[test]
+    fn test_settings() {
+        let file_name = std::env::temp_dir()
+            .join(format!("ruqola-unicodeemoji-{}.ini", std::process::id()))
+            .to_string_lossy()
+            .to_string();
+        std::fs::write(&file_name, "[General]\naccountName=foo\n").unwrap();
+        let mut m = UnicodeEmojiManager::new();
+        m.emoji_used("tada");
+        m.skin_tone = SkinTone::Medium;
+        m.write_settings(&file_name).unwrap();
+
+        let mut b = UnicodeEmojiManager::new();
+        b.load_settings(&file_name);
+        assert_eq!(b.recent_emojis.emojis, vec!["tada"]);
+        assert_eq!(b.skin_tone, SkinTone::Medium);
+        assert_eq!(b.picker_text(b.find_emoji("wave").unwrap()), "👋🏽");
+        assert!(
+            std::fs::read_to_string(&file_name)
+                .unwrap()
+                .contains("accountName=foo")
+        );
+        let _ = std::fs::remove_file(&file_name);
+    }
+}
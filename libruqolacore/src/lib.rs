@@ -5,6 +5,9 @@
  */
 #[macro_use]
 extern crate lazy_static;
+pub mod attachments;
+pub mod autotranslate;
+pub mod autocomplete;
 pub mod avatarinfo;
 pub mod bannerinfo;
 pub mod bannerinfos;
@@ -12,21 +15,25 @@ pub mod command;
 pub mod customsoundinfo;
 pub mod customsoundinfos;
 pub mod deviceinfo;
+pub mod discussions;
 pub mod emoticons;
 pub mod file;
 pub mod fileattachments;
 pub mod invitation;
 pub mod languages;
 pub mod manager;
+pub mod markdown;
 pub mod message;
 pub mod messageinfo;
 pub mod messages;
+pub mod notification;
 pub mod notificationinfo;
 pub mod ownuser;
 pub mod permission;
 pub mod permissionaccesstokeninfo;
 pub mod permissionaccesstokeninfos;
 pub mod permissions;
+pub mod registrationflow;
 pub mod retentioninfo;
 pub mod rocketchataccount;
 pub mod rocketchataccountmanager;
@@ -34,8 +41,13 @@ pub mod rocketchataccountsettings;
 pub mod rocketchatbackend;
 pub mod roles;
 pub mod room;
+pub mod roomrestapi;
 pub mod rooms;
+pub mod ruqolaserverconfig;
 pub mod ruqolaserverconfigpassword;
 pub mod status;
 pub mod teams;
+pub mod typing;
+pub mod uikit;
+pub mod urlpreview;
 pub mod user;
@@ -4,30 +4,248 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PermissionsManager {}
+use crate::permissions::Permissions;
+use crate::rooms::Rooms;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{APIMethod, PermissionsListAllMethod};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct PermissionsManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    // Permission identifier => roles allowed
+    pub permissions: HashMap<String, Vec<String>>,
+    // Global roles of the logged user ("admin", "user", …)
+    pub user_roles: Vec<String>,
+    // Room identifier => roles from subscription ("owner", "moderator", …)
+    pub room_roles: HashMap<String, Vec<String>>,
+}
+
+impl Default for PermissionsManager {
+    fn default() -> Self {
+        PermissionsManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl PermissionsManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        PermissionsManager {
+            settings,
+            server_url,
+            permissions: HashMap::new(),
+            user_roles: Vec::new(),
+            room_roles: HashMap::new(),
+        }
+    }
+
+    // Load permissions.listAll result.
+    pub fn parse_permissions(&mut self, json: &str) {
+        let mut permissions = Permissions::new();
+        permissions.parse_elements(json);
+        self.permissions.clear();
+        self.apply_permissions(&permissions);
+    }
+
+    pub fn apply_permissions(&mut self, permissions: &Permissions) {
+        for permission in &permissions.update {
+            self.permissions
+                .insert(permission.identifier.clone(), permission.roles.clone());
+        }
+        for permission in &permissions.remove {
+            self.permissions.remove(&permission.identifier);
+        }
+    }
+
+    // "permissions-changed" stream: ["updated"|"inserted"|"removed", {"_id": …, "roles": […]}]
+    pub fn apply_permissions_changed(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        let (Some(action), Some(permission)) = (
+            args.first().and_then(|a| a.as_str()),
+            args.get(1).and_then(|p| p.as_object()),
+        ) else {
+            return;
+        };
+        let Some(identifier) = permission.get("_id").and_then(|i| i.as_str()) else {
+            return;
+        };
+        match action {
+            "removed" => {
+                self.permissions.remove(identifier);
+            }
+            "updated" | "inserted" | "changed" => {
+                // Setting permissions don't have roles
+                let Some(roles) = permission.get("roles").and_then(|r| r.as_array()) else {
+                    return;
+                };
+                let roles = roles
+                    .iter()
+                    .filter_map(|r| r.as_str())
+                    .map(|r| r.to_string())
+                    .collect();
+                self.permissions.insert(identifier.to_string(), roles);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn set_user_roles(&mut self, roles: Vec<String>) {
+        self.user_roles = roles;
+    }
+
+    pub fn set_room_roles(&mut self, room_id: &str, roles: Vec<String>) {
+        if roles.is_empty() {
+            self.room_roles.remove(room_id);
+        } else {
+            self.room_roles.insert(room_id.to_string(), roles);
+        }
+    }
+
+    pub fn set_rooms(&mut self, rooms: &Rooms) {
+        self.room_roles.clear();
+        for room in &rooms.rooms {
+            self.set_room_roles(&room.room_id, room.roles.clone());
+        }
+    }
+
+    pub fn roles(&self, permission: &str) -> Option<&Vec<String>> {
+        self.permissions.get(permission)
+    }
+
+    // Check global roles and, when room_id is not empty, the roles in this room.
+    pub fn has_permission(&self, permission: &str, room_id: &str) -> bool {
+        let Some(allowed_roles) = self.permissions.get(permission) else {
+            return false;
+        };
+        let room_roles = if room_id.is_empty() {
+            None
+        } else {
+            self.room_roles.get(room_id)
+        };
+        self.user_roles
+            .iter()
+            .chain(room_roles.into_iter().flatten())
+            .any(|role| allowed_roles.contains(role))
+    }
+
+    pub fn has_any_permission(&self, permissions: &[&str], room_id: &str) -> bool {
+        permissions
+            .iter()
+            .any(|permission| self.has_permission(permission, room_id))
+    }
+
+    pub async fn load_permissions(&mut self) -> Result<(), Error> {
+        let result = PermissionsListAllMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+        }
+        .call()
+        .await?;
+        self.parse_permissions(&result);
+        Ok(())
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    // TODO
-    /*
     use crate::manager::permissionsmanager::PermissionsManager;
+    use crate::room::Room;
+    use crate::rooms::Rooms;
+
+    fn generate_manager() -> PermissionsManager {
+        let mut m = PermissionsManager::default();
+        m.parse_permissions(
+            &std::fs::read_to_string("src/data/permissions/permissions.json")
+                .expect("Failed to open file"),
+        );
+        m
+    }
+
     #[test]
     fn test_default_values() {
-        let r = LicensesManager::new();
-        assert!(r.licenses.is_empty());
+        let m = PermissionsManager::default();
+        assert!(m.permissions.is_empty());
+        assert!(m.user_roles.is_empty());
+        assert!(m.room_roles.is_empty());
+        assert!(!m.has_permission("pin-message", ""));
     }
 
     #[test]
-    fn test_contains_license() {
-        let mut r = LicensesManager::new();
-        r.licenses.push("dd".to_string());
-        r.licenses.push("license2".to_string());
+    fn test_parse_permissions() {
+        let m = generate_manager();
+        assert_eq!(m.permissions.len(), 1147);
+        assert!(m.roles("pin-message").is_some());
+        assert!(m.roles("unknown-permission").is_none());
+    }
+
+    #[test]
+    fn test_has_permission() {
+        let mut m = PermissionsManager::default();
+        m.permissions.insert(
+            "pin-message".to_string(),
+            vec![
+                "owner".to_string(),
+                "moderator".to_string(),
+                "admin".to_string(),
+            ],
+        );
+        m.permissions
+            .insert("create-c".to_string(), vec!["user".to_string()]);
+        m.set_user_roles(vec!["user".to_string()]);
+        assert!(m.has_permission("create-c", ""));
+        assert!(!m.has_permission("pin-message", ""));
+        assert!(!m.has_permission("pin-message", "room1"));
+
+        // Owner in room1 only
+        let mut rooms = Rooms::new();
+        rooms.rooms = vec![
+            Room {
+                room_id: "room1".to_string(),
+                roles: vec!["owner".to_string()],
+                ..Default::default()
+            },
+            Room {
+                room_id: "room2".to_string(),
+                ..Default::default()
+            },
+        ];
+        m.set_rooms(&rooms);
+        assert!(m.has_permission("pin-message", "room1"));
+        assert!(!m.has_permission("pin-message", "room2"));
+        assert!(m.has_any_permission(&["pin-message", "create-c"], "room2"));
+
+        // Global admin
+        m.set_user_roles(vec!["user".to_string(), "admin".to_string()]);
+        assert!(m.has_permission("pin-message", "room2"));
+        assert!(!m.has_permission("unknown-permission", "room1"));
+    }
+
+    #[test]
+    fn test_apply_permissions_changed() {
+        let mut m = PermissionsManager::default();
+        m.set_user_roles(vec!["user".to_string()]);
+        m.apply_permissions_changed(&serde_json::json!([
+            "updated",
+            {"_id": "pin-message", "_updatedAt": {"$date": 1634615770401_i64}, "roles": ["admin", "user"]}
+        ]));
+        assert!(m.has_permission("pin-message", ""));
+
+        m.apply_permissions_changed(&serde_json::json!([
+            "updated",
+            {"_id": "pin-message", "roles": ["admin"]}
+        ]));
+        assert!(!m.has_permission("pin-message", ""));
+        assert_eq!(m.roles("pin-message").unwrap().len(), 1);
+
+        m.apply_permissions_changed(&serde_json::json!(["removed", {"_id": "pin-message"}]));
+        assert!(m.roles("pin-message").is_none());
 
-        assert!(!r.licenses.is_empty());
-        assert!(r.licenses.len() == 2);
-        assert!(r.has_license(String::from("dd")));
-        assert!(!r.has_license(String::from("dd2")));
+        // Invalid values are ignored
+        m.apply_permissions_changed(&serde_json::json!({"_id": "pin-message"}));
+        m.apply_permissions_changed(&serde_json::json!(["updated", {"_id": "foo"}]));
+        assert!(m.permissions.is_empty());
     }
-    */
 }
@@ -0,0 +1,312 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::markdown::markdownast::{Block, Document, Inline};
+use crate::markdown::rendercontext::{EmojiTarget, MarkdownRenderContext, MentionTarget};
+use crate::markdown::syntaxhighlighter::HighlightedToken;
+
+// Links used for mentions, handled by UI.
+pub const USER_LINK_PREFIX: &str = "ruqola:/user/";
+pub const ROOM_LINK_PREFIX: &str = "ruqola:/room/";
+
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+pub fn to_html(document: &Document, context: &MarkdownRenderContext) -> String {
+    let mut html = String::new();
+    render_blocks(&document.blocks, context, &mut html);
+    html
+}
+
+fn render_blocks(blocks: &[Block], context: &MarkdownRenderContext, html: &mut String) {
+    for block in blocks {
+        match block {
+            Block::Paragraph(content) => {
+                html.push_str("<p>");
+                render_inlines(content, context, html);
+                html.push_str("</p>");
+            }
+            Block::Heading { level, content } => {
+                html.push_str(&format!("<h{}>", level));
+                render_inlines(content, context, html);
+                html.push_str(&format!("</h{}>", level));
+            }
+            Block::Code { language, code } => {
+                match context.highlighter.and_then(|h| h.background_color()) {
+                    Some(background) => html.push_str(&format!(
+                        "<pre style=\"background-color:{}\">",
+                        background.name()
+                    )),
+                    None => html.push_str("<pre>"),
+                }
+                if language.is_empty() {
+                    html.push_str("<code>");
+                } else {
+                    html.push_str(&format!(
+                        "<code class=\"language-{}\">",
+                        escape_html(language)
+                    ));
+                }
+                match context.highlighter {
+                    Some(highlighter) => {
+                        for token in highlighter.highlight(code, language) {
+                            render_highlighted_token(&token, html);
+                        }
+                    }
+                    None => html.push_str(&escape_html(code)),
+                }
+                html.push_str("</code></pre>");
+            }
+            Block::Quote(blocks) => {
+                html.push_str("<blockquote>");
+                render_blocks(blocks, context, html);
+                html.push_str("</blockquote>");
+            }
+            Block::UnorderedList(items) => {
+                html.push_str("<ul>");
+                for item in items {
+                    html.push_str("<li>");
+                    render_inlines(item, context, html);
+                    html.push_str("</li>");
+                }
+                html.push_str("</ul>");
+            }
+            Block::OrderedList { start, items } => {
+                if *start == 1 {
+                    html.push_str("<ol>");
+                } else {
+                    html.push_str(&format!("<ol start=\"{}\">", start));
+                }
+                for item in items {
+                    html.push_str("<li>");
+                    render_inlines(item, context, html);
+                    html.push_str("</li>");
+                }
+                html.push_str("</ol>");
+            }
+            Block::TaskList(tasks) => {
+                html.push_str("<ul class=\"tasks\">");
+                for task in tasks {
+                    html.push_str(if task.checked {
+                        "<li><input type=\"checkbox\" checked disabled/> "
+                    } else {
+                        "<li><input type=\"checkbox\" disabled/> "
+                    });
+                    render_inlines(&task.content, context, html);
+                    html.push_str("</li>");
+                }
+                html.push_str("</ul>");
+            }
+            Block::Katex(formula) => {
+                html.push_str(&format!(
+                    "<div class=\"katex\">{}</div>",
+                    escape_html(formula)
+                ));
+            }
+        }
+    }
+}
+
+fn render_inlines(inlines: &[Inline], context: &MarkdownRenderContext, html: &mut String) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => html.push_str(&escape_html(text)),
+            Inline::Bold(children) => render_tag("b", children, context, html),
+            Inline::Italic(children) => render_tag("i", children, context, html),
+            Inline::Strike(children) => render_tag("s", children, context, html),
+            Inline::InlineCode(code) => {
+                html.push_str(&format!("<code>{}</code>", escape_html(code)));
+            }
+            Inline::Link { url, label } => {
+                html.push_str(&format!("<a href=\"{}\">", escape_html(url)));
+                render_inlines(label, context, html);
+                html.push_str("</a>");
+            }
+            Inline::UserMention(name) => match context.resolve_mention(name) {
+                MentionTarget::User { own, .. } => html.push_str(&format!(
+                    "<a href=\"{}{}\" class=\"{}\">@{}</a>",
+                    USER_LINK_PREFIX,
+                    escape_html(name),
+                    if own { "mention own" } else { "mention" },
+                    escape_html(name)
+                )),
+                MentionTarget::Group(group) => html.push_str(&format!(
+                    "<span class=\"mention group\">@{}</span>",
+                    escape_html(&group)
+                )),
+                MentionTarget::Unresolved => html.push_str(&format!("@{}", escape_html(name))),
+            },
+            Inline::ChannelMention(name) => match context.resolve_channel(name) {
+                Some(channel) => html.push_str(&format!(
+                    "<a href=\"{}{}\" class=\"channel\">#{}</a>",
+                    ROOM_LINK_PREFIX,
+                    escape_html(&channel.name),
+                    escape_html(name)
+                )),
+                None => html.push_str(&format!("#{}", escape_html(name))),
+            },
+            Inline::Emoji(shortcode) => match context.resolve_emoji(shortcode) {
+                EmojiTarget::Unicode(unicode) => html.push_str(&format!(
+                    "<span class=\"emoji\" title=\":{}:\">{}</span>",
+                    escape_html(shortcode),
+                    unicode
+                )),
+                EmojiTarget::Custom {
+                    shortcode,
+                    source: Some(source),
+                } => html.push_str(&format!(
+                    "<img class=\"custom-emoji\" src=\"{}\" title=\"{}\" alt=\"{}\"/>",
+                    escape_html(&source),
+                    escape_html(&shortcode),
+                    escape_html(&shortcode)
+                )),
+                _ => html.push_str(&format!(":{}:", escape_html(shortcode))),
+            },
+            Inline::Katex(formula) => html.push_str(&format!(
+                "<span class=\"katex\">{}</span>",
+                escape_html(formula)
+            )),
+            Inline::LineBreak => html.push_str("<br/>"),
+        }
+    }
+}
+
+fn render_highlighted_token(token: &HighlightedToken, html: &mut String) {
+    let mut style = String::new();
+    if let Some(color) = token.color {
+        style.push_str(&format!("color:{};", color.name()));
+    }
+    if token.bold {
+        style.push_str("font-weight:bold;");
+    }
+    if token.italic {
+        style.push_str("font-style:italic;");
+    }
+    if style.is_empty() {
+        html.push_str(&escape_html(&token.text));
+    } else {
+        html.push_str(&format!(
+            "<span style=\"{}\">{}</span>",
+            style,
+            escape_html(&token.text)
+        ));
+    }
+}
+
+fn render_tag(tag: &str, children: &[Inline], context: &MarkdownRenderContext, html: &mut String) {
+    html.push_str(&format!("<{}>", tag));
+    render_inlines(children, context, html);
+    html.push_str(&format!("</{}>", tag));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+    use crate::markdown::htmlrenderer::{escape_html, to_html};
+    use crate::markdown::markdownparser::parse;
+    use crate::markdown::rendercontext::MarkdownRenderContext;
+    use crate::markdown::syntaxhighlighter::SyntaxHighlighter;
+    use crate::message::channels::Channels;
+    use crate::message::mentions::Mentions;
+
+    fn html(text: &str) -> String {
+        to_html(&parse(text), &MarkdownRenderContext::new())
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<b>\"a\" & 'b'</b>"),
+            "&lt;b&gt;&quot;a&quot; &amp; &#39;b&#39;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(html(""), "");
+        assert_eq!(
+            html("*bold* _it_ ~no~ `<code>`"),
+            "<p><b>bold</b> <i>it</i> <s>no</s> <code>&lt;code&gt;</code></p>"
+        );
+        assert_eq!(html("a\nb <script>"), "<p>a<br/>b &lt;script&gt;</p>");
+        assert_eq!(
+            html("[KDE](https://kde.org?a=1&b=2)"),
+            "<p><a href=\"https://kde.org?a=1&amp;b=2\">KDE</a></p>"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(
+            html("## Title\n> quote\n```rust\nlet a = \"<\";\n```"),
+            "<h2>Title</h2><blockquote><p>quote</p></blockquote><pre><code class=\"language-rust\">let a = &quot;&lt;&quot;;</code></pre>"
+        );
+        assert_eq!(
+            html("- a\n- b\n\n2. c\n- [x] d"),
+            "<ul><li>a</li><li>b</li></ul><ol start=\"2\"><li>c</li></ol><ul class=\"tasks\"><li><input type=\"checkbox\" checked disabled/> d</li></ul>"
+        );
+        assert_eq!(html("\\[x < 2\\]"), "<div class=\"katex\">x &lt; 2</div>");
+    }
+
+    #[test]
+    fn test_mentions_and_emojis() {
+        let mentions: Mentions = serde_json::from_str(
+            r#"[{"_id": "id1", "username": "foo"}, {"_id": "id2", "username": "me"}]"#,
+        )
+        .unwrap();
+        let channels: Channels =
+            serde_json::from_str(r#"[{"_id": "GENERAL", "name": "general"}]"#).unwrap();
+        let emojis = UnicodeEmojiManager::new();
+        let context = MarkdownRenderContext {
+            mentions: Some(&mentions),
+            channels: Some(&channels),
+            own_user_name: "me",
+            unicode_emojis: Some(&emojis),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_html(&parse("@foo @me @bar @all #general #random"), &context),
+            "<p><a href=\"ruqola:/user/foo\" class=\"mention\">@foo</a> <a href=\"ruqola:/user/me\" class=\"mention own\">@me</a> @bar <span class=\"mention group\">@all</span> <a href=\"ruqola:/room/general\" class=\"channel\">#general</a> #random</p>"
+        );
+        assert_eq!(
+            to_html(&parse(":tada: :unknown:"), &context),
+            "<p><span class=\"emoji\" title=\":tada:\">🎉</span> :unknown:</p>"
+        );
+    }
+
+    #[test]
+    fn test_highlighted_code() {
+        let highlighter = SyntaxHighlighter::new();
+        let context = MarkdownRenderContext {
+            highlighter: Some(&highlighter),
+            ..Default::default()
+        };
+        let result = to_html(&parse("```rust\nlet a = \"<\";\n```"), &context);
+        assert!(result.starts_with(
+            "<pre style=\"background-color:#ffffff\"><code class=\"language-rust\"><span style=\"color:#"
+        ));
+        assert!(result.ends_with("</code></pre>"));
+        assert!(result.contains("&lt;"));
+        assert!(!result.contains("\"<\""));
+        // Unknown language is only escaped
+        assert_eq!(
+            to_html(&parse("```foo\na < b\n```"), &context),
+            "<pre style=\"background-color:#ffffff\"><code class=\"language-foo\">a &lt; b</code></pre>"
+        );
+    }
+}
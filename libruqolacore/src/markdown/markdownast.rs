@@ -0,0 +1,129 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Bold(Vec<Inline>),
+    Italic(Vec<Inline>),
+    Strike(Vec<Inline>),
+    InlineCode(String),
+    Link { url: String, label: Vec<Inline> },
+    // "@name", including "all" and "here"
+    UserMention(String),
+    // "#name"
+    ChannelMention(String),
+    // ":shortcode:", resolved by renderers
+    Emoji(String),
+    // "\(…\)" or "$$…$$" inside text
+    Katex(String),
+    LineBreak,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+    pub checked: bool,
+    pub content: Vec<Inline>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    // Level 1…4
+    Heading { level: u8, content: Vec<Inline> },
+    // Fenced code, language is empty when not specified
+    Code { language: String, code: String },
+    Quote(Vec<Block>),
+    UnorderedList(Vec<Vec<Inline>>),
+    OrderedList { start: u64, items: Vec<Vec<Inline>> },
+    TaskList(Vec<Task>),
+    // "\[…\]" or "$$…$$" on its own lines
+    Katex(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+}
+
+impl Document {
+    pub fn new() -> Self {
+        Document { blocks: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    // Users mentioned in document, in order, without duplicates.
+    pub fn user_mentions(&self) -> Vec<&str> {
+        let mut result = Vec::new();
+        self.visit_inlines(&mut |inline| {
+            if let Inline::UserMention(name) = inline
+                && !result.contains(&name.as_str())
+            {
+                result.push(name.as_str());
+            }
+        });
+        result
+    }
+
+    pub fn channel_mentions(&self) -> Vec<&str> {
+        let mut result = Vec::new();
+        self.visit_inlines(&mut |inline| {
+            if let Inline::ChannelMention(name) = inline
+                && !result.contains(&name.as_str())
+            {
+                result.push(name.as_str());
+            }
+        });
+        result
+    }
+
+    // Calls visitor for each inline, children included.
+    pub fn visit_inlines<'a, F: FnMut(&'a Inline)>(&'a self, visitor: &mut F) {
+        for block in &self.blocks {
+            visit_block(block, visitor);
+        }
+    }
+}
+
+fn visit_block<'a, F: FnMut(&'a Inline)>(block: &'a Block, visitor: &mut F) {
+    match block {
+        Block::Paragraph(content) | Block::Heading { content, .. } => {
+            visit_inlines(content, visitor)
+        }
+        Block::Quote(blocks) => {
+            for block in blocks {
+                visit_block(block, visitor);
+            }
+        }
+        Block::UnorderedList(items) | Block::OrderedList { items, .. } => {
+            for item in items {
+                visit_inlines(item, visitor);
+            }
+        }
+        Block::TaskList(tasks) => {
+            for task in tasks {
+                visit_inlines(&task.content, visitor);
+            }
+        }
+        Block::Code { .. } | Block::Katex(_) => {}
+    }
+}
+
+fn visit_inlines<'a, F: FnMut(&'a Inline)>(inlines: &'a [Inline], visitor: &mut F) {
+    for inline in inlines {
+        visitor(inline);
+        match inline {
+            Inline::Bold(children) | Inline::Italic(children) | Inline::Strike(children) => {
+                visit_inlines(children, visitor)
+            }
+            Inline::Link { label, .. } => visit_inlines(label, visitor),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,673 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::emoticons::shortcode::is_valid_shortcode;
+use crate::markdown::markdownast::{Block, Document, Inline, Task};
+
+// Rocket.Chat message markdown => Document.
+// Blocks are line based, inline formatting doesn't span several lines.
+pub fn parse(text: &str) -> Document {
+    let text = text.replace("\r\n", "\n");
+    let lines: Vec<&str> = text.split('\n').collect();
+    Document {
+        blocks: parse_blocks(&lines),
+    }
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            index += 1;
+            continue;
+        }
+        if let Some(language) = trimmed.strip_prefix("```") {
+            index = parse_code_block(lines, index, language, &mut blocks);
+        } else if let Some(end) = katex_block_end(trimmed) {
+            index = parse_katex_block(lines, index, end, &mut blocks);
+        } else if let Some((level, content)) = heading(line) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(content),
+            });
+            index += 1;
+        } else if line.starts_with('>') {
+            let mut quoted = Vec::new();
+            while index < lines.len() && lines[index].starts_with('>') {
+                let content = &lines[index][1..];
+                quoted.push(content.strip_prefix(' ').unwrap_or(content));
+                index += 1;
+            }
+            blocks.push(Block::Quote(parse_blocks(&quoted)));
+        } else if task_item(line).is_some() {
+            let mut tasks = Vec::new();
+            while let Some((checked, content)) = lines.get(index).and_then(|line| task_item(line)) {
+                tasks.push(Task {
+                    checked,
+                    content: parse_inlines(content),
+                });
+                index += 1;
+            }
+            blocks.push(Block::TaskList(tasks));
+        } else if unordered_item(line).is_some() {
+            let mut items = Vec::new();
+            while let Some(content) = lines
+                .get(index)
+                .and_then(|line| unordered_item(line).filter(|_| task_item(line).is_none()))
+            {
+                items.push(parse_inlines(content));
+                index += 1;
+            }
+            blocks.push(Block::UnorderedList(items));
+        } else if let Some((start, _)) = ordered_item(line) {
+            let mut items = Vec::new();
+            while let Some((_, content)) = lines.get(index).and_then(|line| ordered_item(line)) {
+                items.push(parse_inlines(content));
+                index += 1;
+            }
+            blocks.push(Block::OrderedList { start, items });
+        } else {
+            let mut content = Vec::new();
+            while index < lines.len()
+                && !lines[index].trim().is_empty()
+                && (content.is_empty() || !is_block_start(lines[index]))
+            {
+                if !content.is_empty() {
+                    content.push(Inline::LineBreak);
+                }
+                content.extend(parse_inlines(lines[index]));
+                index += 1;
+            }
+            blocks.push(Block::Paragraph(content));
+        }
+    }
+    blocks
+}
+
+// Returns index of line after block.
+fn parse_code_block(lines: &[&str], index: usize, rest: &str, blocks: &mut Vec<Block>) -> usize {
+    // "```code```" on one line
+    if let Some(code) = rest.strip_suffix("```")
+        && !code.is_empty()
+    {
+        blocks.push(Block::Code {
+            language: String::default(),
+            code: code.to_string(),
+        });
+        return index + 1;
+    }
+    let language = rest.trim().to_string();
+    let mut code = Vec::new();
+    let mut next = index + 1;
+    while next < lines.len() {
+        let line = lines[next];
+        next += 1;
+        if line.trim() == "```" {
+            break;
+        }
+        if let Some(last) = line.trim_end().strip_suffix("```") {
+            code.push(last);
+            break;
+        }
+        code.push(line);
+    }
+    blocks.push(Block::Code {
+        language,
+        code: code.join("\n"),
+    });
+    next
+}
+
+// Closing delimiter when line starts a KaTeX block: opening delimiter alone,
+// or a formula filling the whole line.
+fn katex_block_end(trimmed: &str) -> Option<&'static str> {
+    [("$$", "$$"), ("\\[", "\\]")]
+        .into_iter()
+        .find(|(start, end)| {
+            trimmed == *start
+                || (trimmed.len() > 4 && trimmed.starts_with(start) && trimmed.ends_with(end))
+        })
+        .map(|(_, end)| end)
+}
+
+fn parse_katex_block(lines: &[&str], index: usize, end: &str, blocks: &mut Vec<Block>) -> usize {
+    let first = &lines[index].trim()[2..];
+    // "$$x^2$$" on one line
+    if let Some(formula) = first.strip_suffix(end) {
+        blocks.push(Block::Katex(formula.trim().to_string()));
+        return index + 1;
+    }
+    let mut formula = vec![first];
+    let mut next = index + 1;
+    while next < lines.len() {
+        let line = lines[next].trim();
+        next += 1;
+        if let Some(last) = line.strip_suffix(end) {
+            formula.push(last);
+            break;
+        }
+        formula.push(line);
+    }
+    let formula: Vec<&str> = formula.into_iter().filter(|l| !l.is_empty()).collect();
+    blocks.push(Block::Katex(formula.join("\n")));
+    next
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=4).contains(&level) {
+        return None;
+    }
+    let content = line[level..].strip_prefix(' ')?;
+    Some((level as u8, content.trim()))
+}
+
+fn unordered_item(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+}
+
+fn task_item(line: &str) -> Option<(bool, &str)> {
+    let item = unordered_item(line)?;
+    if let Some(content) = item.strip_prefix("[ ] ") {
+        Some((false, content))
+    } else {
+        item.strip_prefix("[x] ")
+            .or_else(|| item.strip_prefix("[X] "))
+            .map(|content| (true, content))
+    }
+}
+
+fn ordered_item(line: &str) -> Option<(u64, &str)> {
+    let line = line.trim_start();
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let content = line[digits..].strip_prefix(". ")?;
+    Some((line[..digits].parse().ok()?, content))
+}
+
+fn is_block_start(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with("```")
+        || katex_block_end(trimmed).is_some()
+        || heading(line).is_some()
+        || line.starts_with('>')
+        || unordered_item(line).is_some()
+        || ordered_item(line).is_some()
+}
+
+// Inline formatting of one line.
+pub fn parse_inlines(text: &str) -> Vec<Inline> {
+    let mut result: Vec<Inline> = Vec::new();
+    let mut buffer = String::new();
+    let mut index = 0;
+    while index < text.len() {
+        let c = text[index..].chars().next().unwrap_or_default();
+        if let Some((inline, length)) = parse_inline_at(text, index) {
+            match inline {
+                Inline::Text(literal) => buffer.push_str(&literal),
+                inline => {
+                    if !buffer.is_empty() {
+                        result.push(Inline::Text(std::mem::take(&mut buffer)));
+                    }
+                    result.push(inline);
+                }
+            }
+            index += length;
+        } else {
+            buffer.push(c);
+            index += c.len_utf8();
+        }
+    }
+    if !buffer.is_empty() {
+        result.push(Inline::Text(buffer));
+    }
+    result
+}
+
+// Inline starting at index and its length in bytes.
+fn parse_inline_at(text: &str, index: usize) -> Option<(Inline, usize)> {
+    let rest = &text[index..];
+    let previous = text[..index].chars().next_back();
+    let at_word_start = previous.is_none_or(|c| !c.is_alphanumeric());
+    match rest.chars().next()? {
+        '\\' => parse_escape(rest),
+        '`' => {
+            let end = rest[1..].find('`')?;
+            (end > 0).then(|| (Inline::InlineCode(rest[1..end + 1].to_string()), end + 2))
+        }
+        '$' if rest.starts_with("$$") => {
+            let end = rest[2..].find("$$")?;
+            (end > 0).then(|| (Inline::Katex(rest[2..end + 2].to_string()), end + 4))
+        }
+        marker @ ('*' | '_' | '~') if at_word_start => parse_emphasis(rest, marker),
+        '[' => parse_link(rest),
+        '<' => parse_angle_link(rest),
+        'h' | 'f' if at_word_start => parse_url(rest),
+        '@' if at_word_start && previous != Some('.') => {
+            let name = mention_name(&rest[1..])?;
+            Some((Inline::UserMention(name.to_string()), name.len() + 1))
+        }
+        '#' if at_word_start && previous != Some('&') => {
+            let name = mention_name(&rest[1..])?;
+            Some((Inline::ChannelMention(name.to_string()), name.len() + 1))
+        }
+        ':' if at_word_start => {
+            let end = rest[1..].find(':')?;
+            let shortcode = &rest[1..end + 1];
+            is_valid_shortcode(shortcode).then(|| (Inline::Emoji(shortcode.to_string()), end + 2))
+        }
+        _ => None,
+    }
+}
+
+fn parse_escape(rest: &str) -> Option<(Inline, usize)> {
+    if let Some(formula) = rest.strip_prefix("\\(") {
+        let end = formula.find("\\)")?;
+        return Some((Inline::Katex(formula[..end].to_string()), end + 4));
+    }
+    let escaped = rest[1..].chars().next()?;
+    escaped
+        .is_ascii_punctuation()
+        .then(|| (Inline::Text(escaped.to_string()), 1 + escaped.len_utf8()))
+}
+
+// "*bold*", "_italic_", "~strike~", doubled markers are accepted too.
+fn parse_emphasis(rest: &str, marker: char) -> Option<(Inline, usize)> {
+    let doubled: String = [marker, marker].iter().collect();
+    let delimiter = if rest.starts_with(&doubled) {
+        doubled.as_str()
+    } else {
+        &rest[..1]
+    };
+    let start = delimiter.len();
+    if rest[start..].chars().next()?.is_whitespace() {
+        return None;
+    }
+    let end = find_closing(rest, start, delimiter)?;
+    let content = parse_inlines(&rest[start..end]);
+    let inline = match marker {
+        '*' => Inline::Bold(content),
+        '_' => Inline::Italic(content),
+        _ => Inline::Strike(content),
+    };
+    Some((inline, end + delimiter.len()))
+}
+
+// Closing delimiter follows a non space character, and isn't inside inline code.
+fn find_closing(text: &str, from: usize, delimiter: &str) -> Option<usize> {
+    let mut in_code = false;
+    let mut escaped = false;
+    for (offset, c) in text[from..].char_indices() {
+        let index = from + offset;
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '`' => in_code = !in_code,
+            _ if !in_code && index > from && text[index..].starts_with(delimiter) => {
+                let before = text[..index].chars().next_back()?;
+                let after = text[index + delimiter.len()..].chars().next();
+                // "snake_case" is not italic
+                if !before.is_whitespace() && after.is_none_or(|c| !c.is_alphanumeric()) {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// "[label](url)"
+fn parse_link(rest: &str) -> Option<(Inline, usize)> {
+    let label_end = rest.find("](")?;
+    let label = &rest[1..label_end];
+    let url_start = label_end + 2;
+    let url_end = url_start + rest[url_start..].find(')')?;
+    let url = &rest[url_start..url_end];
+    if label.is_empty() || !is_allowed_url(url) || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((
+        Inline::Link {
+            url: url.to_string(),
+            label: parse_inlines(label),
+        },
+        url_end + 1,
+    ))
+}
+
+// "<url|label>"
+fn parse_angle_link(rest: &str) -> Option<(Inline, usize)> {
+    let end = rest.find('>')?;
+    let (url, label) = rest[1..end].split_once('|')?;
+    if !is_allowed_url(url) || url.contains(char::is_whitespace) || label.is_empty() {
+        return None;
+    }
+    Some((
+        Inline::Link {
+            url: url.to_string(),
+            label: parse_inlines(label),
+        },
+        end + 1,
+    ))
+}
+
+const URL_SCHEMES: [&str; 3] = ["http://", "https://", "ftp://"];
+
+// "javascript:" and co. stay plain text.
+fn is_allowed_url(url: &str) -> bool {
+    URL_SCHEMES.iter().chain(&["mailto:"]).any(|scheme| {
+        url.len() > scheme.len()
+            && url
+                .get(..scheme.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    })
+}
+
+fn parse_url(rest: &str) -> Option<(Inline, usize)> {
+    if !URL_SCHEMES.iter().any(|scheme| rest.starts_with(scheme)) {
+        return None;
+    }
+    let end = rest
+        .find(|c: char| c.is_whitespace() || c == '<' || c == '>')
+        .unwrap_or(rest.len());
+    let mut url = &rest[..end];
+    // Punctuation ending a sentence, ")" of "(see https://…)"
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', '"']);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(without) if trimmed.matches('(').count() < trimmed.matches(')').count() => without,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+    if url.ends_with("://") {
+        return None;
+    }
+    Some((
+        Inline::Link {
+            url: url.to_string(),
+            label: vec![Inline::Text(url.to_string())],
+        },
+        url.len(),
+    ))
+}
+
+// User or room name after "@" or "#".
+fn mention_name(text: &str) -> Option<&str> {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || "._-".contains(c)))
+        .unwrap_or(text.len());
+    let name = text[..end].trim_end_matches(['.', '-']);
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::markdown::markdownast::{Block, Inline, Task};
+    use crate::markdown::markdownparser::{parse, parse_inlines};
+
+    fn text(value: &str) -> Inline {
+        Inline::Text(value.to_string())
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(parse("").is_empty());
+        assert!(parse("\n  \n").is_empty());
+        assert!(parse_inlines("").is_empty());
+    }
+
+    #[test]
+    fn test_emphasis() {
+        assert_eq!(
+            parse_inlines("a *bold* _italic_ ~strike~"),
+            vec![
+                text("a "),
+                Inline::Bold(vec![text("bold")]),
+                text(" "),
+                Inline::Italic(vec![text("italic")]),
+                text(" "),
+                Inline::Strike(vec![text("strike")]),
+            ]
+        );
+        assert_eq!(
+            parse_inlines("**bold _and italic_**"),
+            vec![Inline::Bold(vec![
+                text("bold "),
+                Inline::Italic(vec![text("and italic")])
+            ])]
+        );
+        assert_eq!(
+            parse_inlines("snake_case_name"),
+            vec![text("snake_case_name")]
+        );
+        assert_eq!(parse_inlines("2 * 3 * 4"), vec![text("2 * 3 * 4")]);
+        assert_eq!(parse_inlines("*not closed"), vec![text("*not closed")]);
+        assert_eq!(parse_inlines("\\*escaped\\*"), vec![text("*escaped*")]);
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(
+            parse_inlines("run `cargo *test*` now"),
+            vec![
+                text("run "),
+                Inline::InlineCode("cargo *test*".to_string()),
+                text(" now")
+            ]
+        );
+        assert_eq!(
+            parse_inlines("*a `b*` c*"),
+            vec![Inline::Bold(vec![
+                text("a "),
+                Inline::InlineCode("b*".to_string()),
+                text(" c")
+            ])]
+        );
+        assert_eq!(
+            parse("```rust\nfn main() {\n\n}\n```\nafter").blocks,
+            vec![
+                Block::Code {
+                    language: "rust".to_string(),
+                    code: "fn main() {\n\n}".to_string()
+                },
+                Block::Paragraph(vec![text("after")])
+            ]
+        );
+        assert_eq!(
+            parse("```let a = 1;```").blocks,
+            vec![Block::Code {
+                language: String::default(),
+                code: "let a = 1;".to_string()
+            }]
+        );
+        // Not closed: until end of message
+        assert_eq!(
+            parse("```\n*a*").blocks,
+            vec![Block::Code {
+                language: String::default(),
+                code: "*a*".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(
+            parse_inlines("see [KDE *site*](https://kde.org)."),
+            vec![
+                text("see "),
+                Inline::Link {
+                    url: "https://kde.org".to_string(),
+                    label: vec![text("KDE "), Inline::Bold(vec![text("site")])]
+                },
+                text(".")
+            ]
+        );
+        assert_eq!(
+            parse_inlines("(see https://kde.org/foo_bar_baz), ok"),
+            vec![
+                text("(see "),
+                Inline::Link {
+                    url: "https://kde.org/foo_bar_baz".to_string(),
+                    label: vec![text("https://kde.org/foo_bar_baz")]
+                },
+                text("), ok")
+            ]
+        );
+        assert_eq!(
+            parse_inlines("<https://kde.org|KDE>"),
+            vec![Inline::Link {
+                url: "https://kde.org".to_string(),
+                label: vec![text("KDE")]
+            }]
+        );
+        assert_eq!(
+            parse_inlines("http:// nothing"),
+            vec![text("http:// nothing")]
+        );
+        assert_eq!(
+            parse_inlines("[mail](MAILTO:foo@kde.org)"),
+            vec![Inline::Link {
+                url: "MAILTO:foo@kde.org".to_string(),
+                label: vec![text("mail")]
+            }]
+        );
+        assert!(
+            !parse_inlines("[click](javascript:alert(1))")
+                .iter()
+                .any(|inline| matches!(inline, Inline::Link { .. }))
+        );
+        assert!(
+            !parse_inlines("<data:text/html,foo|click> [a](/relative) [b](mailto:)")
+                .iter()
+                .any(|inline| matches!(inline, Inline::Link { .. }))
+        );
+    }
+
+    #[test]
+    fn test_mentions() {
+        assert_eq!(
+            parse_inlines("@laurent.montel: see #dev-team and @all."),
+            vec![
+                Inline::UserMention("laurent.montel".to_string()),
+                text(": see "),
+                Inline::ChannelMention("dev-team".to_string()),
+                text(" and "),
+                Inline::UserMention("all".to_string()),
+                text("."),
+            ]
+        );
+        assert_eq!(parse_inlines("foo@kde.org"), vec![text("foo@kde.org")]);
+        assert_eq!(parse_inlines("a # b"), vec![text("a # b")]);
+        assert_eq!(parse_inlines("&#39;"), vec![text("&#39;")]);
+    }
+
+    #[test]
+    fn test_emoji_and_katex() {
+        assert_eq!(
+            parse_inlines("hi :smile::+1: at 10:30:00"),
+            vec![
+                text("hi "),
+                Inline::Emoji("smile".to_string()),
+                Inline::Emoji("+1".to_string()),
+                text(" at 10:30:00"),
+            ]
+        );
+        assert_eq!(
+            parse_inlines("area \\(\\pi r^2\\) and $$e=mc^2$$"),
+            vec![
+                text("area "),
+                Inline::Katex("\\pi r^2".to_string()),
+                text(" and "),
+                Inline::Katex("e=mc^2".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse("$$\n\\sum_{i=0}^n i\n$$").blocks,
+            vec![Block::Katex("\\sum_{i=0}^n i".to_string())]
+        );
+        assert_eq!(
+            parse("\\[x^2\\]").blocks,
+            vec![Block::Katex("x^2".to_string())]
+        );
+        assert_eq!(
+            parse("$$a$$ is inline\nnext").blocks,
+            vec![Block::Paragraph(vec![
+                Inline::Katex("a".to_string()),
+                text(" is inline"),
+                Inline::LineBreak,
+                text("next")
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let document = parse(
+            "# Title\nfirst line\nsecond *line*\n\n> quoted\n> - item\n\n- a\n* b\n3. c\n4. d\n- [ ] todo\n- [x] done\n#### small\n##### not heading",
+        );
+        assert_eq!(
+            document.blocks,
+            vec![
+                Block::Heading {
+                    level: 1,
+                    content: vec![text("Title")]
+                },
+                Block::Paragraph(vec![
+                    text("first line"),
+                    Inline::LineBreak,
+                    text("second "),
+                    Inline::Bold(vec![text("line")])
+                ]),
+                Block::Quote(vec![
+                    Block::Paragraph(vec![text("quoted")]),
+                    Block::UnorderedList(vec![vec![text("item")]])
+                ]),
+                Block::UnorderedList(vec![vec![text("a")], vec![text("b")]]),
+                Block::OrderedList {
+                    start: 3,
+                    items: vec![vec![text("c")], vec![text("d")]]
+                },
+                Block::TaskList(vec![
+                    Task {
+                        checked: false,
+                        content: vec![text("todo")]
+                    },
+                    Task {
+                        checked: true,
+                        content: vec![text("done")]
+                    }
+                ]),
+                Block::Heading {
+                    level: 4,
+                    content: vec![text("small")]
+                },
+                Block::Paragraph(vec![text("##### not heading")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_mentions() {
+        let document = parse("@foo #general\n> @bar and *@foo*\n- #random");
+        assert_eq!(document.user_mentions(), vec!["foo", "bar"]);
+        assert_eq!(document.channel_mentions(), vec!["general", "random"]);
+    }
+}
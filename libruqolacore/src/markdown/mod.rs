@@ -0,0 +1,12 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+pub mod htmlrenderer;
+pub mod markdownast;
+pub mod markdownparser;
+pub mod plaintextrenderer;
+pub mod rendercontext;
+pub mod styledspanrenderer;
+pub mod syntaxhighlighter;
@@ -0,0 +1,126 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::markdown::markdownast::{Block, Document, Inline};
+use crate::markdown::rendercontext::{EmojiTarget, MarkdownRenderContext};
+
+// Text without formatting, for notifications, room previews or clipboard.
+pub fn to_plain_text(document: &Document, context: &MarkdownRenderContext) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    render_blocks(&document.blocks, context, "", &mut lines);
+    lines.join("\n")
+}
+
+fn render_blocks(
+    blocks: &[Block],
+    context: &MarkdownRenderContext,
+    prefix: &str,
+    lines: &mut Vec<String>,
+) {
+    for block in blocks {
+        match block {
+            Block::Paragraph(content) | Block::Heading { content, .. } => {
+                for line in render_inlines(content, context).split('\n') {
+                    lines.push(format!("{}{}", prefix, line));
+                }
+            }
+            Block::Code { code, .. } | Block::Katex(code) => {
+                for line in code.split('\n') {
+                    lines.push(format!("{}{}", prefix, line));
+                }
+            }
+            Block::Quote(blocks) => {
+                render_blocks(blocks, context, &format!("{}> ", prefix), lines);
+            }
+            Block::UnorderedList(items) => {
+                for item in items {
+                    lines.push(format!("{}- {}", prefix, render_inlines(item, context)));
+                }
+            }
+            Block::OrderedList { start, items } => {
+                for (index, item) in items.iter().enumerate() {
+                    lines.push(format!(
+                        "{}{}. {}",
+                        prefix,
+                        *start + index as u64,
+                        render_inlines(item, context)
+                    ));
+                }
+            }
+            Block::TaskList(tasks) => {
+                for task in tasks {
+                    lines.push(format!(
+                        "{}- [{}] {}",
+                        prefix,
+                        if task.checked { "x" } else { " " },
+                        render_inlines(&task.content, context)
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn render_inlines(inlines: &[Inline], context: &MarkdownRenderContext) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(value) | Inline::InlineCode(value) | Inline::Katex(value) => {
+                text.push_str(value)
+            }
+            Inline::Bold(children) | Inline::Italic(children) | Inline::Strike(children) => {
+                text.push_str(&render_inlines(children, context))
+            }
+            Inline::Link { url, label } => {
+                let label = render_inlines(label, context);
+                if label == *url {
+                    text.push_str(url);
+                } else {
+                    text.push_str(&format!("{} ({})", label, url));
+                }
+            }
+            Inline::UserMention(name) => text.push_str(&format!("@{}", name)),
+            Inline::ChannelMention(name) => text.push_str(&format!("#{}", name)),
+            Inline::Emoji(shortcode) => match context.resolve_emoji(shortcode) {
+                EmojiTarget::Unicode(unicode) => text.push_str(&unicode),
+                _ => text.push_str(&format!(":{}:", shortcode)),
+            },
+            Inline::LineBreak => text.push('\n'),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+    use crate::markdown::markdownparser::parse;
+    use crate::markdown::plaintextrenderer::to_plain_text;
+    use crate::markdown::rendercontext::MarkdownRenderContext;
+
+    #[test]
+    fn test_plain_text() {
+        let emojis = UnicodeEmojiManager::new();
+        let context = MarkdownRenderContext {
+            unicode_emojis: Some(&emojis),
+            ..Default::default()
+        };
+        let plain = |text: &str| to_plain_text(&parse(text), &context);
+        assert_eq!(plain(""), "");
+        assert_eq!(
+            plain("*bold* _it_ `code` :smile: :foo:"),
+            "bold it code 😄 :foo:"
+        );
+        assert_eq!(
+            plain("[KDE](https://kde.org) https://kde.org @foo #general"),
+            "KDE (https://kde.org) https://kde.org @foo #general"
+        );
+        assert_eq!(
+            plain("# Title\n> a\n> b\n\n3. c\n- [ ] d\n```\nx\n```"),
+            "Title\n> a\n> b\n3. c\n- [ ] d\nx"
+        );
+    }
+}
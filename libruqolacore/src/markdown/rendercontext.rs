@@ -0,0 +1,191 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::emoticons::customemojimanager::CustomEmojiManager;
+use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+use crate::markdown::syntaxhighlighter::SyntaxHighlighter;
+use crate::message::channelinfo::ChannelInfo;
+use crate::message::channels::Channels;
+use crate::message::mentions::Mentions;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MentionTarget {
+    User {
+        user_name: String,
+        // Empty when message doesn't provide mentions
+        user_identifier: String,
+        own: bool,
+    },
+    // "all" or "here"
+    Group(String),
+    // Not a mention for server, displayed as text
+    Unresolved,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EmojiTarget {
+    Unicode(String),
+    Custom {
+        // ":name:"
+        shortcode: String,
+        // Cached file or server url
+        source: Option<String>,
+    },
+    Unknown,
+}
+
+// Data used by renderers to resolve mentions, channels and emojis.
+// Without mentions (or channels) all names are considered valid, as for a message being written.
+#[derive(Clone, Copy, Default)]
+pub struct MarkdownRenderContext<'a> {
+    pub mentions: Option<&'a Mentions>,
+    pub channels: Option<&'a Channels>,
+    pub own_user_name: &'a str,
+    pub unicode_emojis: Option<&'a UnicodeEmojiManager>,
+    pub custom_emojis: Option<&'a CustomEmojiManager>,
+    // Code blocks are not highlighted without it
+    pub highlighter: Option<&'a SyntaxHighlighter>,
+}
+
+impl<'a> MarkdownRenderContext<'a> {
+    pub fn new() -> Self {
+        MarkdownRenderContext {
+            ..Default::default()
+        }
+    }
+
+    pub fn resolve_mention(&self, name: &str) -> MentionTarget {
+        if name == "all" || name == "here" {
+            return MentionTarget::Group(name.to_string());
+        }
+        let user_identifier = match self.mentions {
+            Some(mentions) => match mentions.user_identifier(name) {
+                Some(identifier) => identifier.to_string(),
+                None => return MentionTarget::Unresolved,
+            },
+            None => String::default(),
+        };
+        MentionTarget::User {
+            user_name: name.to_string(),
+            user_identifier,
+            own: !self.own_user_name.is_empty() && name == self.own_user_name,
+        }
+    }
+
+    // None when channel is not referenced by message.
+    pub fn resolve_channel(&self, name: &str) -> Option<ChannelInfo> {
+        match self.channels {
+            Some(channels) => channels.find_channel(name).cloned(),
+            None => Some(ChannelInfo {
+                name: name.to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn resolve_emoji(&self, shortcode: &str) -> EmojiTarget {
+        if let Some(unicode) = self
+            .unicode_emojis
+            .and_then(|emojis| emojis.unicode_for_shortcode(shortcode))
+        {
+            return EmojiTarget::Unicode(unicode);
+        }
+        if let Some(custom_emojis) = self.custom_emojis
+            && let Some(emoji) = custom_emojis.find_emoji(shortcode)
+        {
+            return EmojiTarget::Custom {
+                shortcode: format!(":{}:", shortcode),
+                source: custom_emojis.image_source(emoji),
+            };
+        }
+        EmojiTarget::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::customemojimanager::CustomEmojiManager;
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+    use crate::markdown::rendercontext::{EmojiTarget, MarkdownRenderContext, MentionTarget};
+    use crate::message::channels::Channels;
+    use crate::message::mentions::Mentions;
+
+    #[test]
+    fn test_default_values() {
+        let context = MarkdownRenderContext::new();
+        assert!(context.mentions.is_none());
+        assert!(context.channels.is_none());
+        assert!(context.own_user_name.is_empty());
+        assert_eq!(
+            context.resolve_mention("foo"),
+            MentionTarget::User {
+                user_name: "foo".to_string(),
+                user_identifier: String::default(),
+                own: false
+            }
+        );
+        assert_eq!(context.resolve_channel("general").unwrap().name, "general");
+        assert_eq!(context.resolve_emoji("smile"), EmojiTarget::Unknown);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mentions: Mentions = serde_json::from_str(
+            r#"[{"_id": "id1", "username": "foo"}, {"_id": "id2", "username": "me"}]"#,
+        )
+        .unwrap();
+        let channels: Channels =
+            serde_json::from_str(r#"[{"_id": "GENERAL", "name": "general"}]"#).unwrap();
+        let unicode_emojis = UnicodeEmojiManager::new();
+        let mut custom_emojis = CustomEmojiManager::default();
+        custom_emojis.server_url = "https://mydomain.com".to_string();
+        custom_emojis.parse_elements(
+            r#"{"emojis": [{"_id": "id1", "name": "kde", "aliases": [], "extension": "png"}]}"#,
+        );
+        let context = MarkdownRenderContext {
+            mentions: Some(&mentions),
+            channels: Some(&channels),
+            own_user_name: "me",
+            unicode_emojis: Some(&unicode_emojis),
+            custom_emojis: Some(&custom_emojis),
+            ..Default::default()
+        };
+        assert_eq!(
+            context.resolve_mention("foo"),
+            MentionTarget::User {
+                user_name: "foo".to_string(),
+                user_identifier: "id1".to_string(),
+                own: false
+            }
+        );
+        assert!(matches!(
+            context.resolve_mention("me"),
+            MentionTarget::User { own: true, .. }
+        ));
+        assert_eq!(context.resolve_mention("bar"), MentionTarget::Unresolved);
+        assert_eq!(
+            context.resolve_mention("here"),
+            MentionTarget::Group("here".to_string())
+        );
+        assert_eq!(
+            context.resolve_channel("general").unwrap().identifier,
+            "GENERAL"
+        );
+        assert!(context.resolve_channel("random").is_none());
+        assert_eq!(
+            context.resolve_emoji("tada"),
+            EmojiTarget::Unicode("🎉".to_string())
+        );
+        assert_eq!(
+            context.resolve_emoji("kde"),
+            EmojiTarget::Custom {
+                shortcode: ":kde:".to_string(),
+                source: Some("https://mydomain.com/emoji-custom/kde.png".to_string())
+            }
+        );
+        assert_eq!(context.resolve_emoji("foo"), EmojiTarget::Unknown);
+    }
+}
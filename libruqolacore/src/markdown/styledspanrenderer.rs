@@ -0,0 +1,457 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::markdown::markdownast::{Block, Document, Inline};
+use crate::markdown::rendercontext::{EmojiTarget, MarkdownRenderContext, MentionTarget};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpanColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl SpanColor {
+    // "#rrggbb"
+    pub fn name(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub strike: bool,
+    pub code: bool,
+    // 0 when it's not a heading
+    pub heading_level: u8,
+    pub quote_level: u8,
+    // Set by syntax highlighting, None for default text color
+    pub color: Option<SpanColor>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SpanKind {
+    #[default]
+    Text,
+    Link(String),
+    UserMention {
+        user_name: String,
+        own: bool,
+    },
+    GroupMention,
+    ChannelMention {
+        room_name: String,
+    },
+    Emoji {
+        shortcode: String,
+        // Image of custom emoji, text is used otherwise
+        image_source: Option<String>,
+    },
+    Katex,
+    CodeBlock {
+        language: String,
+    },
+    // "• ", "1. " or checkbox before list item
+    ListMarker,
+    LineBreak,
+}
+
+// Flat list of spans, each one displayed as a styled text element in UI.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: SpanStyle,
+    pub kind: SpanKind,
+}
+
+impl StyledSpan {
+    pub fn new(text: &str, style: SpanStyle, kind: SpanKind) -> Self {
+        StyledSpan {
+            text: text.to_string(),
+            style,
+            kind,
+        }
+    }
+}
+
+pub fn to_styled_spans(document: &Document, context: &MarkdownRenderContext) -> Vec<StyledSpan> {
+    let mut spans = Vec::new();
+    render_blocks(&document.blocks, context, SpanStyle::default(), &mut spans);
+    spans
+}
+
+fn push_span(spans: &mut Vec<StyledSpan>, span: StyledSpan) {
+    // Merge text with same style
+    if span.kind == SpanKind::Text
+        && let Some(last) = spans.last_mut()
+        && last.kind == SpanKind::Text
+        && last.style == span.style
+    {
+        last.text.push_str(&span.text);
+        return;
+    }
+    spans.push(span);
+}
+
+// Between blocks
+fn push_line_break(spans: &mut Vec<StyledSpan>) {
+    if !spans.is_empty() {
+        spans.push(StyledSpan::new(
+            "\n",
+            SpanStyle::default(),
+            SpanKind::LineBreak,
+        ));
+    }
+}
+
+fn render_blocks(
+    blocks: &[Block],
+    context: &MarkdownRenderContext,
+    style: SpanStyle,
+    spans: &mut Vec<StyledSpan>,
+) {
+    for block in blocks {
+        match block {
+            Block::Paragraph(content) => {
+                push_line_break(spans);
+                render_inlines(content, context, style, spans);
+            }
+            Block::Heading { level, content } => {
+                push_line_break(spans);
+                let style = SpanStyle {
+                    heading_level: *level,
+                    ..style
+                };
+                render_inlines(content, context, style, spans);
+            }
+            Block::Code { language, code } => {
+                push_line_break(spans);
+                let style = SpanStyle {
+                    code: true,
+                    ..style
+                };
+                let kind = SpanKind::CodeBlock {
+                    language: language.clone(),
+                };
+                // Highlighted block is split in several consecutive spans
+                match context.highlighter {
+                    Some(highlighter) => {
+                        for token in highlighter.highlight(code, language) {
+                            let style = SpanStyle {
+                                color: token.color,
+                                bold: token.bold,
+                                italic: token.italic,
+                                ..style
+                            };
+                            spans.push(StyledSpan::new(&token.text, style, kind.clone()));
+                        }
+                    }
+                    None => spans.push(StyledSpan::new(code, style, kind)),
+                }
+            }
+            Block::Quote(blocks) => {
+                let style = SpanStyle {
+                    quote_level: style.quote_level + 1,
+                    ..style
+                };
+                render_blocks(blocks, context, style, spans);
+            }
+            Block::UnorderedList(items) => {
+                for item in items {
+                    push_line_break(spans);
+                    spans.push(StyledSpan::new("• ", style, SpanKind::ListMarker));
+                    render_inlines(item, context, style, spans);
+                }
+            }
+            Block::OrderedList { start, items } => {
+                for (index, item) in items.iter().enumerate() {
+                    push_line_break(spans);
+                    let marker = format!("{}. ", *start + index as u64);
+                    spans.push(StyledSpan::new(&marker, style, SpanKind::ListMarker));
+                    render_inlines(item, context, style, spans);
+                }
+            }
+            Block::TaskList(tasks) => {
+                for task in tasks {
+                    push_line_break(spans);
+                    let marker = if task.checked { "☑ " } else { "☐ " };
+                    spans.push(StyledSpan::new(marker, style, SpanKind::ListMarker));
+                    render_inlines(&task.content, context, style, spans);
+                }
+            }
+            Block::Katex(formula) => {
+                push_line_break(spans);
+                spans.push(StyledSpan::new(formula, style, SpanKind::Katex));
+            }
+        }
+    }
+}
+
+fn render_inlines(
+    inlines: &[Inline],
+    context: &MarkdownRenderContext,
+    style: SpanStyle,
+    spans: &mut Vec<StyledSpan>,
+) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => push_span(spans, StyledSpan::new(text, style, SpanKind::Text)),
+            Inline::Bold(children) => {
+                let style = SpanStyle {
+                    bold: true,
+                    ..style
+                };
+                render_inlines(children, context, style, spans);
+            }
+            Inline::Italic(children) => {
+                let style = SpanStyle {
+                    italic: true,
+                    ..style
+                };
+                render_inlines(children, context, style, spans);
+            }
+            Inline::Strike(children) => {
+                let style = SpanStyle {
+                    strike: true,
+                    ..style
+                };
+                render_inlines(children, context, style, spans);
+            }
+            Inline::InlineCode(code) => push_span(
+                spans,
+                StyledSpan::new(
+                    code,
+                    SpanStyle {
+                        code: true,
+                        ..style
+                    },
+                    SpanKind::Text,
+                ),
+            ),
+            Inline::Link { url, label } => {
+                let mut label_spans = Vec::new();
+                render_inlines(label, context, style, &mut label_spans);
+                for mut span in label_spans {
+                    if span.kind == SpanKind::Text {
+                        span.kind = SpanKind::Link(url.clone());
+                    }
+                    spans.push(span);
+                }
+            }
+            Inline::UserMention(name) => {
+                let text = format!("@{}", name);
+                let kind = match context.resolve_mention(name) {
+                    MentionTarget::User { user_name, own, .. } => {
+                        SpanKind::UserMention { user_name, own }
+                    }
+                    MentionTarget::Group(_) => SpanKind::GroupMention,
+                    MentionTarget::Unresolved => SpanKind::Text,
+                };
+                push_span(spans, StyledSpan::new(&text, style, kind));
+            }
+            Inline::ChannelMention(name) => {
+                let text = format!("#{}", name);
+                let kind = match context.resolve_channel(name) {
+                    Some(channel) => SpanKind::ChannelMention {
+                        room_name: channel.name,
+                    },
+                    None => SpanKind::Text,
+                };
+                push_span(spans, StyledSpan::new(&text, style, kind));
+            }
+            Inline::Emoji(shortcode) => {
+                let span = match context.resolve_emoji(shortcode) {
+                    EmojiTarget::Unicode(unicode) => StyledSpan::new(
+                        &unicode,
+                        style,
+                        SpanKind::Emoji {
+                            shortcode: format!(":{}:", shortcode),
+                            image_source: None,
+                        },
+                    ),
+                    EmojiTarget::Custom { shortcode, source } => StyledSpan::new(
+                        &shortcode,
+                        style,
+                        SpanKind::Emoji {
+                            shortcode: shortcode.clone(),
+                            image_source: source,
+                        },
+                    ),
+                    EmojiTarget::Unknown => {
+                        StyledSpan::new(&format!(":{}:", shortcode), style, SpanKind::Text)
+                    }
+                };
+                push_span(spans, span);
+            }
+            Inline::Katex(formula) => spans.push(StyledSpan::new(formula, style, SpanKind::Katex)),
+            Inline::LineBreak => spans.push(StyledSpan::new("\n", style, SpanKind::LineBreak)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+    use crate::markdown::markdownparser::parse;
+    use crate::markdown::rendercontext::MarkdownRenderContext;
+    use crate::markdown::styledspanrenderer::{
+        SpanColor, SpanKind, SpanStyle, StyledSpan, to_styled_spans,
+    };
+    use crate::markdown::syntaxhighlighter::SyntaxHighlighter;
+    use crate::message::mentions::Mentions;
+
+    fn spans(text: &str) -> Vec<StyledSpan> {
+        to_styled_spans(&parse(text), &MarkdownRenderContext::new())
+    }
+
+    #[test]
+    fn test_text() {
+        assert!(spans("").is_empty());
+        assert_eq!(
+            spans("hello world"),
+            vec![StyledSpan::new(
+                "hello world",
+                SpanStyle::default(),
+                SpanKind::Text
+            )]
+        );
+    }
+
+    #[test]
+    fn test_formatting() {
+        let bold = SpanStyle {
+            bold: true,
+            ..Default::default()
+        };
+        let bold_italic = SpanStyle {
+            italic: true,
+            ..bold
+        };
+        assert_eq!(
+            spans("a *b _c_* [d](https://kde.org)"),
+            vec![
+                StyledSpan::new("a ", SpanStyle::default(), SpanKind::Text),
+                StyledSpan::new("b ", bold, SpanKind::Text),
+                StyledSpan::new("c", bold_italic, SpanKind::Text),
+                StyledSpan::new(" ", SpanStyle::default(), SpanKind::Text),
+                StyledSpan::new(
+                    "d",
+                    SpanStyle::default(),
+                    SpanKind::Link("https://kde.org".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let quote = SpanStyle {
+            quote_level: 1,
+            ..Default::default()
+        };
+        let result = spans("# T\n> q\n- i\n```sh\nls\n```");
+        assert_eq!(
+            result,
+            vec![
+                StyledSpan::new(
+                    "T",
+                    SpanStyle {
+                        heading_level: 1,
+                        ..Default::default()
+                    },
+                    SpanKind::Text
+                ),
+                StyledSpan::new("\n", SpanStyle::default(), SpanKind::LineBreak),
+                StyledSpan::new("q", quote, SpanKind::Text),
+                StyledSpan::new("\n", SpanStyle::default(), SpanKind::LineBreak),
+                StyledSpan::new("• ", SpanStyle::default(), SpanKind::ListMarker),
+                StyledSpan::new("i", SpanStyle::default(), SpanKind::Text),
+                StyledSpan::new("\n", SpanStyle::default(), SpanKind::LineBreak),
+                StyledSpan::new(
+                    "ls",
+                    SpanStyle {
+                        code: true,
+                        ..Default::default()
+                    },
+                    SpanKind::CodeBlock {
+                        language: "sh".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mentions_and_emojis() {
+        let mentions: Mentions =
+            serde_json::from_str(r#"[{"_id": "id1", "username": "me"}]"#).unwrap();
+        let emojis = UnicodeEmojiManager::new();
+        let context = MarkdownRenderContext {
+            mentions: Some(&mentions),
+            own_user_name: "me",
+            unicode_emojis: Some(&emojis),
+            ..Default::default()
+        };
+        let result = to_styled_spans(&parse("@me @foo @here #general :+1:"), &context);
+        let kinds: Vec<&SpanKind> = result.iter().map(|span| &span.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &SpanKind::UserMention {
+                    user_name: "me".to_string(),
+                    own: true
+                },
+                &SpanKind::Text,
+                &SpanKind::GroupMention,
+                &SpanKind::Text,
+                &SpanKind::ChannelMention {
+                    room_name: "general".to_string()
+                },
+                &SpanKind::Text,
+                &SpanKind::Emoji {
+                    shortcode: ":+1:".to_string(),
+                    image_source: None
+                },
+            ]
+        );
+        assert_eq!(result[1].text, " @foo ");
+        assert_eq!(result[6].text, "👍");
+    }
+
+    #[test]
+    fn test_span_color() {
+        let color = SpanColor {
+            red: 255,
+            green: 10,
+            blue: 0,
+        };
+        assert_eq!(color.name(), "#ff0a00");
+    }
+
+    #[test]
+    fn test_highlighted_code() {
+        let highlighter = SyntaxHighlighter::new();
+        let context = MarkdownRenderContext {
+            highlighter: Some(&highlighter),
+            ..Default::default()
+        };
+        let code = "fn main() {\n    println!(\"a\");\n}";
+        let result = to_styled_spans(&parse(&format!("a\n```\n{}\n```", code)), &context);
+        assert_eq!(result[1].kind, SpanKind::LineBreak);
+        let code_spans = &result[2..];
+        assert!(code_spans.len() > 2);
+        assert!(code_spans.iter().all(|span| span.style.code
+            && span.kind
+                == SpanKind::CodeBlock {
+                    language: String::new()
+                }));
+        assert!(code_spans.iter().any(|span| span.style.color.is_some()));
+        let text: String = code_spans.iter().map(|span| span.text.as_str()).collect();
+        assert_eq!(text, code);
+    }
+}
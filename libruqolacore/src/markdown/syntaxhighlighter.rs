@@ -0,0 +1,392 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::markdown::styledspanrenderer::SpanColor;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color, FontStyle, Theme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+lazy_static! {
+    // Loading is slow, shared by all accounts
+    static ref SYNTAX_SET: SyntaxSet = SyntaxSet::load_defaults_newlines();
+    static ref THEME_SET: ThemeSet = ThemeSet::load_defaults();
+}
+
+pub const DEFAULT_THEME: &str = "InspiredGitHub";
+
+// Language tags used in messages which are not known by syntect.
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("shell", "sh"),
+    ("console", "sh"),
+    ("zsh", "sh"),
+    ("typescript", "js"),
+    ("ts", "js"),
+    ("jsx", "js"),
+    ("jsonc", "json"),
+    ("yml", "yaml"),
+    ("c++", "cpp"),
+    ("qml", "js"),
+    ("golang", "go"),
+];
+
+// Words which are typical of a language, used when code block doesn't specify it.
+const LANGUAGE_HINTS: &[(&str, &[&str])] = &[
+    (
+        "rs",
+        &[
+            "fn ",
+            "let mut ",
+            "impl ",
+            "pub fn ",
+            "println!",
+            "use std::",
+            "&mut ",
+            "-> Self",
+            "Some(",
+            "Ok(",
+        ],
+    ),
+    (
+        "py",
+        &[
+            "def ", "import ", "self.", "elif ", "print(", "None", "__init__", "from ",
+        ],
+    ),
+    (
+        "cpp",
+        &[
+            "#include",
+            "std::",
+            "nullptr",
+            "template<",
+            "const auto",
+            "public:",
+            "private:",
+            "void ",
+        ],
+    ),
+    (
+        "js",
+        &[
+            "function ",
+            "const ",
+            "=>",
+            "console.log",
+            "===",
+            "document.",
+            "require(",
+            "var ",
+        ],
+    ),
+    (
+        "java",
+        &[
+            "public class ",
+            "public static void",
+            "System.out",
+            "private final ",
+            "import java.",
+            "@Override",
+        ],
+    ),
+    (
+        "go",
+        &["func ", "package ", ":= ", "fmt.", "go func", "err != nil"],
+    ),
+    (
+        "sql",
+        &[
+            "SELECT ",
+            "FROM ",
+            "WHERE ",
+            "INSERT INTO",
+            "UPDATE ",
+            "CREATE TABLE",
+            "JOIN ",
+            "ORDER BY",
+        ],
+    ),
+    (
+        "sh",
+        &[
+            "echo ", "sudo ", "export ", "fi\n", "then\n", "grep ", "cd ", "apt ", "| ",
+        ],
+    ),
+    ("php", &["<?php", "$this->", "function ", "echo "]),
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HighlightedToken {
+    pub text: String,
+    // None for default foreground of theme
+    pub color: Option<SpanColor>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+impl HighlightedToken {
+    pub fn new(text: &str) -> Self {
+        HighlightedToken {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+// Highlights code blocks with a syntect theme.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxHighlighter {
+    pub theme_name: String,
+}
+
+impl Default for SyntaxHighlighter {
+    fn default() -> Self {
+        SyntaxHighlighter::new()
+    }
+}
+
+impl SyntaxHighlighter {
+    pub fn new() -> Self {
+        SyntaxHighlighter {
+            theme_name: DEFAULT_THEME.to_string(),
+        }
+    }
+
+    pub fn theme_names() -> Vec<String> {
+        THEME_SET.themes.keys().cloned().collect()
+    }
+
+    fn theme(&self) -> &'static Theme {
+        THEME_SET
+            .themes
+            .get(&self.theme_name)
+            .unwrap_or(&THEME_SET.themes[DEFAULT_THEME])
+    }
+
+    pub fn background_color(&self) -> Option<SpanColor> {
+        self.theme().settings.background.map(span_color)
+    }
+
+    fn find_syntax(language: &str) -> Option<&'static SyntaxReference> {
+        let language = language.trim().to_lowercase();
+        let language = LANGUAGE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == language)
+            .map(|(_, token)| token.to_string())
+            .unwrap_or(language);
+        SYNTAX_SET.find_syntax_by_token(&language)
+    }
+
+    // Name of language used for code ("Rust", "Python"…), detected when language is empty.
+    pub fn language_name(&self, code: &str, language: &str) -> Option<String> {
+        self.syntax(code, language)
+            .map(|syntax| syntax.name.clone())
+    }
+
+    fn syntax(&self, code: &str, language: &str) -> Option<&'static SyntaxReference> {
+        if language.trim().is_empty() {
+            detect_language(code).and_then(|token| SYNTAX_SET.find_syntax_by_token(&token))
+        } else {
+            SyntaxHighlighter::find_syntax(language)
+        }
+    }
+
+    // A single token without color when language is unknown.
+    pub fn highlight(&self, code: &str, language: &str) -> Vec<HighlightedToken> {
+        let Some(syntax) = self.syntax(code, language) else {
+            return vec![HighlightedToken::new(code)];
+        };
+        let theme = self.theme();
+        let default_color = theme.settings.foreground.map(span_color);
+        let mut highlighter = HighlightLines::new(syntax, theme);
+        let mut tokens: Vec<HighlightedToken> = Vec::new();
+        for line in LinesWithEndings::from(code) {
+            let Ok(ranges) = highlighter.highlight_line(line, &SYNTAX_SET) else {
+                return vec![HighlightedToken::new(code)];
+            };
+            for (style, text) in ranges {
+                let color =
+                    Some(span_color(style.foreground)).filter(|c| Some(*c) != default_color);
+                let bold = style.font_style.contains(FontStyle::BOLD);
+                let italic = style.font_style.contains(FontStyle::ITALIC);
+                match tokens.last_mut() {
+                    Some(last)
+                        if last.color == color && last.bold == bold && last.italic == italic =>
+                    {
+                        last.text.push_str(text)
+                    }
+                    _ => tokens.push(HighlightedToken {
+                        text: text.to_string(),
+                        color,
+                        bold,
+                        italic,
+                    }),
+                }
+            }
+        }
+        tokens
+    }
+}
+
+fn span_color(color: Color) -> SpanColor {
+    SpanColor {
+        red: color.r,
+        green: color.g,
+        blue: color.b,
+    }
+}
+
+// Syntect token of language ("rs", "py"…) guessed from code, None when unsure.
+pub fn detect_language(code: &str) -> Option<String> {
+    let code = code.trim();
+    if code.is_empty() {
+        return None;
+    }
+    // Shebang, "<?xml", modelines…
+    let first_line = code.lines().next().unwrap_or_default();
+    if let Some(syntax) = SYNTAX_SET.find_syntax_by_first_line(first_line) {
+        return syntax.file_extensions.first().cloned();
+    }
+    if (code.starts_with('{') || code.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(code).is_ok()
+    {
+        return Some("json".to_string());
+    }
+    if code.starts_with("diff --git") || code.starts_with("--- ") || code.starts_with("@@ ") {
+        return Some("diff".to_string());
+    }
+    if code.starts_with('<') && code.ends_with('>') {
+        let lower = code.to_lowercase();
+        let html = ["<html", "<div", "<body", "<span", "<!doctype html"];
+        return Some(if html.iter().any(|tag| lower.contains(tag)) {
+            "html".to_string()
+        } else {
+            "xml".to_string()
+        });
+    }
+    let (token, score) = LANGUAGE_HINTS
+        .iter()
+        .map(|(token, hints)| {
+            let score = hints.iter().filter(|hint| code.contains(*hint)).count();
+            (token, score)
+        })
+        .fold(("", 0), |best, (token, score)| {
+            if score > best.1 {
+                (*token, score)
+            } else {
+                best
+            }
+        });
+    // A single hint is not enough ("const " or "| " are common in text)
+    (score >= 2).then(|| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::markdown::styledspanrenderer::SpanColor;
+    use crate::markdown::syntaxhighlighter::{DEFAULT_THEME, SyntaxHighlighter, detect_language};
+
+    #[test]
+    fn test_default_values() {
+        let b = SyntaxHighlighter::new();
+        assert_eq!(b.theme_name, DEFAULT_THEME);
+        assert!(SyntaxHighlighter::theme_names().contains(&"base16-ocean.dark".to_string()));
+        assert_eq!(
+            b.background_color(),
+            Some(SpanColor {
+                red: 255,
+                green: 255,
+                blue: 255
+            })
+        );
+    }
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(detect_language(""), None);
+        assert_eq!(detect_language("hello world"), None);
+        assert_eq!(detect_language("#!/bin/bash\nls"), Some("sh".to_string()));
+        assert_eq!(detect_language("{\"a\": [1, 2]}"), Some("json".to_string()));
+        assert_eq!(
+            detect_language("<?xml version=\"1.0\"?>\n<a/>"),
+            Some("xml".to_string())
+        );
+        assert_eq!(
+            detect_language("<div><b>a</b></div>"),
+            Some("html".to_string())
+        );
+        assert_eq!(
+            detect_language("fn main() {\n    let mut a = Some(1);\n}"),
+            Some("rs".to_string())
+        );
+        assert_eq!(
+            detect_language("import os\ndef foo(self):\n    return None"),
+            Some("py".to_string())
+        );
+        assert_eq!(
+            detect_language("#include <vector>\nstd::vector<int> a;"),
+            Some("cpp".to_string())
+        );
+        assert_eq!(
+            detect_language("SELECT name FROM users WHERE id = 1"),
+            Some("sql".to_string())
+        );
+        assert_eq!(
+            detect_language("diff --git a/foo b/foo\n+bar"),
+            Some("diff".to_string())
+        );
+    }
+
+    #[test]
+    fn test_language_name() {
+        let b = SyntaxHighlighter::new();
+        assert_eq!(b.language_name("", "rust").unwrap(), "Rust");
+        assert_eq!(b.language_name("", "Python").unwrap(), "Python");
+        assert_eq!(
+            b.language_name("", "shell").unwrap(),
+            "Bourne Again Shell (bash)"
+        );
+        assert_eq!(b.language_name("", "c++").unwrap(), "C++");
+        assert!(b.language_name("", "unknownlanguage").is_none());
+        assert_eq!(
+            b.language_name("fn a() -> Self { Ok(1) }", "").unwrap(),
+            "Rust"
+        );
+    }
+
+    #[test]
+    fn test_highlight() {
+        let b = SyntaxHighlighter::new();
+        let code = "fn main() {\n    let a = \"text\";\n}";
+        let tokens = b.highlight(code, "rust");
+        assert!(tokens.len() > 2);
+        // Text is preserved
+        let text: String = tokens.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(text, code);
+        let keyword = tokens.iter().find(|token| token.text == "fn").unwrap();
+        assert!(keyword.color.is_some());
+        let string = tokens
+            .iter()
+            .find(|token| token.text.contains("text"))
+            .unwrap();
+        assert!(string.color.is_some());
+        assert_ne!(keyword.color, string.color);
+
+        let tokens = b.highlight("just text", "unknownlanguage");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "just text");
+        assert!(tokens[0].color.is_none());
+
+        // Other theme
+        let mut dark = SyntaxHighlighter::new();
+        dark.theme_name = "base16-ocean.dark".to_string();
+        assert_ne!(dark.background_color(), b.background_color());
+        assert_ne!(dark.highlight(code, "rust"), b.highlight(code, "rust"));
+        dark.theme_name = "unknown".to_string();
+        assert_eq!(dark.background_color(), b.background_color());
+    }
+}
@@ -6,9 +6,10 @@
 use serde::Deserialize;
 use std::fmt;
 
-use super::blockaction::BlockAction;
+use super::blockaction::{BlockAction, ButtonAction, deserialize_text};
 
 #[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
 pub struct BlockActions {
     pub blockactions: Vec<BlockAction>,
 }
@@ -23,8 +24,6 @@ impl BlockActions {
 
 #[derive(Default, Clone, Deserialize, Debug, PartialEq)]
 pub enum BlockType {
-    #[default]
-    Unknown,
     #[serde(alias = "video_conf")]
     VideoConf,
     #[serde(alias = "actions")]
@@ -43,9 +42,22 @@ pub enum BlockType {
     Preview,
     #[serde(alias = "callout")]
     Callout,
+    // Only in modals and contextual bars
+    #[serde(alias = "input")]
+    Input,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+pub struct BlockThumb {
+    #[serde(default)]
+    pub url: String,
 }
 
-#[derive(Clone, Default, Deserialize, Debug)]
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(default)]
 pub struct Block {
     #[serde(rename = "blockId")]
     pub block_id: String,
@@ -53,11 +65,34 @@ pub struct Block {
     pub call_id: String,
     #[serde(rename = "appId")]
     pub app_id: String,
+    #[serde(rename = "text", deserialize_with = "deserialize_text")]
     pub section_text: String,
     #[serde(rename = "type")]
     pub block_type: BlockType,
     #[serde(rename = "elements")]
     pub block_actions: BlockActions,
+    // Section
+    pub fields: Vec<ButtonAction>,
+    pub accessory: Option<BlockAction>,
+    // Image, callout and preview
+    #[serde(deserialize_with = "deserialize_text")]
+    pub title: String,
+    #[serde(rename = "imageUrl")]
+    pub image_url: String,
+    #[serde(rename = "altText")]
+    pub alt_text: String,
+    #[serde(deserialize_with = "deserialize_text")]
+    pub description: String,
+    #[serde(rename = "externalUrl")]
+    pub external_url: String,
+    pub thumb: Option<BlockThumb>,
+    // "info", "success", "warning" or "danger"
+    pub variant: String,
+    // Input
+    #[serde(deserialize_with = "deserialize_text")]
+    pub label: String,
+    pub element: Option<BlockAction>,
+    pub optional: bool,
 }
 
 impl Block {
@@ -69,19 +104,36 @@ impl Block {
             section_text: String::new(),
             block_type: BlockType::Unknown,
             block_actions: BlockActions::new(),
+            fields: Vec::new(),
+            accessory: None,
+            title: String::new(),
+            image_url: String::new(),
+            alt_text: String::new(),
+            description: String::new(),
+            external_url: String::new(),
+            thumb: None,
+            variant: String::new(),
+            label: String::new(),
+            element: None,
+            optional: false,
         }
     }
 }
 
 /*
-Debug output for MessageAttachmentField
+Debug output for Block
 */
 impl fmt::Display for Block {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "Block(blockId: {},callId: {},appId {},sectionText {}, blockType {:?}, block_action {:?} )",
-            self.block_id, self.call_id, self.app_id, self.section_text, self.block_type, self.block_actions
+            self.block_id,
+            self.call_id,
+            self.app_id,
+            self.section_text,
+            self.block_type,
+            self.block_actions
         )
     }
 }
@@ -89,6 +141,12 @@ impl fmt::Display for Block {
 #[cfg(test)]
 mod tests {
     use crate::message::block::{Block, BlockType};
+    use std::fs::File;
+
+    pub fn parse(filename: &str) -> Vec<Block> {
+        let file = File::open(filename).expect("Failed to open file");
+        serde_json::from_reader(file).expect("JSON was not well-formatted")
+    }
 
     #[test]
     fn test_block_default() {
@@ -99,5 +157,70 @@ mod tests {
         assert!(block.section_text.is_empty());
         assert_eq!(block.block_type, BlockType::Unknown);
         assert!(block.block_actions.blockactions.is_empty());
+        assert!(block.fields.is_empty());
+        assert!(block.accessory.is_none());
+        assert!(block.element.is_none());
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let blocks = parse("src/data/uikit/blocks.json");
+        let types: Vec<BlockType> = blocks
+            .iter()
+            .map(|block| block.block_type.clone())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                BlockType::Section,
+                BlockType::Actions,
+                BlockType::Context,
+                BlockType::Divider,
+                BlockType::Image,
+                BlockType::Preview,
+                BlockType::Callout,
+                BlockType::VideoConf,
+                BlockType::Unknown
+            ]
+        );
+
+        let section = &blocks[0];
+        assert_eq!(section.block_id, "summary");
+        assert_eq!(section.app_id, "deploy-bot");
+        assert_eq!(section.section_text, "*Deploy* of `ruqola` requested");
+        assert_eq!(section.fields.len(), 2);
+        assert!(section.fields[0].markdown);
+        let accessory = section.accessory.as_ref().unwrap();
+        assert_eq!(accessory.type_block, "overflow");
+        assert_eq!(accessory.options.len(), 2);
+        assert_eq!(accessory.options[1].text, "Cancel deploy");
+
+        let actions = &blocks[1].block_actions.blockactions;
+        assert_eq!(actions.len(), 3);
+        assert_eq!(actions[0].button.text, "Approve");
+        assert_eq!(actions[0].style, "primary");
+        assert_eq!(actions[2].type_block, "static_select");
+        assert_eq!(actions[2].placeholder.text, "Environment");
+
+        let context = &blocks[2].block_actions.blockactions;
+        assert_eq!(context[0].type_block, "image");
+        assert_eq!(context[0].image_url, "https://kde.org/konqi.png");
+        assert_eq!(context[1].button.text, "Requested by @foo");
+
+        assert_eq!(blocks[4].image_url, "https://kde.org/screenshot.png");
+        assert_eq!(blocks[4].title, "Screenshot");
+
+        let preview = &blocks[5];
+        assert_eq!(preview.title, "Release notes");
+        assert_eq!(preview.description, "What's new in 2.6");
+        assert_eq!(preview.external_url, "https://kde.org/announcements/");
+        assert_eq!(
+            preview.thumb.as_ref().unwrap().url,
+            "https://kde.org/thumb.png"
+        );
+
+        assert_eq!(blocks[6].variant, "warning");
+        assert_eq!(blocks[6].title, "Heads up");
+        assert_eq!(blocks[7].call_id, "call1");
     }
 }
@@ -3,13 +3,16 @@
 *
 * SPDX-License-Identifier: LGPL-2.0-or-later
 */
+use crate::message::blockaction::deserialize_text;
 use serde::Deserialize;
 use std::fmt;
 
-#[derive(Clone, Default, Deserialize, Debug)]
-struct BlockAccessoryOption {
-    text: String,
-    value: String,
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+pub struct BlockAccessoryOption {
+    #[serde(default, deserialize_with = "deserialize_text")]
+    pub text: String,
+    #[serde(default)]
+    pub value: String,
 }
 
 impl BlockAccessoryOption {
@@ -22,7 +25,7 @@ impl BlockAccessoryOption {
 }
 
 /*
-Debug output for MessageAttachmentField
+Debug output for BlockAccessoryOption
 */
 impl fmt::Display for BlockAccessoryOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -44,4 +47,14 @@ mod tests {
         assert!(block_accessory_option.value.is_empty());
         assert!(block_accessory_option.text.is_empty());
     }
+
+    #[test]
+    fn test_parse() {
+        let option: BlockAccessoryOption = serde_json::from_str(
+            r#"{"text": {"type": "plain_text", "text": "Delete"}, "value": "delete"}"#,
+        )
+        .unwrap();
+        assert_eq!(option.text, "Delete");
+        assert_eq!(option.value, "delete");
+    }
 }
@@ -3,24 +3,78 @@
 *
 * SPDX-License-Identifier: LGPL-2.0-or-later
 */
-use serde::Deserialize;
+use crate::message::blockaccessoryoption::BlockAccessoryOption;
+use serde::{Deserialize, Deserializer};
 use std::fmt;
 
+// Text objects are {"type": "plain_text"|"mrkdwn", "text": …}, some apps send a string.
+// Preview blocks use a list of text objects.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TextValue {
+    Text(String),
+    Object {
+        #[serde(default)]
+        text: String,
+        #[serde(default, rename = "type")]
+        text_type: String,
+    },
+    List(Vec<TextValue>),
+}
+
+impl From<TextValue> for ButtonAction {
+    fn from(value: TextValue) -> Self {
+        match value {
+            TextValue::Text(text) => ButtonAction {
+                text,
+                markdown: false,
+            },
+            TextValue::Object { text, text_type } => ButtonAction {
+                text,
+                markdown: text_type == "mrkdwn",
+            },
+            TextValue::List(values) => {
+                let texts: Vec<ButtonAction> = values.into_iter().map(ButtonAction::from).collect();
+                ButtonAction {
+                    text: texts
+                        .iter()
+                        .map(|text| text.text.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(" "),
+                    markdown: texts.iter().any(|text| text.markdown),
+                }
+            }
+        }
+    }
+}
+
+// Text of a text object, used by fields which only keep the string.
+pub(crate) fn deserialize_text<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(ButtonAction::from(TextValue::deserialize(deserializer)?).text)
+}
+
 #[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(from = "TextValue")]
 pub struct ButtonAction {
     pub text: String,
+    // "mrkdwn" text object
+    pub markdown: bool,
 }
 
 impl ButtonAction {
     pub fn new() -> Self {
         ButtonAction {
             text: String::new(),
+            markdown: false,
         }
     }
 }
 
 /*
-Debug output for MessageAttachmentField
+Debug output for ButtonAction
 */
 impl fmt::Display for ButtonAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -41,6 +95,16 @@ pub struct BlockAction {
     pub block_id: String,
     pub value: String,
     pub url: String,
+    // "primary" or "danger" for buttons
+    pub style: String,
+    // Overflow menu and select entries
+    pub options: Vec<BlockAccessoryOption>,
+    pub placeholder: ButtonAction,
+    pub multiline: bool,
+    #[serde(rename = "imageUrl")]
+    pub image_url: String,
+    #[serde(rename = "altText")]
+    pub alt_text: String,
 }
 
 impl BlockAction {
@@ -52,12 +116,18 @@ impl BlockAction {
             block_id: String::new(),
             value: String::new(),
             url: String::new(),
+            style: String::new(),
+            options: Vec::new(),
+            placeholder: ButtonAction::new(),
+            multiline: false,
+            image_url: String::new(),
+            alt_text: String::new(),
         }
     }
 }
 
 /*
-Debug output for MessageAttachmentField
+Debug output for BlockAction
 */
 impl fmt::Display for BlockAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -71,7 +141,7 @@ impl fmt::Display for BlockAction {
 
 #[cfg(test)]
 mod tests {
-    use crate::message::blockaction::BlockAction;
+    use crate::message::blockaction::{BlockAction, ButtonAction};
 
     #[test]
     fn test_blockaction_default() {
@@ -82,5 +152,40 @@ mod tests {
         assert!(block_action.value.is_empty());
         assert!(block_action.url.is_empty());
         assert!(block_action.button.text.is_empty());
+        assert!(block_action.style.is_empty());
+        assert!(block_action.options.is_empty());
+        assert!(!block_action.multiline);
+    }
+
+    #[test]
+    fn test_text_object() {
+        let text: ButtonAction = serde_json::from_str(r#""hello""#).unwrap();
+        assert_eq!(text.text, "hello");
+        assert!(!text.markdown);
+        let text: ButtonAction =
+            serde_json::from_str(r#"{"type": "mrkdwn", "text": "*hello*"}"#).unwrap();
+        assert_eq!(text.text, "*hello*");
+        assert!(text.markdown);
+        let text: ButtonAction = serde_json::from_str(
+            r#"[{"type": "plain_text", "text": "a"}, {"type": "plain_text", "text": "b"}]"#,
+        )
+        .unwrap();
+        assert_eq!(text.text, "a b");
+    }
+
+    #[test]
+    fn test_parse_button() {
+        let block_action: BlockAction = serde_json::from_str(
+            r#"{"type": "button", "actionId": "approve", "blockId": "b1", "appId": "app1",
+                "text": {"type": "plain_text", "text": "Approve", "emoji": true},
+                "value": "42", "style": "primary"}"#,
+        )
+        .unwrap();
+        assert_eq!(block_action.type_block, "button");
+        assert_eq!(block_action.action_id, "approve");
+        assert_eq!(block_action.block_id, "b1");
+        assert_eq!(block_action.button.text, "Approve");
+        assert_eq!(block_action.value, "42");
+        assert_eq!(block_action.style, "primary");
     }
 }
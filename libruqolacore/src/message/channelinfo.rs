@@ -6,7 +6,7 @@
 use serde::Deserialize;
 use std::fmt;
 
-#[derive(Clone, Default, Deserialize, Debug)]
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
 #[serde(default)]
 pub struct ChannelInfo {
     pub fname: String,
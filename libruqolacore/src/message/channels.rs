@@ -3,5 +3,65 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
+use crate::message::channelinfo::ChannelInfo;
+use serde::Deserialize;
+use std::fmt;
 
-// TODO
+// "channels" array of a message: rooms referenced by "#name"
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+pub struct Channels {
+    pub channels: Vec<ChannelInfo>,
+}
+
+impl Channels {
+    pub fn new() -> Self {
+        Channels {
+            channels: Vec::default(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+
+    // Name or fname
+    pub fn find_channel(&self, name: &str) -> Option<&ChannelInfo> {
+        self.channels
+            .iter()
+            .find(|channel| channel.name == name || channel.fname == name)
+    }
+}
+
+/*
+Debug output for Channels
+*/
+impl fmt::Display for Channels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Channels(channels: {:?})", self.channels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::channels::Channels;
+
+    #[test]
+    fn test_is_empty() {
+        let b = Channels::new();
+        assert!(b.channels.is_empty());
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let b: Channels = serde_json::from_str(
+            r#"[{"_id": "GENERAL", "name": "general"}, {"_id": "hHhfS6bJcHt2E3kKf", "name": "dev-team", "fname": "Dev Team"}]"#,
+        )
+        .unwrap();
+        assert_eq!(b.channels.len(), 2);
+        assert_eq!(b.find_channel("general").unwrap().identifier, "GENERAL");
+        assert_eq!(b.find_channel("Dev Team").unwrap().name, "dev-team");
+        assert!(b.find_channel("random").is_none());
+    }
+}
@@ -6,18 +6,49 @@
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt;
-// TODO verify as "mentions.insert(mention.value("username"_L1).toString(), mention.value("_id"_L1).toString().toLatin1());"
+
+// Element of "mentions" array of a message
 #[derive(Clone, Default, Deserialize, Debug)]
+#[serde(default)]
+struct MentionInfo {
+    #[serde(rename = "_id")]
+    identifier: String,
+    username: String,
+}
+
+// username => user identifier ("all" and "here" use their name as identifier)
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(from = "Vec<MentionInfo>")]
 pub struct Mentions {
     pub mentions: HashMap<String, String>,
 }
 
+impl From<Vec<MentionInfo>> for Mentions {
+    fn from(infos: Vec<MentionInfo>) -> Self {
+        Mentions {
+            mentions: infos
+                .into_iter()
+                .filter(|info| !info.username.is_empty())
+                .map(|info| (info.username, info.identifier))
+                .collect(),
+        }
+    }
+}
+
 impl Mentions {
     pub fn new() -> Self {
         Mentions {
             mentions: HashMap::default(),
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.mentions.is_empty()
+    }
+
+    pub fn user_identifier(&self, user_name: &str) -> Option<&str> {
+        self.mentions.get(user_name).map(String::as_str)
+    }
 }
 
 /*
@@ -37,5 +68,19 @@ mod tests {
     fn test_is_empty() {
         let b = Mentions::new();
         assert!(b.mentions.is_empty());
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        let b: Mentions = serde_json::from_str(
+            r#"[{"_id": "uD6ad7aeb2W3Wk2D8", "username": "laurent", "name": "Laurent Montel", "type": "user"},
+                {"_id": "here", "username": "here"}, {"_id": "broken"}]"#,
+        )
+        .unwrap();
+        assert_eq!(b.mentions.len(), 2);
+        assert_eq!(b.user_identifier("laurent"), Some("uD6ad7aeb2W3Wk2D8"));
+        assert_eq!(b.user_identifier("here"), Some("here"));
+        assert_eq!(b.user_identifier("foo"), None);
     }
 }
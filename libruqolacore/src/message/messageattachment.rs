@@ -3,48 +3,226 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
+use crate::message::messageattachmentfield::MessageAttachmentField;
 use serde::Deserialize;
-enum AttachmentType {
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AttachmentType {
+    #[default]
     Unknown,
     NormalText,
     File,
     Video,
     Audio,
     Image,
+    // Message quoted by a link in text
+    Quote,
+}
+
+#[derive(Clone, Copy, Deserialize, Debug, Default, PartialEq)]
+pub struct ImageDimensions {
+    #[serde(default)]
+    pub width: i64,
+    #[serde(default)]
+    pub height: i64,
 }
 
 #[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
 pub struct MessageAttachment {
     pub title: String,
     pub description: String,
+    pub text: String,
     pub color: String,
+    #[serde(rename = "title_link")]
     pub link: String,
+    #[serde(rename = "title_link_download")]
+    pub link_download: bool,
     pub author_name: String,
     pub author_icon: String,
+    pub author_link: String,
+    pub image_url: String,
     pub image_type: String,
+    pub image_size: i64,
+    pub image_dimensions: Option<ImageDimensions>,
+    // Small base64 image displayed while real one is loading
+    pub image_preview: String,
+    pub audio_url: String,
+    pub audio_type: String,
+    pub audio_size: i64,
+    pub video_url: String,
+    pub video_type: String,
+    pub video_size: i64,
+    pub thumb_url: String,
+    pub message_link: String,
+    pub collapsed: bool,
+    pub fields: Vec<MessageAttachmentField>,
+    // Attachments of quoted message
+    pub attachments: Vec<MessageAttachment>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub format: String,
+    pub size: i64,
 }
 
-/*
-class Messageattachment {
-  List<MessageAttachmentField>? mAttachmentFields;
-  AttachmentType mAttachmentType = AttachmentType.unknown;
-  String? mColor;
-  String? mDescription;
-  String? mTitle;
-  String? mLink;
-  String? mAuthorName;
-  String? mMimeType;
-  String? mText;
-  String? mAttachmentId;
-  String? mAuthorIcon;
-  String? mAttachmentFieldsText;
-  String? mImageUrlPreview;
-  String? mAttachmentGeneratedTitle;
-  int mAttachmentSize = -1;
-  int mImageHeight = -1;
-  int mImageWidth = -1;
-  bool mIsAnimatedImage = false;
-  bool mCollapsed = false;
-  bool mShowAttachment = false;
+impl Default for MessageAttachment {
+    fn default() -> Self {
+        MessageAttachment::new()
+    }
+}
+
+impl MessageAttachment {
+    pub fn new() -> Self {
+        MessageAttachment {
+            title: String::default(),
+            description: String::default(),
+            text: String::default(),
+            color: String::default(),
+            link: String::default(),
+            link_download: false,
+            author_name: String::default(),
+            author_icon: String::default(),
+            author_link: String::default(),
+            image_url: String::default(),
+            image_type: String::default(),
+            image_size: -1,
+            image_dimensions: None,
+            image_preview: String::default(),
+            audio_url: String::default(),
+            audio_type: String::default(),
+            audio_size: -1,
+            video_url: String::default(),
+            video_type: String::default(),
+            video_size: -1,
+            thumb_url: String::default(),
+            message_link: String::default(),
+            collapsed: false,
+            fields: Vec::new(),
+            attachments: Vec::new(),
+            kind: String::default(),
+            format: String::default(),
+            size: -1,
+        }
+    }
+
+    pub fn attachment_type(&self) -> AttachmentType {
+        if !self.image_url.is_empty() {
+            AttachmentType::Image
+        } else if !self.video_url.is_empty() {
+            AttachmentType::Video
+        } else if !self.audio_url.is_empty() {
+            AttachmentType::Audio
+        } else if !self.message_link.is_empty() {
+            AttachmentType::Quote
+        } else if self.kind == "file" || (self.link_download && !self.link.is_empty()) {
+            AttachmentType::File
+        } else if !self.text.is_empty() || !self.fields.is_empty() || !self.title.is_empty() {
+            AttachmentType::NormalText
+        } else {
+            AttachmentType::Unknown
+        }
+    }
+
+    pub fn is_animated_image(&self) -> bool {
+        self.image_type == "image/gif"
+    }
 }
+
+/*
+Debug output for MessageAttachment
 */
+impl fmt::Display for MessageAttachment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Attachment(title: {}, link: {}, type: {:?})",
+            self.title,
+            self.link,
+            self.attachment_type()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::messageattachment::{AttachmentType, ImageDimensions, MessageAttachment};
+    use std::fs::File;
+
+    pub fn parse(filename: &str) -> Vec<MessageAttachment> {
+        let file = File::open(filename).expect("Failed to open file");
+        serde_json::from_reader(file).expect("JSON was not well-formatted")
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = MessageAttachment::new();
+        assert!(b.title.is_empty());
+        assert!(b.link.is_empty());
+        assert!(b.fields.is_empty());
+        assert!(b.attachments.is_empty());
+        assert!(b.image_dimensions.is_none());
+        assert_eq!(b.image_size, -1);
+        assert_eq!(b.size, -1);
+        assert!(!b.collapsed);
+        assert_eq!(b.attachment_type(), AttachmentType::Unknown);
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let attachments = parse("src/data/messageattachment/messageattachments.json");
+        assert_eq!(attachments.len(), 6);
+        let types: Vec<AttachmentType> = attachments
+            .iter()
+            .map(|attachment| attachment.attachment_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                AttachmentType::Image,
+                AttachmentType::Audio,
+                AttachmentType::Video,
+                AttachmentType::File,
+                AttachmentType::Quote,
+                AttachmentType::NormalText
+            ]
+        );
+
+        let image = &attachments[0];
+        assert_eq!(image.title, "konqi.png");
+        assert_eq!(image.description, "Our mascot");
+        assert_eq!(image.link, "/file-upload/6CvLxgRcJHgvPfhrk/konqi.png");
+        assert!(image.link_download);
+        assert_eq!(image.image_type, "image/png");
+        assert_eq!(image.image_size, 52431);
+        assert_eq!(
+            image.image_dimensions,
+            Some(ImageDimensions {
+                width: 800,
+                height: 600
+            })
+        );
+        assert!(!image.image_preview.is_empty());
+        assert!(!image.is_animated_image());
+
+        let file = &attachments[3];
+        assert_eq!(file.format, "PDF");
+        assert_eq!(file.size, 120034);
+
+        let quote = &attachments[4];
+        assert_eq!(quote.author_name, "foo");
+        assert_eq!(quote.text, "Release is *tomorrow*");
+        assert_eq!(quote.attachments.len(), 1);
+        assert_eq!(
+            quote.attachments[0].attachment_type(),
+            AttachmentType::Image
+        );
+
+        let fields = &attachments[5];
+        assert_eq!(fields.color, "#ff0000");
+        assert!(fields.collapsed);
+        assert_eq!(fields.fields.len(), 3);
+        assert!(fields.fields[0].short);
+        assert!(!fields.fields[2].short);
+    }
+}
@@ -13,6 +13,9 @@ use serde::Deserialize;
 pub struct MessageAttachmentField {
     pub value: String,
     pub title: String,
+    // Displayed next to other short fields
+    #[serde(default)]
+    pub short: bool,
 }
 
 impl Default for MessageAttachmentField {
@@ -26,6 +29,7 @@ impl MessageAttachmentField {
         MessageAttachmentField {
             title: String::default(),
             value: String::default(),
+            short: false,
         }
     }
     pub fn parse(filename: &str) -> MessageAttachmentField {
@@ -55,5 +59,17 @@ mod tests {
         let b = MessageAttachmentField::new();
         assert!(b.value.is_empty());
         assert!(b.title.is_empty());
+        assert!(!b.short);
+    }
+
+    #[test]
+    fn test_parse() {
+        let b = MessageAttachmentField::parse(
+            r#"{"title": "Branch", "value": "master", "short": true}"#,
+        );
+        assert_eq!(b.title, "Branch");
+        assert_eq!(b.value, "master");
+        assert!(b.short);
+        assert!(!MessageAttachmentField::parse(r#"{"title": "a", "value": "b"}"#).short);
     }
 }
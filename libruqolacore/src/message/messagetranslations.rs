@@ -7,9 +7,11 @@
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt;
+// Auto translations of a message, flattened in message json.
 #[derive(Default, Clone, Deserialize, Debug, PartialEq)]
 pub struct MessageTranslations {
-    #[serde(rename = "translations")]
+    // Language => translated text
+    #[serde(default, rename = "translations")]
     pub translated_string: HashMap<String, String>,
 }
 
@@ -19,6 +21,30 @@ impl MessageTranslations {
             translated_string: HashMap::new(),
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.translated_string.is_empty()
+    }
+
+    // "pt-BR" falls back to "pt" translation.
+    pub fn translated_text(&self, language: &str) -> Option<&str> {
+        if language.is_empty() {
+            return None;
+        }
+        self.translated_string
+            .get(language)
+            .or_else(|| {
+                let (primary, _) = language.split_once(['-', '_'])?;
+                self.translated_string.get(primary)
+            })
+            .map(|text| text.as_str())
+    }
+
+    // Translations returned by autotranslate.translateMessage
+    pub fn merge(&mut self, translations: MessageTranslations) {
+        self.translated_string
+            .extend(translations.translated_string);
+    }
 }
 
 /*
@@ -42,5 +68,29 @@ mod tests {
     fn test_is_empty() {
         let b = MessageTranslations::new();
         assert!(b.translated_string.is_empty());
+        assert!(b.is_empty());
+        assert!(b.translated_text("fr").is_none());
+    }
+
+    #[test]
+    fn test_translated_text() {
+        let mut b: MessageTranslations = serde_json::from_str(
+            r#"{"msg": "hello", "translations": {"fr": "bonjour", "pt": "olá"}}"#,
+        )
+        .unwrap();
+        assert_eq!(b.translated_text("fr"), Some("bonjour"));
+        assert_eq!(b.translated_text("pt-BR"), Some("olá"));
+        assert_eq!(b.translated_text("pt_PT"), Some("olá"));
+        assert!(b.translated_text("de").is_none());
+        assert!(b.translated_text("").is_none());
+
+        b.merge(
+            serde_json::from_str(r#"{"translations": {"de": "hallo", "fr": "salut"}}"#).unwrap(),
+        );
+        assert_eq!(b.translated_text("de"), Some("hallo"));
+        assert_eq!(b.translated_text("fr"), Some("salut"));
+
+        let b: MessageTranslations = serde_json::from_str(r#"{"msg": "hello"}"#).unwrap();
+        assert!(b.is_empty());
     }
 }
@@ -4,6 +4,8 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
 #[derive(Default, Clone, Deserialize, Debug, PartialEq)]
 pub enum ContentType {
     #[default]
@@ -17,7 +19,39 @@ pub enum ContentType {
     #[serde(alias = "video")]
     Video,
 }
+
+impl ContentType {
+    // From "contentType" header: "image/png", "video/mp4; codecs=…"
+    pub fn from_mime_type(mime_type: &str) -> Self {
+        let mime_type = mime_type.trim().to_lowercase();
+        if mime_type.starts_with("image/gif") {
+            ContentType::ImageAnimated
+        } else if mime_type.starts_with("image/") {
+            ContentType::Image
+        } else if mime_type.starts_with("audio/") {
+            ContentType::Audio
+        } else if mime_type.starts_with("video/") {
+            ContentType::Video
+        } else {
+            ContentType::None
+        }
+    }
+}
+
+// Element of "urls" in message: {"url": …, "meta": {…}, "headers": {…}}
+#[derive(Deserialize)]
+struct MessageUrlInfo {
+    url: String,
+    #[serde(default)]
+    meta: HashMap<String, Value>,
+    #[serde(default)]
+    headers: HashMap<String, Value>,
+    #[serde(default, rename = "ignoreParse")]
+    ignore_parse: bool,
+}
+
 #[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(from = "MessageUrlInfo")]
 pub struct MessageUrl {
     pub url: String,
     pub page_title: String,
@@ -42,8 +76,67 @@ impl Default for MessageUrl {
     }
 }
 
+// First non empty value, meta values are strings (even sizes) but oembed ones can be numbers.
+fn meta_value(meta: &HashMap<String, Value>, keys: &[&str]) -> String {
+    keys.iter()
+        .filter_map(|key| match meta.get(*key) {
+            Some(Value::String(value)) => Some(value.trim().to_string()),
+            Some(Value::Number(value)) => Some(value.to_string()),
+            _ => None,
+        })
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+}
+
+impl From<MessageUrlInfo> for MessageUrl {
+    fn from(info: MessageUrlInfo) -> Self {
+        let meta = &info.meta;
+        let mut message_url = MessageUrl {
+            page_title: meta_value(
+                meta,
+                &["ogTitle", "twitterTitle", "oembedTitle", "pageTitle"],
+            ),
+            description: meta_value(
+                meta,
+                &[
+                    "ogDescription",
+                    "twitterDescription",
+                    "oembedDescription",
+                    "description",
+                ],
+            ),
+            image_url: meta_value(
+                meta,
+                &[
+                    "ogImage",
+                    "twitterImage",
+                    "oembedThumbnailUrl",
+                    "msapplicationTileImage",
+                ],
+            ),
+            author_name: meta_value(meta, &["oembedAuthorName"]),
+            author_url: meta_value(meta, &["oembedAuthorUrl"]),
+            site_url: meta_value(meta, &["oembedProviderUrl"]),
+            site_name: meta_value(meta, &["ogSiteName", "oembedProviderName"]),
+            html_description: meta_value(meta, &["oembedHtml"]),
+            image_height: meta_value(meta, &["ogImageHeight", "oembedThumbnailHeight"])
+                .parse()
+                .unwrap_or(-1),
+            image_width: meta_value(meta, &["ogImageWidth", "oembedThumbnailWidth"])
+                .parse()
+                .unwrap_or(-1),
+            content_type: ContentType::from_mime_type(&meta_value(&info.headers, &["contentType"])),
+            url: info.url,
+            ..MessageUrl::new()
+        };
+        message_url.image_build_url = message_url.build_image_url();
+        message_url.show_preview = !info.ignore_parse && message_url.has_preview_data();
+        message_url
+    }
+}
+
 impl MessageUrl {
-    fn new() -> Self {
+    pub fn new() -> Self {
         MessageUrl {
             url: String::new(),
             page_title: String::new(),
@@ -62,4 +155,119 @@ impl MessageUrl {
             content_type: ContentType::None,
         }
     }
+
+    // Url links directly to an image.
+    pub fn is_image(&self) -> bool {
+        matches!(
+            self.content_type,
+            ContentType::Image | ContentType::ImageAnimated
+        )
+    }
+
+    pub fn has_preview_data(&self) -> bool {
+        !self.page_title.is_empty()
+            || !self.description.is_empty()
+            || !self.image_build_url.is_empty()
+    }
+
+    // Image url can be relative to page ("/logo.png", "//cdn.org/logo.png").
+    fn build_image_url(&self) -> String {
+        if self.image_url.is_empty() {
+            return if self.is_image() {
+                self.url.clone()
+            } else {
+                String::new()
+            };
+        }
+        match reqwest::Url::parse(&self.url).and_then(|base| base.join(&self.image_url)) {
+            Ok(url) => url.to_string(),
+            Err(_) => self.image_url.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::messageurl::{ContentType, MessageUrl};
+    use std::fs::File;
+
+    pub fn parse(filename: &str) -> Vec<MessageUrl> {
+        let file = File::open(filename).expect("Failed to open file");
+        serde_json::from_reader(file).expect("JSON was not well-formatted")
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = MessageUrl::new();
+        assert!(b.url.is_empty());
+        assert!(b.page_title.is_empty());
+        assert!(b.image_build_url.is_empty());
+        assert_eq!(b.image_height, -1);
+        assert_eq!(b.image_width, -1);
+        assert!(!b.show_preview);
+        assert!(!b.has_preview_data());
+        assert_eq!(b.content_type, ContentType::None);
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(ContentType::from_mime_type(""), ContentType::None);
+        assert_eq!(
+            ContentType::from_mime_type("text/html; charset=utf-8"),
+            ContentType::None
+        );
+        assert_eq!(ContentType::from_mime_type("image/png"), ContentType::Image);
+        assert_eq!(
+            ContentType::from_mime_type("Image/GIF"),
+            ContentType::ImageAnimated
+        );
+        assert_eq!(ContentType::from_mime_type("video/mp4"), ContentType::Video);
+        assert_eq!(ContentType::from_mime_type("audio/ogg"), ContentType::Audio);
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let urls = parse("src/data/messageurl/messageurls.json");
+        assert_eq!(urls.len(), 5);
+
+        let kde = &urls[0];
+        assert_eq!(kde.url, "https://kde.org/announcements/");
+        assert_eq!(kde.page_title, "KDE Announcements");
+        assert_eq!(kde.description, "News from the KDE community");
+        assert_eq!(kde.site_name, "KDE");
+        assert_eq!(kde.image_url, "/stuff/logo.png");
+        assert_eq!(kde.image_build_url, "https://kde.org/stuff/logo.png");
+        assert_eq!(kde.image_width, 1200);
+        assert_eq!(kde.image_height, 630);
+        assert_eq!(kde.content_type, ContentType::None);
+        assert!(kde.show_preview);
+
+        let video = &urls[1];
+        assert_eq!(video.page_title, "Akademy 2026 keynote");
+        assert_eq!(video.author_name, "KDE");
+        assert_eq!(video.author_url, "https://www.youtube.com/@KdeOrg");
+        assert_eq!(video.site_name, "YouTube");
+        assert_eq!(video.site_url, "https://www.youtube.com/");
+        assert_eq!(
+            video.image_build_url,
+            "https://i.ytimg.com/vi/akademy/hqdefault.jpg"
+        );
+        assert_eq!(video.image_width, 480);
+        assert_eq!(video.image_height, 360);
+        assert!(!video.html_description.is_empty());
+        assert!(video.show_preview);
+
+        let image = &urls[2];
+        assert_eq!(image.content_type, ContentType::ImageAnimated);
+        assert!(image.is_image());
+        assert_eq!(image.image_build_url, image.url);
+        assert!(image.show_preview);
+
+        // Preview removed by user
+        assert!(!urls[3].show_preview);
+        assert!(urls[3].has_preview_data());
+
+        // Nothing to show
+        assert!(!urls[4].show_preview);
+    }
 }
@@ -3,16 +3,16 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
-mod block;
-mod blockaccessoryoption;
-mod blockaction;
-mod channelinfo;
-mod channels;
-mod mentions;
-mod messageattachment;
-mod messageattachmentfield;
+pub mod block;
+pub mod blockaccessoryoption;
+pub mod blockaction;
+pub mod channelinfo;
+pub mod channels;
+pub mod mentions;
+pub mod messageattachment;
+pub mod messageattachmentfield;
 mod messagepinned;
-mod messagetranslations;
-mod messageurl;
+pub mod messagetranslations;
+pub mod messageurl;
 mod reaction;
 mod replies;
@@ -4,12 +4,19 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
+use crate::markdown::markdownast::Document;
+use crate::markdown::markdownparser;
+use crate::markdown::rendercontext::MarkdownRenderContext;
+use crate::message::block::Block;
+use crate::message::channels::Channels;
+use crate::message::mentions::Mentions;
+use crate::message::messageattachment::MessageAttachment;
+use crate::message::messagetranslations::MessageTranslations;
+use crate::message::messageurl::MessageUrl;
 use serde::Deserialize;
 use std::fmt;
 #[derive(Default, Clone, Deserialize, Debug, PartialEq)]
 pub enum SystemMessageType {
-    #[default]
-    Unknown,
     #[serde(alias = "uj")]
     UserJoined,
     #[serde(alias = "ul")]
@@ -98,6 +105,10 @@ pub enum SystemMessageType {
     UserRequesterOtrKeyRefresh,
     #[serde(alias = "videoconf")]
     VideoConf,
+    // Normal message or type not handled
+    #[default]
+    #[serde(other)]
+    Unknown,
 }
 
 #[allow(non_snake_case)]
@@ -112,6 +123,7 @@ pub struct MessageInfo {
     pub avatar: String,
     #[serde(default)]
     pub tmid: String,
+    // Discussion room, set on "discussion-created" system messages
     #[serde(default)]
     pub drid: String,
     pub role: String,
@@ -121,8 +133,24 @@ pub struct MessageInfo {
     pub dcount: i64,
     // TODO add type
     pub rid: String,
+    #[serde(default, rename = "t")]
+    pub message_type: SystemMessageType,
 
     pub unread: bool,
+    #[serde(default)]
+    pub mentions: Mentions,
+    #[serde(default)]
+    pub channels: Channels,
+    #[serde(default)]
+    pub urls: Vec<MessageUrl>,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    // UIKit blocks sent by apps
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    // Set when auto translate is enabled in a room
+    #[serde(flatten)]
+    pub translations: MessageTranslations,
 }
 
 impl Default for MessageInfo {
@@ -143,14 +171,43 @@ impl MessageInfo {
             drid: String::default(),
             role: String::default(),
             rid: String::default(),
+            message_type: SystemMessageType::Unknown,
             tcount: 0,
             dcount: 0,
             unread: false,
+            mentions: Mentions::default(),
+            channels: Channels::default(),
+            urls: Vec::new(),
+            attachments: Vec::new(),
+            blocks: Vec::new(),
+            translations: MessageTranslations::new(),
         }
     }
     pub fn parse(filename: &str) -> MessageInfo {
         serde_json::from_str(filename).expect("JSON was not well-formatted")
     }
+
+    pub fn markdown_document(&self) -> Document {
+        markdownparser::parse(&self.message)
+    }
+
+    // Mentions and channels of message, emojis and own user are set by caller.
+    pub fn render_context(&self) -> MarkdownRenderContext<'_> {
+        MarkdownRenderContext {
+            mentions: Some(&self.mentions),
+            channels: Some(&self.channels),
+            ..Default::default()
+        }
+    }
+
+    // Room to open when user clicks on a discussion created message.
+    pub fn discussion_room_id(&self) -> Option<&str> {
+        if self.message_type == SystemMessageType::DiscussionCreated && !self.drid.is_empty() {
+            Some(&self.drid)
+        } else {
+            None
+        }
+    }
 }
 
 /*
@@ -168,7 +225,8 @@ impl fmt::Display for MessageInfo {
 
 #[cfg(test)]
 mod tests {
-    use crate::messageinfo::MessageInfo;
+    use crate::markdown::htmlrenderer::to_html;
+    use crate::messageinfo::{MessageInfo, SystemMessageType};
 
     #[test]
     fn test_is_empty() {
@@ -183,5 +241,54 @@ mod tests {
         assert_eq!(b.dcount, 0);
         assert_eq!(b.tcount, 0);
         assert!(b.rid.is_empty());
+        assert!(b.mentions.is_empty());
+        assert!(b.channels.is_empty());
+        assert!(b.urls.is_empty());
+        assert!(b.attachments.is_empty());
+        assert!(b.blocks.is_empty());
+        assert!(b.translations.is_empty());
+        assert_eq!(b.message_type, SystemMessageType::Unknown);
+        assert!(b.discussion_room_id().is_none());
+    }
+
+    #[test]
+    fn test_discussion_created() {
+        let b: MessageInfo = serde_json::from_value(serde_json::json!({
+            "_id": "Gd9sQWhHLP4TzxZeE", "msg": "release", "alias": "", "emoji": "",
+            "avatar": "", "role": "", "rid": "GENERAL", "unread": false,
+            "t": "discussion-created", "drid": "7gkGrmQ2TyXBj8ZfN", "dcount": 12
+        }))
+        .unwrap();
+        assert_eq!(b.message_type, SystemMessageType::DiscussionCreated);
+        assert_eq!(b.dcount, 12);
+        assert_eq!(b.discussion_room_id(), Some("7gkGrmQ2TyXBj8ZfN"));
+
+        // Unknown types are accepted
+        let b: MessageInfo = serde_json::from_value(serde_json::json!({
+            "_id": "Gd9sQWhHLP4TzxZeE", "msg": "", "alias": "", "emoji": "",
+            "avatar": "", "role": "", "rid": "GENERAL", "unread": false,
+            "t": "livechat-started", "drid": "7gkGrmQ2TyXBj8ZfN"
+        }))
+        .unwrap();
+        assert_eq!(b.message_type, SystemMessageType::Unknown);
+        assert!(b.discussion_room_id().is_none());
+    }
+
+    #[test]
+    fn test_markdown() {
+        let b: MessageInfo = serde_json::from_value(serde_json::json!({
+            "_id": "AkzpHAvZpdnuchw2a", "msg": "*hi* @foo, see #general", "alias": "", "emoji": "",
+            "avatar": "", "role": "", "rid": "GENERAL", "unread": false,
+            "mentions": [{"_id": "Fp3wPsDNh7e3Mh5wN", "username": "foo", "name": "Foo"}],
+            "channels": [{"_id": "GENERAL", "name": "general"}]
+        }))
+        .unwrap();
+        assert_eq!(b.mentions.user_identifier("foo"), Some("Fp3wPsDNh7e3Mh5wN"));
+        assert_eq!(b.channels.channels.len(), 1);
+        assert_eq!(b.markdown_document().user_mentions(), vec!["foo"]);
+        assert_eq!(
+            to_html(&b.markdown_document(), &b.render_context()),
+            "<p><b>hi</b> <a href=\"ruqola:/user/foo\" class=\"mention\">@foo</a>, see <a href=\"ruqola:/room/general\" class=\"channel\">#general</a></p>"
+        );
     }
 }
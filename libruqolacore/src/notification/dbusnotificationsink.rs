@@ -0,0 +1,148 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::notification::notificationsink::{NotificationAction, NotificationSink};
+use crate::notificationinfo::NotificationInfo;
+use futures_util::StreamExt;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use zbus::zvariant::Value;
+
+// https://specifications.freedesktop.org/notification-spec/latest/
+const DESTINATION: &str = "org.freedesktop.Notifications";
+const PATH: &str = "/org/freedesktop/Notifications";
+const INTERFACE: &str = "org.freedesktop.Notifications";
+// Action invoked when user clicks on notification
+const DEFAULT_ACTION: &str = "default";
+
+// Show notifications with org.freedesktop.Notifications.
+// Clicked notifications are sent to actions channel.
+pub struct DBusNotificationSink {
+    sender: mpsc::UnboundedSender<(NotificationInfo, Option<String>)>,
+}
+
+impl DBusNotificationSink {
+    // Must be called inside tokio runtime.
+    pub async fn new(
+        app_name: String,
+        actions: mpsc::UnboundedSender<NotificationAction>,
+    ) -> zbus::Result<Self> {
+        let connection = zbus::Connection::session().await?;
+        let proxy = zbus::Proxy::new(&connection, DESTINATION, PATH, INTERFACE).await?;
+        let mut invoked = proxy.receive_signal("ActionInvoked").await?;
+        let mut closed = proxy.receive_signal("NotificationClosed").await?;
+        let (sender, mut receiver) =
+            mpsc::unbounded_channel::<(NotificationInfo, Option<String>)>();
+        tokio::spawn(async move {
+            // Notification identifier => action
+            let mut pending: HashMap<u32, NotificationAction> = HashMap::new();
+            loop {
+                tokio::select! {
+                    notification = receiver.recv() => {
+                        let Some((info, sound)) = notification else {
+                            break;
+                        };
+                        match show(&proxy, &app_name, &info, sound.as_deref()).await {
+                            Ok(id) => {
+                                pending.insert(id, NotificationAction::from_notification(&info));
+                            }
+                            Err(err) => println!("Impossible to show notification: {err}"),
+                        }
+                    }
+                    Some(message) = invoked.next() => {
+                        if let Ok((id, action_key)) = message.body().deserialize::<(u32, String)>()
+                            && action_key == DEFAULT_ACTION
+                            && let Some(action) = pending.remove(&id)
+                        {
+                            let _ = actions.send(action);
+                        }
+                    }
+                    Some(message) = closed.next() => {
+                        if let Ok((id, _reason)) = message.body().deserialize::<(u32, u32)>() {
+                            pending.remove(&id);
+                        }
+                    }
+                }
+            }
+        });
+        Ok(DBusNotificationSink { sender })
+    }
+}
+
+async fn show(
+    proxy: &zbus::Proxy<'_>,
+    app_name: &str,
+    info: &NotificationInfo,
+    sound: Option<&str>,
+) -> zbus::Result<u32> {
+    let mut hints: HashMap<&str, Value> = HashMap::new();
+    hints.insert("category", Value::from("im.received"));
+    match sound {
+        Some(sound) => {
+            hints.insert("sound-name", Value::from(sound));
+        }
+        None => {
+            hints.insert("suppress-sound", Value::from(true));
+        }
+    }
+    let summary = if info.title.is_empty() {
+        &info.sender_name
+    } else {
+        &info.title
+    };
+    let body = escape_body(&info.message);
+    proxy
+        .call(
+            "Notify",
+            &(
+                app_name,
+                0u32,
+                "",
+                summary.as_str(),
+                body.as_str(),
+                vec![DEFAULT_ACTION, "Open"],
+                hints,
+                -1i32,
+            ),
+        )
+        .await
+}
+
+// Servers which support body markup parse "<" and "&" of chat text.
+fn escape_body(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+impl NotificationSink for DBusNotificationSink {
+    fn show_notification(&mut self, info: &NotificationInfo, sound: Option<&str>) {
+        let _ = self
+            .sender
+            .send((info.clone(), sound.map(|sound| sound.to_string())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification::dbusnotificationsink::escape_body;
+
+    #[test]
+    fn test_escape_body() {
+        assert_eq!(escape_body("hello"), "hello");
+        assert_eq!(
+            escape_body("<b>a</b> & 'b' \"c\""),
+            "&lt;b&gt;a&lt;/b&gt; &amp; 'b' \"c\""
+        );
+    }
+}
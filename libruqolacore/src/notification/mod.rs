@@ -0,0 +1,10 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+#[cfg(target_os = "linux")]
+pub mod dbusnotificationsink;
+pub mod notificationmanager;
+pub mod notificationsink;
+pub mod roomnotificationsettings;
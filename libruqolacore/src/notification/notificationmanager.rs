@@ -0,0 +1,429 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::notification::notificationsink::{NotificationAction, NotificationSink};
+use crate::notification::roomnotificationsettings::{
+    NotificationPreference, RoomNotificationSettings,
+};
+use crate::notificationinfo::NotificationInfo;
+use crate::ownuser::ownuserinfo::OwnUser;
+use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+use crate::user::Status;
+use std::sync::{Arc, Mutex};
+
+// Sound used when user didn't choose one
+const DEFAULT_SOUND: &str = "chime";
+
+// Filter "<user_id>/notification" stream with user and room preferences,
+// accepted notifications are sent to sink.
+#[derive(Clone)]
+pub struct NotificationManager {
+    pub account_name: String,
+    pub own_user_id: String,
+    pub own_user_name: String,
+    pub preferences: OwnUserPreferences,
+    // Busy users don't receive notifications
+    pub status: Status,
+    pub do_not_disturb: bool,
+    // Room displayed by client
+    pub current_room_id: String,
+    // Thread displayed by client, empty when no thread is opened
+    pub current_thread_message_id: String,
+    sink: Option<Arc<Mutex<dyn NotificationSink>>>,
+}
+
+impl Default for NotificationManager {
+    fn default() -> Self {
+        NotificationManager::new(String::default())
+    }
+}
+
+impl NotificationManager {
+    pub fn new(account_name: String) -> Self {
+        NotificationManager {
+            account_name,
+            own_user_id: String::default(),
+            own_user_name: String::default(),
+            preferences: OwnUserPreferences::new(),
+            status: Status::Unknown,
+            do_not_disturb: false,
+            current_room_id: String::default(),
+            current_thread_message_id: String::default(),
+            sink: None,
+        }
+    }
+
+    pub fn set_sink(&mut self, sink: Arc<Mutex<dyn NotificationSink>>) {
+        self.sink = Some(sink);
+    }
+
+    pub fn set_own_user(&mut self, own_user: &OwnUser) {
+        self.own_user_id = own_user.user_id.clone();
+        self.own_user_name = own_user.username.clone();
+        self.preferences = own_user.preferences().clone();
+        self.status = own_user.status.clone();
+    }
+
+    // Room preference, then user preference. Server default is "all".
+    pub fn desktop_notification_preference(
+        &self,
+        room_settings: Option<&RoomNotificationSettings>,
+    ) -> NotificationPreference {
+        if let Some(settings) = room_settings
+            && settings.desktop_notifications != NotificationPreference::Default
+        {
+            return settings.desktop_notifications.clone();
+        }
+        match NotificationPreference::from_name(&self.preferences.desktop_notifications) {
+            NotificationPreference::Default => NotificationPreference::All,
+            preference => preference,
+        }
+    }
+
+    // Direct messages, @username, @all, @here and highlight words.
+    // Group mentions (@all, @here) can be muted by room.
+    pub fn is_mention(
+        &self,
+        info: &NotificationInfo,
+        room_settings: Option<&RoomNotificationSettings>,
+    ) -> bool {
+        if info.is_direct_message() {
+            return true;
+        }
+        let message = info.message.to_lowercase();
+        let mentioned = |name: &str| {
+            message
+                .split(|c: char| !(c.is_alphanumeric() || "@._-".contains(c)))
+                .any(|word| word.trim_end_matches(['.', ':']) == name)
+        };
+        let mute_group_mentions =
+            room_settings.is_some_and(|settings| settings.mute_group_mentions);
+        if !mute_group_mentions && (mentioned("@all") || mentioned("@here")) {
+            return true;
+        }
+        if !self.own_user_name.is_empty()
+            && mentioned(&format!("@{}", self.own_user_name.to_lowercase()))
+        {
+            return true;
+        }
+        self.preferences
+            .highlight_words
+            .iter()
+            .filter(|word| !word.is_empty())
+            .any(|word| message.contains(&word.to_lowercase()))
+    }
+
+    pub fn should_notify(
+        &self,
+        info: &NotificationInfo,
+        room_settings: Option<&RoomNotificationSettings>,
+    ) -> bool {
+        if !info.is_valid() || self.do_not_disturb || self.status == Status::Busy {
+            return false;
+        }
+        if !self.own_user_id.is_empty() && info.sender_id == self.own_user_id {
+            return false;
+        }
+        if room_settings.is_some_and(|settings| settings.disable_notifications) {
+            return false;
+        }
+        if self.preferences.mute_focused_conversations && info.room_id == self.current_room_id {
+            return false;
+        }
+        match self.desktop_notification_preference(room_settings) {
+            NotificationPreference::Nothing => false,
+            NotificationPreference::Mentions => self.is_mention(info, room_settings),
+            NotificationPreference::All | NotificationPreference::Default => true,
+        }
+    }
+
+    // None when notification must be silent. Room sound wins, "0" means user preference.
+    pub fn notification_sound(
+        &self,
+        room_settings: Option<&RoomNotificationSettings>,
+    ) -> Option<String> {
+        let sound = match room_settings {
+            Some(settings)
+                if !settings.audio_notification_value.is_empty()
+                    && settings.audio_notification_value != "0" =>
+            {
+                settings.audio_notification_value.as_str()
+            }
+            _ => self.preferences.new_message_notification.as_str(),
+        };
+        match sound {
+            "none" => None,
+            "" | "default" => Some(DEFAULT_SOUND.to_string()),
+            sound => Some(sound.to_string()),
+        }
+    }
+
+    // "<user_id>/notification" stream, returns notifications sent to sink.
+    pub fn apply_notification<F>(
+        &mut self,
+        value: &serde_json::Value,
+        room_settings: F,
+    ) -> Vec<NotificationInfo>
+    where
+        F: Fn(&str) -> Option<RoomNotificationSettings>,
+    {
+        let Some(args) = value.as_array() else {
+            return Vec::new();
+        };
+        let mut notifications = Vec::new();
+        for arg in args {
+            let Some(mut info) = NotificationInfo::parse_notification(arg) else {
+                continue;
+            };
+            info.account_name = self.account_name.clone();
+            let settings = room_settings(&info.room_id);
+            if !self.should_notify(&info, settings.as_ref()) {
+                continue;
+            }
+            if let Some(sink) = &self.sink
+                && let Ok(mut sink) = sink.lock()
+            {
+                sink.show_notification(
+                    &info,
+                    self.notification_sound(settings.as_ref()).as_deref(),
+                );
+            }
+            notifications.push(info);
+        }
+        notifications
+    }
+
+    // User clicked on notification, room (or thread) is displayed.
+    pub fn notification_activated(&mut self, action: &NotificationAction) {
+        self.current_room_id = action.room_id().to_string();
+        self.current_thread_message_id = action.thread_message_id().to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification::notificationmanager::NotificationManager;
+    use crate::notification::notificationsink::{InMemoryNotificationSink, NotificationAction};
+    use crate::notification::roomnotificationsettings::{
+        NotificationPreference, RoomNotificationSettings,
+    };
+    use crate::notificationinfo::NotificationInfo;
+    use crate::ownuser::ownuserinfo::OwnUser;
+    use crate::user::Status;
+    use std::sync::{Arc, Mutex};
+
+    fn generate_manager() -> NotificationManager {
+        let mut own_user = OwnUser::new();
+        own_user.parse_elements(
+            &std::fs::read_to_string("src/data/ownuser/me.json").expect("Failed to open file"),
+        );
+        let mut m = NotificationManager::new("kde".to_string());
+        m.set_own_user(&own_user);
+        m
+    }
+
+    fn generate_info(room_id: &str, channel_type: &str, message: &str) -> NotificationInfo {
+        NotificationInfo {
+            room_id: room_id.to_string(),
+            channel_type: channel_type.to_string(),
+            message: message.to_string(),
+            sender_id: "uD5Aq8ePJAfK3N3Ps".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn room_settings(preference: NotificationPreference) -> RoomNotificationSettings {
+        RoomNotificationSettings {
+            desktop_notifications: preference,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_values() {
+        let m = NotificationManager::default();
+        assert!(m.account_name.is_empty());
+        assert!(!m.do_not_disturb);
+        assert!(m.current_room_id.is_empty());
+        assert_eq!(
+            m.desktop_notification_preference(None),
+            NotificationPreference::All
+        );
+        assert_eq!(m.notification_sound(None), Some("chime".to_string()));
+    }
+
+    #[test]
+    fn test_set_own_user() {
+        let m = generate_manager();
+        assert_eq!(m.own_user_id, "XQZAk3998f9hSNwh");
+        assert_eq!(m.own_user_name, "laurent");
+        assert_eq!(m.status, Status::Online);
+        assert_eq!(m.preferences.highlight_words, vec!["ruqola"]);
+        assert_eq!(m.preferences.desktop_notifications, "mentions");
+    }
+
+    #[test]
+    fn test_is_mention() {
+        let m = generate_manager();
+        assert!(m.is_mention(
+            &generate_info("GENERAL", "c", "@laurent: can you review?"),
+            None
+        ));
+        assert!(m.is_mention(&generate_info("GENERAL", "c", "Hi @Laurent."), None));
+        assert!(m.is_mention(&generate_info("GENERAL", "c", "@here release"), None));
+        assert!(m.is_mention(&generate_info("GENERAL", "c", "New Ruqola release"), None));
+        assert!(m.is_mention(&generate_info("roomid", "d", "hello"), None));
+        assert!(!m.is_mention(&generate_info("GENERAL", "c", "@laurent_m hello"), None));
+        assert!(!m.is_mention(&generate_info("GENERAL", "c", "hello"), None));
+
+        let settings = RoomNotificationSettings {
+            mute_group_mentions: true,
+            ..Default::default()
+        };
+        assert!(!m.is_mention(
+            &generate_info("GENERAL", "c", "@here release"),
+            Some(&settings)
+        ));
+        assert!(m.is_mention(
+            &generate_info("GENERAL", "c", "@laurent release"),
+            Some(&settings)
+        ));
+    }
+
+    #[test]
+    fn test_should_notify() {
+        let mut m = generate_manager();
+        let info = generate_info("GENERAL", "c", "hello");
+        // User preference is "mentions"
+        assert!(!m.should_notify(&info, None));
+        assert!(m.should_notify(&generate_info("GENERAL", "c", "@laurent hello"), None));
+        m.preferences.desktop_notifications = "all".to_string();
+        assert!(m.should_notify(&info, None));
+        // Invalid notification
+        assert!(!m.should_notify(&generate_info("", "c", "hello"), None));
+        // Own message
+        let mut own = info.clone();
+        own.sender_id = "XQZAk3998f9hSNwh".to_string();
+        assert!(!m.should_notify(&own, None));
+
+        // Room preferences
+        assert!(!m.should_notify(&info, Some(&room_settings(NotificationPreference::Nothing))));
+        assert!(!m.should_notify(
+            &info,
+            Some(&room_settings(NotificationPreference::Mentions))
+        ));
+        assert!(m.should_notify(
+            &generate_info("GENERAL", "c", "@laurent hello"),
+            Some(&room_settings(NotificationPreference::Mentions))
+        ));
+        let disabled = RoomNotificationSettings {
+            desktop_notifications: NotificationPreference::All,
+            disable_notifications: true,
+            ..Default::default()
+        };
+        assert!(!m.should_notify(&info, Some(&disabled)));
+
+        // User preferences, room preference wins
+        m.preferences.desktop_notifications = "nothing".to_string();
+        assert!(!m.should_notify(&info, None));
+        assert!(!m.should_notify(&info, Some(&room_settings(NotificationPreference::Default))));
+        assert!(m.should_notify(&info, Some(&room_settings(NotificationPreference::All))));
+        m.preferences.desktop_notifications = "all".to_string();
+
+        // Focused room
+        m.current_room_id = "GENERAL".to_string();
+        assert!(!m.should_notify(&info, None));
+        m.preferences.mute_focused_conversations = false;
+        assert!(m.should_notify(&info, None));
+
+        // Do not disturb
+        m.do_not_disturb = true;
+        assert!(!m.should_notify(&info, None));
+        m.do_not_disturb = false;
+        m.status = Status::Busy;
+        assert!(!m.should_notify(&info, None));
+    }
+
+    #[test]
+    fn test_notification_sound() {
+        let mut m = generate_manager();
+        m.preferences.new_message_notification = "none".to_string();
+        assert!(m.notification_sound(None).is_none());
+        m.preferences.new_message_notification = "beep".to_string();
+        assert_eq!(m.notification_sound(None), Some("beep".to_string()));
+
+        // Room sound
+        let mut settings = RoomNotificationSettings {
+            audio_notification_value: "ding".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            m.notification_sound(Some(&settings)),
+            Some("ding".to_string())
+        );
+        settings.audio_notification_value = "none".to_string();
+        assert!(m.notification_sound(Some(&settings)).is_none());
+        settings.audio_notification_value = "0".to_string();
+        assert_eq!(
+            m.notification_sound(Some(&settings)),
+            Some("beep".to_string())
+        );
+    }
+
+    #[test]
+    fn test_apply_notification() {
+        let mut m = generate_manager();
+        m.preferences.desktop_notifications = "all".to_string();
+        let sink = Arc::new(Mutex::new(InMemoryNotificationSink::new()));
+        m.set_sink(sink.clone());
+        let json: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("src/data/notification/notification-tmid1.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        let value = json["fields"]["args"].clone();
+
+        let notifications = m.apply_notification(&value, |_| None);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].account_name, "kde");
+        {
+            let sink = sink.lock().unwrap();
+            assert_eq!(sink.notifications.len(), 1);
+            assert_eq!(sink.notifications[0].1, Some("chime".to_string()));
+        }
+
+        // Disabled in room
+        let notifications = m.apply_notification(&value, |room_id| {
+            (room_id == "Fc4EWcQXCkJkJ7QEQ").then(|| RoomNotificationSettings {
+                disable_notifications: true,
+                ..Default::default()
+            })
+        });
+        assert!(notifications.is_empty());
+        assert_eq!(sink.lock().unwrap().notifications.len(), 1);
+
+        // Click opens thread
+        let action = sink.lock().unwrap().activate(0).unwrap();
+        assert_eq!(
+            action,
+            NotificationAction::OpenThread {
+                account_name: "kde".to_string(),
+                room_id: "Fc4EWcQXCkJkJ7QEQ".to_string(),
+                thread_message_id: "QLNcu8TnRi8Ld57Pk".to_string()
+            }
+        );
+        m.notification_activated(&action);
+        assert_eq!(m.current_room_id, "Fc4EWcQXCkJkJ7QEQ");
+        assert_eq!(m.current_thread_message_id, "QLNcu8TnRi8Ld57Pk");
+        // Room is displayed now
+        assert!(m.apply_notification(&value, |_| None).is_empty());
+
+        assert!(
+            m.apply_notification(&serde_json::json!({}), |_| None)
+                .is_empty()
+        );
+    }
+}
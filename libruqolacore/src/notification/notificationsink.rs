@@ -0,0 +1,162 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::notificationinfo::NotificationInfo;
+
+// What to open when user clicks on a notification.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotificationAction {
+    OpenRoom {
+        account_name: String,
+        room_id: String,
+    },
+    OpenThread {
+        account_name: String,
+        room_id: String,
+        thread_message_id: String,
+    },
+}
+
+impl NotificationAction {
+    pub fn from_notification(info: &NotificationInfo) -> Self {
+        if info.is_thread_message() {
+            NotificationAction::OpenThread {
+                account_name: info.account_name.clone(),
+                room_id: info.room_id.clone(),
+                thread_message_id: info.tm_id.clone(),
+            }
+        } else {
+            NotificationAction::OpenRoom {
+                account_name: info.account_name.clone(),
+                room_id: info.room_id.clone(),
+            }
+        }
+    }
+
+    pub fn room_id(&self) -> &str {
+        match self {
+            NotificationAction::OpenRoom { room_id, .. } => room_id,
+            NotificationAction::OpenThread { room_id, .. } => room_id,
+        }
+    }
+
+    pub fn account_name(&self) -> &str {
+        match self {
+            NotificationAction::OpenRoom { account_name, .. } => account_name,
+            NotificationAction::OpenThread { account_name, .. } => account_name,
+        }
+    }
+
+    // Empty when room must be opened
+    pub fn thread_message_id(&self) -> &str {
+        match self {
+            NotificationAction::OpenRoom { .. } => "",
+            NotificationAction::OpenThread {
+                thread_message_id, ..
+            } => thread_message_id,
+        }
+    }
+}
+
+// Displays notifications accepted by NotificationManager.
+// sound is None when notification must be silent.
+pub trait NotificationSink: Send {
+    fn show_notification(&mut self, info: &NotificationInfo, sound: Option<&str>);
+}
+
+// Keep notifications in memory (tests, or client which displays them itself).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InMemoryNotificationSink {
+    pub notifications: Vec<(NotificationInfo, Option<String>)>,
+}
+
+impl InMemoryNotificationSink {
+    pub fn new() -> Self {
+        InMemoryNotificationSink {
+            ..Default::default()
+        }
+    }
+
+    // Simulate click on notification.
+    pub fn activate(&self, index: usize) -> Option<NotificationAction> {
+        self.notifications
+            .get(index)
+            .map(|(info, _)| NotificationAction::from_notification(info))
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+    }
+}
+
+impl NotificationSink for InMemoryNotificationSink {
+    fn show_notification(&mut self, info: &NotificationInfo, sound: Option<&str>) {
+        self.notifications
+            .push((info.clone(), sound.map(|sound| sound.to_string())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification::notificationsink::{
+        InMemoryNotificationSink, NotificationAction, NotificationSink,
+    };
+    use crate::notificationinfo::NotificationInfo;
+
+    fn generate_info(tm_id: &str) -> NotificationInfo {
+        NotificationInfo {
+            account_name: "kde".to_string(),
+            room_id: "GENERAL".to_string(),
+            tm_id: tm_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_notification() {
+        assert_eq!(
+            NotificationAction::from_notification(&generate_info("")),
+            NotificationAction::OpenRoom {
+                account_name: "kde".to_string(),
+                room_id: "GENERAL".to_string()
+            }
+        );
+        let action = NotificationAction::from_notification(&generate_info("thread1"));
+        assert_eq!(
+            action,
+            NotificationAction::OpenThread {
+                account_name: "kde".to_string(),
+                room_id: "GENERAL".to_string(),
+                thread_message_id: "thread1".to_string()
+            }
+        );
+        assert_eq!(action.room_id(), "GENERAL");
+        assert_eq!(action.account_name(), "kde");
+        assert_eq!(action.thread_message_id(), "thread1");
+        assert!(
+            NotificationAction::from_notification(&generate_info(""))
+                .thread_message_id()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_in_memory_sink() {
+        let mut sink = InMemoryNotificationSink::new();
+        assert!(sink.activate(0).is_none());
+        sink.show_notification(&generate_info(""), Some("chime"));
+        sink.show_notification(&generate_info("thread1"), None);
+        assert_eq!(sink.notifications.len(), 2);
+        assert_eq!(sink.notifications[0].1, Some("chime".to_string()));
+        assert!(sink.notifications[1].1.is_none());
+        assert!(matches!(
+            sink.activate(1),
+            Some(NotificationAction::OpenThread { .. })
+        ));
+        sink.clear();
+        assert!(sink.notifications.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use librocketchatrestapi::methods::RoomsSaveNotificationInfo;
+use serde::Deserialize;
+
+// Alert level used by desktop notifications preferences.
+#[derive(Clone, Deserialize, Debug, PartialEq, Default)]
+pub enum NotificationPreference {
+    // Use user preference (or server default for user preference)
+    #[default]
+    #[serde(alias = "default")]
+    Default,
+    #[serde(alias = "all")]
+    All,
+    #[serde(alias = "mentions")]
+    Mentions,
+    #[serde(alias = "nothing")]
+    Nothing,
+}
+
+impl NotificationPreference {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "all" => NotificationPreference::All,
+            "mentions" => NotificationPreference::Mentions,
+            "nothing" => NotificationPreference::Nothing,
+            _ => NotificationPreference::Default,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationPreference::Default => "default",
+            NotificationPreference::All => "all",
+            NotificationPreference::Mentions => "mentions",
+            NotificationPreference::Nothing => "nothing",
+        }
+    }
+}
+
+// Notification settings of a room, stored in subscription.
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct RoomNotificationSettings {
+    #[serde(rename = "desktopNotifications")]
+    pub desktop_notifications: NotificationPreference,
+    #[serde(rename = "mobilePushNotifications")]
+    pub mobile_push_notifications: NotificationPreference,
+    #[serde(rename = "emailNotifications")]
+    pub email_notifications: NotificationPreference,
+    // Sound identifier, empty for user preference
+    #[serde(rename = "audioNotificationValue")]
+    pub audio_notification_value: String,
+    #[serde(rename = "hideUnreadStatus")]
+    pub hide_unread_status: bool,
+    #[serde(rename = "muteGroupMentions")]
+    pub mute_group_mentions: bool,
+    #[serde(rename = "disableNotifications")]
+    pub disable_notifications: bool,
+    #[serde(rename = "hideMentionStatus")]
+    pub hide_mention_status: bool,
+}
+
+impl RoomNotificationSettings {
+    // Values sent by rooms.saveNotification
+    pub fn save_info(&self) -> RoomsSaveNotificationInfo {
+        RoomsSaveNotificationInfo {
+            desktop_notifications: Some(self.desktop_notifications.name().to_string()),
+            mobile_push_notifications: Some(self.mobile_push_notifications.name().to_string()),
+            email_notifications: Some(self.email_notifications.name().to_string()),
+            audio_notification_value: Some(if self.audio_notification_value.is_empty() {
+                "0".to_string()
+            } else {
+                self.audio_notification_value.clone()
+            }),
+            unread_alert: None,
+            hide_unread_status: Some(self.hide_unread_status),
+            mute_group_mentions: Some(self.mute_group_mentions),
+            disable_notifications: Some(self.disable_notifications),
+            hide_mention_status: Some(self.hide_mention_status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification::roomnotificationsettings::{
+        NotificationPreference, RoomNotificationSettings,
+    };
+
+    #[test]
+    fn test_default_values() {
+        let settings = RoomNotificationSettings::default();
+        assert_eq!(
+            settings.desktop_notifications,
+            NotificationPreference::Default
+        );
+        assert!(!settings.disable_notifications);
+        assert!(settings.audio_notification_value.is_empty());
+        assert!(!settings.hide_unread_status);
+        assert!(!settings.mute_group_mentions);
+        assert!(!settings.hide_mention_status);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            NotificationPreference::from_name("all"),
+            NotificationPreference::All
+        );
+        assert_eq!(
+            NotificationPreference::from_name("mentions"),
+            NotificationPreference::Mentions
+        );
+        assert_eq!(
+            NotificationPreference::from_name("nothing"),
+            NotificationPreference::Nothing
+        );
+        assert_eq!(
+            NotificationPreference::from_name(""),
+            NotificationPreference::Default
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        for preference in [
+            NotificationPreference::Default,
+            NotificationPreference::All,
+            NotificationPreference::Mentions,
+            NotificationPreference::Nothing,
+        ] {
+            assert_eq!(
+                NotificationPreference::from_name(preference.name()),
+                preference
+            );
+        }
+    }
+
+    #[test]
+    fn test_save_info() {
+        let settings = RoomNotificationSettings {
+            desktop_notifications: NotificationPreference::Mentions,
+            disable_notifications: true,
+            ..Default::default()
+        };
+        let info = settings.save_info();
+        assert_eq!(info.desktop_notifications, Some("mentions".to_string()));
+        assert_eq!(info.mobile_push_notifications, Some("default".to_string()));
+        assert_eq!(info.audio_notification_value, Some("0".to_string()));
+        assert_eq!(info.disable_notifications, Some(true));
+        assert_eq!(info.hide_unread_status, Some(false));
+    }
+
+    #[test]
+    fn test_parse_subscription() {
+        let settings: RoomNotificationSettings = serde_json::from_value(serde_json::json!({
+            "rid": "GENERAL", "desktopNotifications": "mentions", "disableNotifications": true
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.desktop_notifications,
+            NotificationPreference::Mentions
+        );
+        assert!(settings.disable_notifications);
+    }
+}
@@ -4,7 +4,6 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
-
 use std::fmt;
 #[derive(Default, Clone, Debug, PartialEq)]
 pub enum NotificationType {
@@ -14,6 +13,7 @@ pub enum NotificationType {
 }
 // TODO implement display for enum
 
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct NotificationInfo {
     pub message_id: String,
     pub account_name: String,
@@ -30,6 +30,54 @@ pub struct NotificationInfo {
     pub notification_type: NotificationType,
 }
 
+impl NotificationInfo {
+    pub fn new() -> Self {
+        NotificationInfo {
+            ..Default::default()
+        }
+    }
+
+    // Parse "<user_id>/notification" stream argument:
+    // {"title": …, "text": …, "payload": {"_id": …, "rid": …, "tmid": …, "sender": {…}, "type": …, "name": …, "message": {…}}}
+    pub fn parse_notification(json: &serde_json::Value) -> Option<NotificationInfo> {
+        let payload = &json["payload"];
+        let room_id = payload["rid"].as_str()?;
+        let text = |value: &serde_json::Value| value.as_str().unwrap_or_default().to_string();
+        let notification_type = if payload["message"]["t"].as_str() == Some("videoconf") {
+            NotificationType::ConferenceCall
+        } else {
+            NotificationType::StandardMessage
+        };
+        Some(NotificationInfo {
+            message_id: text(&payload["_id"]),
+            account_name: String::default(),
+            message: text(&json["text"]),
+            title: text(&json["title"]),
+            sender_id: text(&payload["sender"]["_id"]),
+            sender_name: text(&payload["sender"]["name"]),
+            sender_user_name: text(&payload["sender"]["username"]),
+            room_name: text(&payload["name"]),
+            room_id: room_id.to_string(),
+            channel_type: text(&payload["type"]),
+            tm_id: text(&payload["tmid"]),
+            date_time: String::default(),
+            notification_type,
+        })
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.room_id.is_empty()
+    }
+
+    pub fn is_thread_message(&self) -> bool {
+        !self.tm_id.is_empty()
+    }
+
+    pub fn is_direct_message(&self) -> bool {
+        self.channel_type == "d"
+    }
+}
+
 /*
 Debug output for MessageInfo
 */
@@ -38,30 +86,89 @@ impl fmt::Display for NotificationInfo {
         write!(
             f,
             "(message identifier: {}, accountName: {}, message: {}, title: {}, senderId: {}, senderName: {}, senderUserName: {}, roomName: {}, roomId: {}, channelType: {}, mTmId: {}, mDateTime: {}, mNotificationType: {:?})",
-            self.message_id, self.account_name, self.message, self.title, self.sender_id, self.sender_name, self.sender_user_name, self.room_name, self.room_id, self.channel_type, self.tm_id, self.date_time, self.notification_type  
+            self.message_id,
+            self.account_name,
+            self.message,
+            self.title,
+            self.sender_id,
+            self.sender_name,
+            self.sender_user_name,
+            self.room_name,
+            self.room_id,
+            self.channel_type,
+            self.tm_id,
+            self.date_time,
+            self.notification_type
         )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::notificationinfo::NotificationInfo;
+    use crate::notificationinfo::{NotificationInfo, NotificationType};
 
     #[test]
     fn test_is_empty() {
-        // TODO
-        /*
         let b = NotificationInfo::new();
-        assert!(b.identifier.is_empty());
-        assert!(b.alias.is_empty());
-        assert!(b.emoji.is_empty());
-        assert!(b.avatar.is_empty());
-        assert!(b.tmid.is_empty());
-        assert!(b.drid.is_empty());
-        assert!(b.role.is_empty());
-        assert_eq!(b.dcount, 0);
-        assert_eq!(b.tcount, 0);
-        assert!(b.rid.is_empty());
-        */
+        assert!(!b.is_valid());
+        assert!(b.message_id.is_empty());
+        assert!(b.account_name.is_empty());
+        assert!(b.message.is_empty());
+        assert!(b.title.is_empty());
+        assert!(b.sender_id.is_empty());
+        assert!(b.room_id.is_empty());
+        assert!(b.tm_id.is_empty());
+        assert!(!b.is_thread_message());
+        assert_eq!(b.notification_type, NotificationType::StandardMessage);
+    }
+
+    fn parse_args(filename: &str) -> serde_json::Value {
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(filename).expect("Failed to open file"))
+                .unwrap();
+        json["fields"]["args"][0].clone()
+    }
+
+    #[test]
+    fn test_parse_notification() {
+        let b = NotificationInfo::parse_notification(&parse_args(
+            "src/data/notification/notification-tmid1.json",
+        ))
+        .unwrap();
+        assert!(b.is_valid());
+        assert_eq!(b.message_id, "dNYhFAYBhqPFnBwRy");
+        assert_eq!(b.title, "my title");
+        assert_eq!(b.message, "text s");
+        assert_eq!(b.sender_id, "Gsvg6BGoBfmPLoFie");
+        assert_eq!(b.sender_name, "foo");
+        assert_eq!(b.sender_user_name, "foo.bla");
+        assert_eq!(b.room_name, "roomname example");
+        assert_eq!(b.room_id, "Fc4EWcQXCkJkJ7QEQ");
+        assert_eq!(b.channel_type, "c");
+        assert_eq!(b.tm_id, "QLNcu8TnRi8Ld57Pk");
+        assert!(b.is_thread_message());
+        assert!(!b.is_direct_message());
+        assert_eq!(b.notification_type, NotificationType::StandardMessage);
+
+        let b = NotificationInfo::parse_notification(&parse_args(
+            "src/data/notification/notification.json",
+        ))
+        .unwrap();
+        assert_eq!(b.message, "pong");
+        assert_eq!(b.room_id, "tgrk5CZKgYGiSSqXpuKK39zoewTkdacidH");
+        assert!(b.room_name.is_empty());
+        assert!(b.is_direct_message());
+        assert!(!b.is_thread_message());
+
+        let json = serde_json::json!({"title": "alice", "text": "Started a call",
+            "payload": {"_id": "msg1", "rid": "roomid", "type": "d",
+                        "sender": {"_id": "uD5Aq8ePJAfK3N3Ps", "username": "alice"},
+                        "message": {"msg": "", "t": "videoconf"}}});
+        let b = NotificationInfo::parse_notification(&json).unwrap();
+        assert_eq!(b.notification_type, NotificationType::ConferenceCall);
+
+        assert!(
+            NotificationInfo::parse_notification(&serde_json::json!({"title": "foo"})).is_none()
+        );
     }
 }
@@ -3,5 +3,7 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
+pub mod ownuserinfo;
+pub mod ownusermanager;
 pub mod ownuserpreferences;
 pub mod servicepassword;
@@ -0,0 +1,271 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+use crate::user::{Status, UserEmailsInfo};
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct OwnUserSettings {
+    pub preferences: OwnUserPreferences,
+}
+
+// Logged user, loaded by /me and updated by "userData" stream.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct OwnUser {
+    #[serde(rename = "_id")]
+    pub user_id: String,
+    pub username: String,
+    pub name: String,
+    #[serde(rename = "nickname")]
+    pub nick_name: String,
+    pub bio: String,
+    #[serde(rename = "statusText")]
+    pub status_text: String,
+    pub status: Status,
+    #[serde(rename = "statusDefault")]
+    pub status_default: Status,
+    pub active: bool,
+    pub roles: Vec<String>,
+    pub emails: Vec<UserEmailsInfo>,
+    #[serde(rename = "utcOffset")]
+    pub utc_offset: f32,
+    #[serde(rename = "avatarETag")]
+    pub avatar_etag: String,
+    pub settings: OwnUserSettings,
+
+    // Json used to apply partial updates
+    #[serde(skip)]
+    data: serde_json::Value,
+}
+
+impl Default for OwnUser {
+    fn default() -> Self {
+        OwnUser::new()
+    }
+}
+
+impl OwnUser {
+    pub fn new() -> Self {
+        OwnUser {
+            user_id: String::default(),
+            username: String::default(),
+            name: String::default(),
+            nick_name: String::default(),
+            bio: String::default(),
+            status_text: String::default(),
+            status: Status::Unknown,
+            status_default: Status::Unknown,
+            active: false,
+            roles: Vec::new(),
+            emails: Vec::new(),
+            utc_offset: 0.0,
+            avatar_etag: String::default(),
+            settings: OwnUserSettings::default(),
+            data: serde_json::Value::Object(serde_json::Map::new()),
+        }
+    }
+
+    // Parse /me result.
+    pub fn parse_elements(&mut self, json: &str) {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(json) {
+            self.parse_value(value);
+        }
+    }
+
+    fn parse_value(&mut self, value: serde_json::Value) {
+        if let Ok(mut user) = serde_json::from_value::<OwnUser>(value.clone()) {
+            user.data = value;
+            *self = user;
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.user_id.is_empty()
+    }
+
+    pub fn preferences(&self) -> &OwnUserPreferences {
+        &self.settings.preferences
+    }
+
+    pub fn email(&self) -> &str {
+        self.emails
+            .first()
+            .map(|email| email.address.as_str())
+            .unwrap_or_default()
+    }
+
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.username
+        } else {
+            &self.name
+        }
+    }
+
+    // "userData" stream: [{"type": "updated", "id": …, "diff": {…}, "unset": {…}}]
+    // Keys can be dotted paths, e.g. "settings.preferences.enableAutoAway".
+    pub fn apply_user_data(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        let mut data = self.data.clone();
+        let mut changed = false;
+        for change in args {
+            if change["type"].as_str() != Some("updated") {
+                continue;
+            }
+            if let Some(id) = change["id"].as_str()
+                && !self.user_id.is_empty()
+                && id != self.user_id
+            {
+                continue;
+            }
+            // Older servers send full values in "data"
+            for field in ["diff", "data"] {
+                if let Some(diff) = change[field].as_object() {
+                    for (key, value) in diff {
+                        set_path(&mut data, key, value.clone());
+                        changed = true;
+                    }
+                }
+            }
+            if let Some(unset) = change["unset"].as_object() {
+                for key in unset.keys() {
+                    remove_path(&mut data, key);
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            self.parse_value(data);
+        }
+    }
+}
+
+fn set_path(data: &mut serde_json::Value, path: &str, value: serde_json::Value) {
+    let mut current = data;
+    for key in path.split('.') {
+        if !current.is_object() {
+            *current = serde_json::Value::Object(serde_json::Map::new());
+        }
+        current = current
+            .as_object_mut()
+            .unwrap()
+            .entry(key)
+            .or_insert(serde_json::Value::Null);
+    }
+    *current = value;
+}
+
+fn remove_path(data: &mut serde_json::Value, path: &str) {
+    let mut current = data;
+    let mut keys: Vec<&str> = path.split('.').collect();
+    let Some(last) = keys.pop() else {
+        return;
+    };
+    for key in keys {
+        let Some(next) = current.get_mut(key) else {
+            return;
+        };
+        current = next;
+    }
+    if let Some(object) = current.as_object_mut() {
+        object.remove(last);
+    }
+}
+
+/* Debug output for OwnUser */
+impl fmt::Display for OwnUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "(user_id: {}, username: {}, name: {}, nick_name: {}, status: {:?}, status_text: {})",
+            self.user_id, self.username, self.name, self.nick_name, self.status, self.status_text
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ownuser::ownuserinfo::OwnUser;
+    use crate::user::Status;
+
+    fn parse(filename: &str) -> OwnUser {
+        let mut user = OwnUser::new();
+        user.parse_elements(&std::fs::read_to_string(filename).expect("Failed to open file"));
+        user
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let user = OwnUser::new();
+        assert!(!user.is_valid());
+        assert!(user.username.is_empty());
+        assert!(user.email().is_empty());
+        assert_eq!(user.status, Status::Unknown);
+        assert!(user.preferences().enable_auto_away);
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let user = parse("src/data/ownuser/me.json");
+        assert!(user.is_valid());
+        assert_eq!(user.user_id, "XQZAk3998f9hSNwh");
+        assert_eq!(user.username, "laurent");
+        assert_eq!(user.display_name(), "Laurent Montel");
+        assert_eq!(user.nick_name, "montel");
+        assert_eq!(user.bio, "KDE developer");
+        assert_eq!(user.status_text, "Hacking");
+        assert_eq!(user.status, Status::Online);
+        assert_eq!(user.status_default, Status::Online);
+        assert_eq!(user.email(), "montel@kde.org");
+        assert_eq!(user.roles, vec!["user", "admin"]);
+        assert_eq!(user.avatar_etag, "8a6AwTsJyzGFt4K6");
+        assert_eq!(user.preferences().idle_time_limit, 300);
+        assert!(!user.preferences().enable_auto_away);
+        assert_eq!(user.preferences().highlight_words, vec!["ruqola"]);
+    }
+
+    #[test]
+    fn test_apply_user_data() {
+        let mut user = parse("src/data/ownuser/me.json");
+        user.apply_user_data(&serde_json::json!([
+            {"type": "updated", "id": "XQZAk3998f9hSNwh",
+             "diff": {"nickname": "laurent_m", "statusText": "On vacation", "status": "away",
+                      "settings.preferences.enableAutoAway": true},
+             "unset": {"bio": 1}}
+        ]));
+        assert_eq!(user.nick_name, "laurent_m");
+        assert_eq!(user.status_text, "On vacation");
+        assert_eq!(user.status, Status::Away);
+        assert!(user.bio.is_empty());
+        assert!(user.preferences().enable_auto_away);
+        // Other preferences are kept
+        assert_eq!(user.preferences().idle_time_limit, 300);
+        assert_eq!(user.username, "laurent");
+
+        // Other user or other type are ignored
+        user.apply_user_data(&serde_json::json!([
+            {"type": "updated", "id": "otheruser", "diff": {"nickname": "foo"}},
+            {"type": "removed", "id": "XQZAk3998f9hSNwh"}
+        ]));
+        assert_eq!(user.nick_name, "laurent_m");
+        assert!(user.is_valid());
+    }
+
+    #[test]
+    fn test_apply_user_data_avatar() {
+        let mut user = parse("src/data/ownuser/me.json");
+        user.apply_user_data(&serde_json::json!([
+            {"type": "updated", "id": "XQZAk3998f9hSNwh", "diff": {"avatarETag": "newEtag"}}
+        ]));
+        assert_eq!(user.avatar_etag, "newEtag");
+    }
+}
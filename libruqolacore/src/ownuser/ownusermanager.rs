@@ -0,0 +1,253 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ownuser::ownuserinfo::OwnUser;
+use crate::user::Status;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use libddpapi::authenticationutils::convert_password_ssha256;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, AvatarSource, OwnMethod, UsersDeleteOwnAccountMethod, UsersGetStatusMethod,
+    UsersResetAvatarMethod, UsersSetAvatarMethod, UsersSetPreferencesInfo,
+    UsersSetPreferencesMethod, UsersSetStatusMethod, UsersUpdateOwnBasicInfoMethod,
+};
+use serde::Deserialize;
+
+// Profile values changed by user, unset values are not modified.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OwnUserBasicInfo {
+    pub name: Option<String>,
+    pub username: Option<String>,
+    pub nick_name: Option<String>,
+    pub bio: Option<String>,
+    pub status_text: Option<String>,
+    pub email: Option<String>,
+    // Needed to change email or password
+    pub current_password: Option<String>,
+    pub new_password: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct OwnUserStatus {
+    #[serde(default)]
+    pub status: Status,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default, rename = "connectionStatus")]
+    pub connection_status: Status,
+}
+
+pub fn status_name(status: &Status) -> Option<&'static str> {
+    match status {
+        Status::Online => Some("online"),
+        Status::Busy => Some("busy"),
+        Status::Away => Some("away"),
+        Status::Offline => Some("offline"),
+        Status::Unknown => None,
+    }
+}
+
+// Own user and REST API calls to modify it.
+#[derive(Clone)]
+pub struct OwnUserManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub own_user: OwnUser,
+}
+
+impl Default for OwnUserManager {
+    fn default() -> Self {
+        OwnUserManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl OwnUserManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        OwnUserManager {
+            settings,
+            server_url,
+            own_user: OwnUser::new(),
+        }
+    }
+
+    pub fn apply_user_data(&mut self, value: &serde_json::Value) {
+        self.own_user.apply_user_data(value);
+    }
+
+    pub async fn load(&mut self) -> Result<(), Error> {
+        let result = OwnMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+        }
+        .call()
+        .await?;
+        self.own_user.parse_elements(&result);
+        Ok(())
+    }
+
+    // Changes are received by "userData" stream.
+    pub async fn update_basic_info(&self, info: &OwnUserBasicInfo) -> Result<(), Error> {
+        UsersUpdateOwnBasicInfoMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            email: info.email.clone(),
+            name: info.name.clone(),
+            username: info.username.clone(),
+            nickname: info.nick_name.clone(),
+            bio: info.bio.clone(),
+            status_text: info.status_text.clone(),
+            current_password: info
+                .current_password
+                .as_ref()
+                .map(|password| convert_password_ssha256(password.clone())),
+            new_password: info.new_password.clone(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_preferences(&self, preferences: UsersSetPreferencesInfo) -> Result<(), Error> {
+        UsersSetPreferencesMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            user_id: self.own_user.user_id.clone(),
+            preferences,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_avatar_url(&self, url: &str) -> Result<(), Error> {
+        UsersSetAvatarMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            avatar: AvatarSource::Url(url.to_string()),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn upload_avatar(
+        &self,
+        file_name: &str,
+        mime_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Error> {
+        UsersSetAvatarMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            avatar: AvatarSource::Image {
+                file_name: file_name.to_string(),
+                mime_type: mime_type.to_string(),
+                data,
+            },
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn reset_avatar(&self) -> Result<(), Error> {
+        UsersResetAvatarMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            user_id: None,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_status(&self, status: Status, message: Option<String>) -> Result<(), Error> {
+        UsersSetStatusMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            status: status_name(&status).map(|name| name.to_string()),
+            message,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn status(&self) -> Result<OwnUserStatus, Error> {
+        let result = UsersGetStatusMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            user_id: None,
+        }
+        .call()
+        .await?;
+        parse_status(&result).ok_or(Error::JsonDecode(result))
+    }
+
+    pub async fn delete_own_account(
+        &self,
+        password: &str,
+        confirm_relinquish: bool,
+    ) -> Result<(), Error> {
+        UsersDeleteOwnAccountMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            password: convert_password_ssha256(password.to_string()),
+            confirm_relinquish,
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+}
+
+// Parse users.getStatus result.
+pub fn parse_status(json: &str) -> Option<OwnUserStatus> {
+    serde_json::from_str::<OwnUserStatus>(json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ownuser::ownusermanager::{OwnUserManager, parse_status, status_name};
+    use crate::user::Status;
+
+    #[test]
+    fn test_default_values() {
+        let m = OwnUserManager::default();
+        assert!(!m.own_user.is_valid());
+        assert!(m.server_url.is_empty());
+    }
+
+    #[test]
+    fn test_status_name() {
+        assert_eq!(status_name(&Status::Busy), Some("busy"));
+        assert_eq!(status_name(&Status::Online), Some("online"));
+        assert!(status_name(&Status::Unknown).is_none());
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let status = parse_status(
+            r#"{"_id":"XQZAk3998f9hSNwh","message":"Hacking","connectionStatus":"online","status":"busy","success":true}"#,
+        )
+        .unwrap();
+        assert_eq!(status.status, Status::Busy);
+        assert_eq!(status.message, "Hacking");
+        assert!(parse_status("foo").is_none());
+    }
+
+    #[test]
+    fn test_apply_user_data() {
+        let mut m = OwnUserManager::default();
+        m.own_user.parse_elements(
+            &std::fs::read_to_string("src/data/ownuser/me.json").expect("Failed to open file"),
+        );
+        m.apply_user_data(&serde_json::json!([
+            {"type": "updated", "id": "XQZAk3998f9hSNwh", "diff": {"name": "Laurent M."}}
+        ]));
+        assert_eq!(m.own_user.name, "Laurent M.");
+    }
+}
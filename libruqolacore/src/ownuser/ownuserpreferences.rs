@@ -29,6 +29,7 @@ pub enum RoomListDisplay {
 }
 
 #[derive(Clone, Deserialize, Debug, PartialEq)]
+#[serde(default)]
 pub struct OwnUserPreferences {
     #[serde(rename = "highlights")]
     pub highlight_words: Vec<String>,
@@ -36,7 +37,6 @@ pub struct OwnUserPreferences {
     pub email_notification_mode: String,
     #[serde(rename = "desktopNotifications")]
     pub desktop_notifications: String,
-    #[serde(default)]
     #[serde(rename = "pushNotifications")]
     pub push_notifications: String,
     #[serde(rename = "newMessageNotification")]
@@ -49,19 +49,35 @@ pub struct OwnUserPreferences {
     pub room_list_display: RoomListDisplay,
 
     #[serde(rename = "idleTimeLimit")]
-    pub idle_time_limit: i64, // -1 TOTO
+    pub idle_time_limit: i64,
     #[serde(rename = "notificationsSoundVolume")]
-    pub notifications_sound_volume: i64, // -1 TODO
+    pub notifications_sound_volume: i64,
+    #[serde(rename = "convertAsciiEmoji")]
     pub convert_ascii_emoji: bool,
-    // Fix default value pub mUseEmojis = true;
+    #[serde(rename = "useEmojis")]
+    pub use_emojis: bool,
+    #[serde(rename = "hideRoles")]
     pub hide_roles: bool,
-    // Fix default value pub mDisplayAvatars = true;
+    #[serde(rename = "displayAvatars")]
+    pub display_avatars: bool,
+    #[serde(rename = "enableAutoAway")]
     pub enable_auto_away: bool,
+    #[serde(rename = "sidebarShowUnread")]
     pub show_unread: bool,
+    #[serde(rename = "sidebarDisplayAvatar")]
     pub show_room_avata: bool,
+    #[serde(rename = "sidebarShowFavorites")]
     pub show_favorite: bool,
+    #[serde(rename = "receiveLoginDetectionEmail")]
     pub receive_login_detection_email: bool,
+    #[serde(rename = "muteFocusedConversations")]
     pub mute_focused_conversations: bool,
+    #[serde(rename = "autoImageLoad")]
+    pub auto_image_load: bool,
+    #[serde(rename = "collapseMediaByDefault")]
+    pub collapse_media_by_default: bool,
+    // Interface language ("fr", "pt-BR"), empty for server default
+    pub language: String,
 }
 
 impl Default for OwnUserPreferences {
@@ -71,17 +87,41 @@ impl Default for OwnUserPreferences {
 }
 
 impl OwnUserPreferences {
+    // Server default values
     pub fn new() -> Self {
         OwnUserPreferences {
-            // TODO
-            ..Default::default()
+            highlight_words: Vec::new(),
+            email_notification_mode: String::default(),
+            desktop_notifications: String::default(),
+            push_notifications: String::default(),
+            new_message_notification: String::default(),
+            new_room_notification: String::default(),
+            room_list_sort_order: RoomListSortOrder::Unknown,
+            room_list_display: RoomListDisplay::Unknown,
+            idle_time_limit: -1,
+            notifications_sound_volume: -1,
+            convert_ascii_emoji: true,
+            use_emojis: true,
+            hide_roles: false,
+            display_avatars: true,
+            enable_auto_away: true,
+            show_unread: false,
+            show_room_avata: true,
+            show_favorite: true,
+            receive_login_detection_email: true,
+            mute_focused_conversations: true,
+            auto_image_load: true,
+            collapse_media_by_default: false,
+            language: String::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+    use crate::ownuser::ownuserpreferences::{
+        OwnUserPreferences, RoomListDisplay, RoomListSortOrder,
+    };
     use std::fs::File;
 
     // Using by test
@@ -95,8 +135,24 @@ mod tests {
         {
             // Load file
             let preferences = parse("src/data/ownuserpreferences/ownuserpreferences1.json");
-            //assert_eq!(permission.update.len(), 1147);
-            //assert_eq!(permission.remove.len(), 0);
+            assert!(preferences.highlight_words.is_empty());
+            assert_eq!(preferences.desktop_notifications, "mentions");
+            assert_eq!(
+                preferences.room_list_sort_order,
+                RoomListSortOrder::ByLastMessage
+            );
+            assert_eq!(preferences.room_list_display, RoomListDisplay::Medium);
+            assert_eq!(preferences.idle_time_limit, 300);
+            assert_eq!(preferences.notifications_sound_volume, 100);
+            assert!(!preferences.convert_ascii_emoji);
+            assert!(!preferences.use_emojis);
+            assert!(!preferences.enable_auto_away);
+            assert!(preferences.auto_image_load);
+            assert!(!preferences.collapse_media_by_default);
+            assert!(preferences.show_favorite);
+            assert_eq!(preferences.language, "fr");
+            // Missing value uses default
+            assert!(preferences.receive_login_detection_email);
         }
     }
 }
@@ -0,0 +1,516 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+// Self-service account creation and password reset, before login.
+
+use crate::ruqolaserverconfig::RuqolaServerConfig;
+use crate::ruqolaserverconfigpassword::PasswordSettingChecks;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{APIMethod, ForgotPasswordMethod, UsersRegisterMethod};
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RegistrationError {
+    RegistrationDisabled,
+    SecretUrlRequired,
+    // Admin must approve account, a reason is needed
+    ReasonRequired,
+    NameRequired,
+    InvalidUsername,
+    InvalidEmail,
+    PasswordMismatch,
+    PasswordPolicy(PasswordSettingChecks),
+    PasswordResetDisabled,
+    RestApi(Error),
+    InvalidResponse(String),
+}
+
+impl From<Error> for RegistrationError {
+    fn from(error: Error) -> Self {
+        RegistrationError::RestApi(error)
+    }
+}
+
+/* Debug output for RegistrationError */
+impl fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::RegistrationDisabled => write!(f, "Registration is disabled"),
+            RegistrationError::SecretUrlRequired => write!(f, "Registration needs a secret url"),
+            RegistrationError::ReasonRequired => write!(f, "Reason to join is required"),
+            RegistrationError::NameRequired => write!(f, "Name is required"),
+            RegistrationError::InvalidUsername => write!(f, "Invalid username"),
+            RegistrationError::InvalidEmail => write!(f, "Invalid email"),
+            RegistrationError::PasswordMismatch => write!(f, "Passwords don't match"),
+            RegistrationError::PasswordPolicy(checks) => {
+                write!(f, "Password policy not met: {:#b}", checks.bits())
+            }
+            RegistrationError::PasswordResetDisabled => write!(f, "Password reset is disabled"),
+            RegistrationError::RestApi(error) => write!(f, "Rest api error: {:?}", error),
+            RegistrationError::InvalidResponse(response) => {
+                write!(f, "Invalid response: {}", response)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RegistrationInfo {
+    pub name: String,
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub confirm_password: String,
+    pub secret_url: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RegistrationResult {
+    pub user_id: String,
+    pub username: String,
+    // User must click on link sent by email before login
+    pub email_verification_required: bool,
+    // Account is inactive until an admin approves it
+    pub approval_required: bool,
+}
+
+#[derive(Deserialize)]
+struct RegisteredEmail {
+    #[serde(default)]
+    verified: bool,
+}
+
+#[derive(Deserialize)]
+struct RegisteredUser {
+    #[serde(rename = "_id")]
+    identifier: String,
+    #[serde(default)]
+    username: String,
+    #[serde(default = "default_active")]
+    active: bool,
+    #[serde(default)]
+    emails: Vec<RegisteredEmail>,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+struct RegisterResult {
+    user: RegisteredUser,
+}
+
+// Default server username validation: [0-9a-zA-Z-_.]+
+pub fn is_valid_username(username: &str) -> bool {
+    !username.is_empty()
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+pub fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .split_once('.')
+            .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+        && !email.contains(char::is_whitespace)
+}
+
+// Latin letter with diacritic => ASCII, "Élodie" gives "elodie".
+fn fold_diacritic(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+// Suggest a username from name, or from email when name is empty.
+// users.getUsernameSuggestion needs a logged user, so it can't be used here.
+// Words with letters that can't be transliterated are skipped.
+pub fn suggest_username(name: &str, email: &str) -> String {
+    let source = if name.trim().is_empty() {
+        email.split('@').next().unwrap_or_default()
+    } else {
+        name
+    };
+    let mut words = Vec::new();
+    'words: for word in source.split(|c: char| c.is_whitespace() || c == '.') {
+        let mut folded = String::new();
+        for c in word.chars().flat_map(char::to_lowercase) {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                folded.push(c);
+            } else if let Some(ascii) = fold_diacritic(c) {
+                folded.push_str(ascii);
+            } else if c.is_alphanumeric() {
+                continue 'words;
+            }
+        }
+        if !folded.is_empty() {
+            words.push(folded);
+        }
+    }
+    words.join(".")
+}
+
+pub fn parse_register_result(json: &str) -> Option<RegistrationResult> {
+    let result = serde_json::from_str::<RegisterResult>(json).ok()?;
+    Some(RegistrationResult {
+        user_id: result.user.identifier,
+        username: result.user.username,
+        email_verification_required: result
+            .user
+            .emails
+            .first()
+            .is_some_and(|email| !email.verified),
+        approval_required: !result.user.active,
+    })
+}
+
+#[derive(Clone)]
+pub struct RegistrationFlow {
+    pub server_config: RuqolaServerConfig,
+}
+
+impl Default for RegistrationFlow {
+    fn default() -> Self {
+        RegistrationFlow::new(String::default())
+    }
+}
+
+impl RegistrationFlow {
+    pub fn new(server_url: String) -> Self {
+        RegistrationFlow {
+            server_config: RuqolaServerConfig::new(AuthenticationType::NoAuthRequired, server_url),
+        }
+    }
+
+    // Load registration settings and password policy.
+    pub async fn load_settings(&mut self) -> Result<(), RegistrationError> {
+        Ok(self.server_config.load_public_settings().await?)
+    }
+
+    // Public form, or secret url form when user has the url.
+    pub fn registration_enabled(&self) -> bool {
+        self.server_config.registration_form() != "Disabled"
+    }
+
+    pub fn password_reset_enabled(&self) -> bool {
+        self.server_config.password_reset_enabled()
+    }
+
+    // Requirements to show next to password field.
+    pub fn password_requirements(&self) -> Vec<String> {
+        let policy = self.server_config.password_settings();
+        policy
+            .enabled_checks()
+            .iter()
+            .map(|check| policy.message(check))
+            .collect()
+    }
+
+    pub fn suggest_username(&self, info: &RegistrationInfo) -> String {
+        suggest_username(&info.name, &info.email)
+    }
+
+    pub fn validate(&self, info: &RegistrationInfo) -> Result<(), RegistrationError> {
+        match self.server_config.registration_form() {
+            "Disabled" => return Err(RegistrationError::RegistrationDisabled),
+            "Secret URL" if info.secret_url.as_deref().unwrap_or_default().is_empty() => {
+                return Err(RegistrationError::SecretUrlRequired);
+            }
+            _ => {}
+        }
+        if self.server_config.require_name_for_signup() && info.name.trim().is_empty() {
+            return Err(RegistrationError::NameRequired);
+        }
+        if !is_valid_username(&info.username) {
+            return Err(RegistrationError::InvalidUsername);
+        }
+        if !is_valid_email(&info.email) {
+            return Err(RegistrationError::InvalidEmail);
+        }
+        if info.password != info.confirm_password {
+            return Err(RegistrationError::PasswordMismatch);
+        }
+        let failed = self
+            .server_config
+            .password_settings()
+            .validate(&info.password);
+        if !failed.is_empty() {
+            return Err(RegistrationError::PasswordPolicy(failed));
+        }
+        if self.server_config.manually_approve_new_users()
+            && info.reason.as_deref().unwrap_or_default().trim().is_empty()
+        {
+            return Err(RegistrationError::ReasonRequired);
+        }
+        Ok(())
+    }
+
+    pub async fn register(
+        &self,
+        info: &RegistrationInfo,
+    ) -> Result<RegistrationResult, RegistrationError> {
+        self.validate(info)?;
+        let result = UsersRegisterMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: self.server_config.server_url.clone(),
+            username: info.username.clone(),
+            email: info.email.clone(),
+            password: info.password.clone(),
+            name: info.name.clone(),
+            secret_url: info.secret_url.clone(),
+            reason: info.reason.clone(),
+        }
+        .call()
+        .await?;
+        let mut registration = parse_register_result(&result)
+            .ok_or_else(|| RegistrationError::InvalidResponse(result.clone()))?;
+        registration.email_verification_required = registration.email_verification_required
+            && self.server_config.email_verification_required();
+        Ok(registration)
+    }
+
+    // Server always answers success, even for an unknown email.
+    pub async fn forgot_password(&self, email: &str) -> Result<(), RegistrationError> {
+        if !self.password_reset_enabled() {
+            return Err(RegistrationError::PasswordResetDisabled);
+        }
+        if !is_valid_email(email) {
+            return Err(RegistrationError::InvalidEmail);
+        }
+        ForgotPasswordMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: self.server_config.server_url.clone(),
+            email: email.to_string(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registrationflow::{
+        RegistrationError, RegistrationFlow, RegistrationInfo, is_valid_email, is_valid_username,
+        parse_register_result, suggest_username,
+    };
+    use crate::ruqolaserverconfigpassword::PasswordSettingCheck;
+
+    fn generate_flow() -> RegistrationFlow {
+        let mut flow = RegistrationFlow::default();
+        flow.server_config.parse_settings(
+            &std::fs::read_to_string("src/data/registrationflow/settings.json")
+                .expect("Failed to open file"),
+        );
+        flow
+    }
+
+    fn generate_info() -> RegistrationInfo {
+        RegistrationInfo {
+            name: "Foo Bla".to_string(),
+            username: "foo.bla".to_string(),
+            email: "foo@kde.org".to_string(),
+            password: "Secret-42".to_string(),
+            confirm_password: "Secret-42".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_valid_username() {
+        assert!(is_valid_username("foo.bla-42_x"));
+        assert!(!is_valid_username(""));
+        assert!(!is_valid_username("foo bla"));
+        assert!(!is_valid_username("@foo"));
+    }
+
+    #[test]
+    fn test_is_valid_email() {
+        assert!(is_valid_email("foo@kde.org"));
+        assert!(!is_valid_email("foo"));
+        assert!(!is_valid_email("@kde.org"));
+        assert!(!is_valid_email("foo@kde"));
+        assert!(!is_valid_email("foo@bla@kde.org"));
+        assert!(!is_valid_email("foo bar@kde.org"));
+    }
+
+    #[test]
+    fn test_suggest_username() {
+        assert_eq!(suggest_username("Laurent Montel", ""), "laurent.montel");
+        assert_eq!(suggest_username("  Élodie  M. ", ""), "elodie.m");
+        assert_eq!(
+            suggest_username("Jürgen Weiß-Łukasz", ""),
+            "jurgen.weiss-lukasz"
+        );
+        assert_eq!(suggest_username("Дмитрий Laurent", ""), "laurent");
+        assert_eq!(suggest_username("", "foo.bla@kde.org"), "foo.bla");
+        assert!(suggest_username("", "").is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let flow = generate_flow();
+        assert!(flow.registration_enabled());
+        assert!(flow.validate(&generate_info()).is_ok());
+
+        let info = RegistrationInfo {
+            name: String::default(),
+            ..generate_info()
+        };
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::NameRequired)
+        ));
+        let info = RegistrationInfo {
+            username: "foo bla".to_string(),
+            ..generate_info()
+        };
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::InvalidUsername)
+        ));
+        let info = RegistrationInfo {
+            email: "foo".to_string(),
+            ..generate_info()
+        };
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::InvalidEmail)
+        ));
+        let info = RegistrationInfo {
+            confirm_password: "Secret-43".to_string(),
+            ..generate_info()
+        };
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::PasswordMismatch)
+        ));
+        let info = RegistrationInfo {
+            password: "secret".to_string(),
+            confirm_password: "secret".to_string(),
+            ..generate_info()
+        };
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::PasswordPolicy(checks)) if checks.contains(PasswordSettingCheck::MinLength)
+        ));
+    }
+
+    #[test]
+    fn test_validate_registration_form() {
+        let mut flow = generate_flow();
+        flow.server_config
+            .apply_settings_changed(&serde_json::json!([
+                "updated",
+                {"_id": "Accounts_RegistrationForm", "value": "Secret URL"}
+            ]));
+        assert!(flow.registration_enabled());
+        assert!(matches!(
+            flow.validate(&generate_info()),
+            Err(RegistrationError::SecretUrlRequired)
+        ));
+        let info = RegistrationInfo {
+            secret_url: Some("abc123".to_string()),
+            ..generate_info()
+        };
+        assert!(flow.validate(&info).is_ok());
+
+        flow.server_config
+            .apply_settings_changed(&serde_json::json!([
+                "updated",
+                {"_id": "Accounts_RegistrationForm", "value": "Disabled"}
+            ]));
+        assert!(!flow.registration_enabled());
+        assert!(matches!(
+            flow.validate(&info),
+            Err(RegistrationError::RegistrationDisabled)
+        ));
+    }
+
+    #[test]
+    fn test_validate_manual_approval() {
+        let mut flow = generate_flow();
+        flow.server_config
+            .apply_settings_changed(&serde_json::json!([
+                "updated",
+                {"_id": "Accounts_ManuallyApproveNewUsers", "value": true}
+            ]));
+        assert!(matches!(
+            flow.validate(&generate_info()),
+            Err(RegistrationError::ReasonRequired)
+        ));
+        let info = RegistrationInfo {
+            reason: Some("KDE contributor".to_string()),
+            ..generate_info()
+        };
+        assert!(flow.validate(&info).is_ok());
+    }
+
+    #[test]
+    fn test_password_requirements() {
+        let flow = generate_flow();
+        assert_eq!(
+            flow.password_requirements(),
+            vec!["At least 8 characters", "At least one number"]
+        );
+    }
+
+    #[test]
+    fn test_parse_register_result() {
+        let result = parse_register_result(
+            &std::fs::read_to_string("src/data/registrationflow/register.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        assert_eq!(result.user_id, "pCM7bXnJ5kd9sGqTr");
+        assert_eq!(result.username, "foo.bla");
+        assert!(result.email_verification_required);
+        assert!(!result.approval_required);
+        assert!(parse_register_result("{}").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_invalid_email() {
+        let flow = generate_flow();
+        assert!(matches!(
+            flow.forgot_password("foo").await,
+            Err(RegistrationError::InvalidEmail)
+        ));
+    }
+}
@@ -1,22 +1,68 @@
-use crate::rocketchataccountmanager::CommandToBackend;
+use crate::rocketchataccountmanager::{CommandToBackend, CommandToGui};
 /*
 * SPDX-FileCopyrightText: 2023-2026 Laurent Montel <laurent.montel@kdab.com>
 *
 * SPDX-License-Identifier: LGPL-2.0-or-later
 */
+use crate::attachments::attachmentimagecache::AttachmentImageCache;
+use crate::attachments::attachmentviewmodel::{self, AttachmentViewModel};
+use crate::autotranslate::autotranslatemanager::AutoTranslateManager;
+use crate::discussions::discussion::Discussion;
+use crate::discussions::discussionsmanager::DiscussionsManager;
+use crate::emoticons::customemojimanager::CustomEmojiManager;
+use crate::emoticons::unicodeemojimanager::UnicodeEmojiManager;
+use crate::manager::permissionsmanager::PermissionsManager;
+use crate::markdown::htmlrenderer;
+use crate::markdown::rendercontext::MarkdownRenderContext;
+use crate::markdown::styledspanrenderer::{self, StyledSpan};
+use crate::markdown::syntaxhighlighter::SyntaxHighlighter;
+use crate::messageinfo::MessageInfo;
+use crate::notification::notificationmanager::NotificationManager;
+use crate::notification::notificationsink::NotificationAction;
+use crate::ownuser::ownusermanager::OwnUserManager;
+use crate::roles::rolesmanager::RolesManager;
 use crate::rooms::Rooms;
+use crate::ruqolaserverconfig::RuqolaServerConfig;
+use crate::status::presencemanager::PresenceManager;
+use crate::typing::receivetypingnotificationmanager::ReceiveTypingNotificationManager;
+use crate::typing::typingnotification::TypingNotification;
+use crate::uikit::uikitmanager::UiKitManager;
+use crate::uikit::uikitrenderer::{self, UiKitNode};
+use crate::urlpreview::previewcard::PreviewCard;
+use crate::urlpreview::urlpreviewmanager::UrlPreviewManager;
 use crate::{
     rocketchataccountsettings::RocketChatAccountSettings, rocketchatbackend::RocketaccountBackend,
 };
+use libauthenticationbase::authenticationsettings::{AuthSettings, AuthenticationType};
 use libddpapi::ddpclient;
-use libddpapi::ddpclient::Event;
+use libddpapi::ddpclient::{Event, TypingInfo};
 use librocketchatrestapi::methods;
+use std::path::Path;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 #[derive(Clone)]
 pub struct RocketChatAccount {
     pub account_settings: RocketChatAccountSettings,
     pub account_backend: RocketaccountBackend,
     pub rooms: Rooms,
+    pub permissions_manager: PermissionsManager,
+    pub roles_manager: RolesManager,
+    pub server_config: RuqolaServerConfig,
+    pub own_user_manager: OwnUserManager,
+    pub presence_manager: PresenceManager,
+    pub receive_typing_notification_manager: ReceiveTypingNotificationManager,
+    pub typing_notification: TypingNotification,
+    pub notification_manager: NotificationManager,
+    pub custom_emoji_manager: CustomEmojiManager,
+    pub unicode_emoji_manager: UnicodeEmojiManager,
+    pub syntax_highlighter: SyntaxHighlighter,
+    pub url_preview_manager: UrlPreviewManager,
+    pub attachment_image_cache: AttachmentImageCache,
+    pub uikit_manager: UiKitManager,
+    pub discussions_manager: DiscussionsManager,
+    pub auto_translate_manager: AutoTranslateManager,
+    // Rendered messages are sent to client
+    pub gui_sender: Option<mpsc::UnboundedSender<CommandToGui>>,
     // pub ddpclient: libddpapi::ddpclient,
     // Store rooms + messages
 }
@@ -31,14 +77,142 @@ impl RocketChatAccount {
             account_settings: RocketChatAccountSettings::new(),
             account_backend: RocketaccountBackend::new(),
             rooms: Rooms::new(),
+            permissions_manager: PermissionsManager::default(),
+            roles_manager: RolesManager::default(),
+            server_config: RuqolaServerConfig::default(),
+            own_user_manager: OwnUserManager::default(),
+            presence_manager: PresenceManager::default(),
+            receive_typing_notification_manager: ReceiveTypingNotificationManager::default(),
+            typing_notification: TypingNotification::default(),
+            notification_manager: NotificationManager::default(),
+            custom_emoji_manager: CustomEmojiManager::default(),
+            unicode_emoji_manager: UnicodeEmojiManager::default(),
+            syntax_highlighter: SyntaxHighlighter::default(),
+            url_preview_manager: UrlPreviewManager::default(),
+            attachment_image_cache: AttachmentImageCache::default(),
+            uikit_manager: UiKitManager::default(),
+            discussions_manager: DiscussionsManager::default(),
+            auto_translate_manager: AutoTranslateManager::default(),
+            gui_sender: None,
             //ddpclient: libddpapi::ddpclient::
         }
     }
     pub fn load_settings(&mut self, file_name: String) {
-        self.account_settings.load_settings(file_name)
+        self.url_preview_manager.load_settings(&file_name);
+        self.unicode_emoji_manager.load_settings(&file_name);
+        self.account_settings.load_settings(file_name);
+        self.notification_manager.account_name = self.account_settings.account_name.clone();
     }
-    pub fn write_settings(&mut self, file_name: String) {
-        self.account_settings.write_settings(file_name)
+    pub fn write_settings(&self, file_name: String) {
+        // Account settings rewrite file, other sections must be written after
+        self.account_settings.write_settings(file_name.clone());
+        if let Err(e) = self.url_preview_manager.write_settings(&file_name) {
+            println!("Impossible to write url preview settings {:?}", e);
+        }
+        if let Err(e) = self.unicode_emoji_manager.write_settings(&file_name) {
+            println!("Impossible to write emoji settings {:?}", e);
+        }
+    }
+
+    // Downloaded images are stored by account, servers can use same names.
+    pub fn set_cache_directory(&mut self, directory: &Path) {
+        self.custom_emoji_manager.image_cache.cache_directory = directory.join("emoji");
+        self.url_preview_manager.image_cache.cache_directory = directory.join("previews");
+        self.attachment_image_cache.cache_directory = directory.join("attachments");
+    }
+
+    // Unicode shortcodes, and ascii emoticons when enabled in user preferences.
+    pub fn convert_emojis(&self, text: &str) -> String {
+        let convert_ascii_emoji = self
+            .own_user_manager
+            .own_user
+            .preferences()
+            .convert_ascii_emoji;
+        self.unicode_emoji_manager
+            .convert_text(text, convert_ascii_emoji)
+    }
+
+    pub fn message_render_context<'a>(
+        &'a self,
+        message: &'a MessageInfo,
+    ) -> MarkdownRenderContext<'a> {
+        MarkdownRenderContext {
+            own_user_name: &self.own_user_manager.own_user.username,
+            unicode_emojis: Some(&self.unicode_emoji_manager),
+            custom_emojis: Some(&self.custom_emoji_manager),
+            highlighter: Some(&self.syntax_highlighter),
+            ..message.render_context()
+        }
+    }
+
+    // Used by cxx-qt client (QTextDocument)
+    pub fn message_html(&self, message: &MessageInfo) -> String {
+        htmlrenderer::to_html(
+            &message.markdown_document(),
+            &self.message_render_context(message),
+        )
+    }
+
+    // Used by slint client
+    pub fn message_styled_spans(&self, message: &MessageInfo) -> Vec<StyledSpan> {
+        styledspanrenderer::to_styled_spans(
+            &message.markdown_document(),
+            &self.message_render_context(message),
+        )
+    }
+
+    // Link previews displayed below message
+    pub fn message_preview_cards(&self, message: &MessageInfo) -> Vec<PreviewCard> {
+        self.url_preview_manager.preview_cards(message)
+    }
+
+    // Urls of attachments are relative to server
+    pub fn message_attachments(&self, message: &MessageInfo) -> Vec<AttachmentViewModel> {
+        attachmentviewmodel::attachment_view_models(
+            message,
+            &self.account_settings.server_url_name,
+        )
+    }
+
+    // Buttons and menus of app messages, actions are sent with uikit_manager
+    pub fn message_blocks(&self, message: &MessageInfo) -> Vec<UiKitNode> {
+        uikitrenderer::render_blocks(&message.blocks)
+    }
+
+    // Discussion to open when user clicks on a "discussion-created" message
+    pub fn message_discussion(&self, message: &MessageInfo) -> Option<&Discussion> {
+        self.discussions_manager.discussion_for_message(message)
+    }
+
+    // Text to display, translated when auto translate is enabled for message room
+    pub fn message_translated_text<'a>(&self, message: &'a MessageInfo) -> &'a str {
+        if !self.server_config.auto_translate_enabled() {
+            return &message.message;
+        }
+        match self.rooms.find_room(&message.rid) {
+            Some(room) => self
+                .auto_translate_manager
+                .translated_text(message, &room.auto_translate_settings),
+            None => &message.message,
+        }
+    }
+
+    // Typing notifications to send, "stopped" is sent when user didn't type during timeout.
+    fn check_typing_timeout(&mut self, now: Instant) -> Vec<TypingInfo> {
+        self.receive_typing_notification_manager.remove_expired(now);
+        self.typing_notification.check_timeout(now)
+    }
+
+    // Returns true when own user must be set away.
+    fn check_auto_away(&mut self, now: Instant) -> bool {
+        self.presence_manager
+            .auto_away
+            .check_idle(now, &self.own_user_manager.own_user.status)
+    }
+
+    // Returns true when status set by auto away must be restored.
+    fn user_activity(&mut self, now: Instant) -> bool {
+        self.presence_manager.auto_away.user_activity(now).is_some()
     }
 
     async fn send_message(&mut self, message: String) {
@@ -46,10 +220,144 @@ impl RocketChatAccount {
         // Necessary ???? => use restapi for it.
     }
 
-    fn parse_result(&mut self, value: serde_json::Value) {
+    async fn parse_result(&mut self, value: serde_json::Value) {
+        if let Some(settings) = parse_login_result(&value) {
+            self.logged_in(settings).await;
+            return;
+        }
         self.parse_rooms(value);
     }
 
+    // Rest api uses token of ddp login.
+    fn set_authentication(&mut self, settings: AuthenticationType) {
+        let server_url = &self.account_settings.server_url_name;
+        self.permissions_manager.settings = settings.clone();
+        self.permissions_manager.server_url = server_url.clone();
+        self.roles_manager.settings = settings.clone();
+        self.roles_manager.server_url = server_url.clone();
+        self.server_config.settings = settings.clone();
+        self.server_config.server_url = server_url.clone();
+        self.attachment_image_cache.settings = settings.clone();
+        self.attachment_image_cache.server_url = server_url.clone();
+        self.custom_emoji_manager.settings = settings.clone();
+        self.custom_emoji_manager.server_url = server_url.clone();
+        self.own_user_manager.settings = settings.clone();
+        self.own_user_manager.server_url = server_url.clone();
+        self.presence_manager.settings = settings.clone();
+        self.presence_manager.server_url = server_url.clone();
+        self.uikit_manager.settings = settings.clone();
+        self.uikit_manager.server_url = server_url.clone();
+        self.discussions_manager.settings = settings.clone();
+        self.discussions_manager.server_url = server_url.clone();
+        self.auto_translate_manager.settings = settings;
+        self.auto_translate_manager.server_url = server_url.clone();
+    }
+
+    // Public settings (login methods, password policy, …) are needed before login.
+    async fn load_public_settings(&mut self) {
+        self.server_config.server_url = self.account_settings.server_url_name.clone();
+        if let Err(e) = self.server_config.load_public_settings().await {
+            println!("Impossible to load public settings {:?}", e);
+        }
+    }
+
+    async fn logged_in(&mut self, settings: AuthenticationType) {
+        self.set_authentication(settings);
+        // "userData" stream only sends changes
+        match self.own_user_manager.load().await {
+            Ok(()) => self.own_user_changed(),
+            Err(e) => println!("Impossible to load own user {:?}", e),
+        }
+        if let Err(e) = self.server_config.load_private_settings().await {
+            println!("Impossible to load private settings {:?}", e);
+        }
+        if let Err(e) = self.permissions_manager.load_permissions().await {
+            println!("Impossible to load permissions {:?}", e);
+        }
+        if let Err(e) = self.roles_manager.load_roles_list().await {
+            println!("Impossible to load roles {:?}", e);
+        }
+        if let Err(e) = self.presence_manager.load_custom_user_statuses().await {
+            println!("Impossible to load custom user statuses {:?}", e);
+        }
+        // Language names are translated in user language, own user is loaded before
+        if self.server_config.auto_translate_enabled()
+            && let Err(e) = self.auto_translate_manager.load_languages().await
+        {
+            println!("Impossible to load auto translate languages {:?}", e);
+        }
+        if let Err(e) = self.custom_emoji_manager.load().await {
+            println!("Impossible to load custom emojis {:?}", e);
+        }
+        if let Err(e) = self.custom_emoji_manager.download_missing_images().await {
+            println!("Impossible to download custom emojis {:?}", e);
+        }
+    }
+
+    // Managers which depend on own user name, roles or preferences.
+    fn own_user_changed(&mut self) {
+        self.presence_manager
+            .auto_away
+            .set_preferences(self.own_user_manager.own_user.preferences());
+        self.url_preview_manager
+            .set_preferences(self.own_user_manager.own_user.preferences());
+        self.auto_translate_manager
+            .set_preferences(self.own_user_manager.own_user.preferences());
+        let user_name = &self.own_user_manager.own_user.username;
+        self.receive_typing_notification_manager.own_user_name = user_name.clone();
+        self.typing_notification.user_name = user_name.clone();
+        self.notification_manager
+            .set_own_user(&self.own_user_manager.own_user);
+        self.permissions_manager
+            .set_user_roles(self.own_user_manager.own_user.roles.clone());
+    }
+
+    // Notifications of other accounts are ignored, returns false for them.
+    // Client displays room, or thread when thread message identifier is not empty.
+    pub async fn notification_activated(&mut self, action: &NotificationAction) -> bool {
+        if action.account_name() != self.account_settings.account_name {
+            return false;
+        }
+        self.notification_manager.notification_activated(action);
+        self.open_room(action.room_id()).await;
+        true
+    }
+
+    // Called when user displays a room, room roles of users can change while room is closed.
+    pub async fn open_room(&mut self, room_id: &str) {
+        let Some(room) = self.rooms.find_room(room_id) else {
+            return;
+        };
+        if let Err(e) = self.roles_manager.load_room_roles(room).await {
+            println!("Impossible to load room roles {:?}", e);
+        }
+    }
+
+    // stream-room-messages arguments, each message is rendered for client message list.
+    fn room_messages_changed(&self, value: &serde_json::Value) {
+        let (Some(sender), Some(messages)) = (&self.gui_sender, value.as_array()) else {
+            return;
+        };
+        for message in messages {
+            let message = match serde_json::from_value::<MessageInfo>(message.clone()) {
+                Ok(message) => message,
+                Err(e) => {
+                    println!("Impossible to parse room message {:?}", e);
+                    continue;
+                }
+            };
+            let command = CommandToGui::RoomMessageChanged {
+                account_name: self.account_settings.account_name.clone(),
+                room_id: message.rid.clone(),
+                message_id: message.identifier.clone(),
+                spans: self.message_styled_spans(&message),
+            };
+            if sender.send(command).is_err() {
+                println!("Impossible to send message to client");
+            }
+        }
+    }
+
     fn parse_rooms(&mut self, value: serde_json::Value) {
         // println!("PARSE ROOM {:?}", value);
         let result_value = value["result"].as_object();
@@ -60,7 +368,16 @@ impl RocketChatAccount {
                     update_value["update"].as_array();
                 if let Some(update_values) = update_value {
                     println!("Has update rooms");
-                    self.rooms.parse_insert_rooms(update_values);
+                    // subscriptions/get and rooms/get results have same format,
+                    // only subscriptions have "rid"
+                    let (subscriptions, rooms): (Vec<serde_json::Value>, Vec<serde_json::Value>) =
+                        update_values
+                            .iter()
+                            .cloned()
+                            .partition(|value| value.get("rid").is_some());
+                    self.rooms.parse_insert_rooms(&rooms);
+                    self.rooms.parse_subscriptions(&subscriptions);
+                    self.permissions_manager.set_rooms(&self.rooms);
                     println!("NOMBER OF ROOMS {:?}", self.rooms.rooms.len());
                     println!("ROOMS************************* {:?}", self.rooms.rooms);
                 }
@@ -75,16 +392,21 @@ impl RocketChatAccount {
             libddpapi::ddpmessage::ChangeElementType::User => println!("USER"),
             libddpapi::ddpmessage::ChangeElementType::Rooms => println!("ROOM"),
             libddpapi::ddpmessage::ChangeElementType::StreamRoomMessage(value) => {
-                println!("ROOM MESSAGE")
+                self.room_messages_changed(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserOtr(value) => {
                 println!("Notification OTR")
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserUiInteraction(value) => {
-                println!("UiInteraction {}", value)
+                self.uikit_manager.apply_interaction(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserNotification(value) => {
-                println!("NOTIFICATION")
+                let rooms = &self.rooms;
+                self.notification_manager.apply_notification(&value, |room_id| {
+                    rooms
+                        .find_room(room_id)
+                        .map(|room| room.notification_settings.clone())
+                });
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserWebrtc(value) => {
                 println!("WebRTC")
@@ -93,7 +415,8 @@ impl RocketChatAccount {
                 println!("USER MESSAGE")
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserUserData(value) => {
-                println!("USER DATA")
+                self.own_user_manager.apply_user_data(&value);
+                self.own_user_changed();
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserRoomsChanged(value) => {
                 println!("ROOMS CHANGED")
@@ -103,7 +426,10 @@ impl RocketChatAccount {
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserSubscriptionsChanged(
                 value,
-            ) => println!("USER SUBSCRIPTION CHANGED"),
+            ) => {
+                self.rooms.apply_subscriptions_changed(&value);
+                self.permissions_manager.set_rooms(&self.rooms);
+            }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyUserForceLogout(value) => {
                 println!("FORCE LOGOUT")
             }
@@ -113,8 +439,12 @@ impl RocketChatAccount {
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyRoomDeleteMessage => {
                 println!("DELETE MESSAGE")
             }
-            libddpapi::ddpmessage::ChangeElementType::StreamNotifyRoomUserActivity => {
-                println!("USER ACTIVITY")
+            libddpapi::ddpmessage::ChangeElementType::StreamNotifyRoomUserActivity(
+                room_id,
+                value,
+            ) => {
+                self.receive_typing_notification_manager
+                    .apply_user_activity(&room_id, &value, Instant::now());
             }
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyLogged => println!("LOGGED"),
             libddpapi::ddpmessage::ChangeElementType::StreamNotifyRoomUnknown => {
@@ -131,13 +461,13 @@ impl RocketChatAccount {
                 println!("STREAM STDOUT")
             }
             libddpapi::ddpmessage::ChangeElementType::RolesChange(value) => {
-                println!("ROLES CHANGED")
+                self.roles_manager.apply_roles_change(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::UpdateAvatar(value) => {
                 println!("UPDATE AVATAR")
             }
             libddpapi::ddpmessage::ChangeElementType::UpdateEmojiCustom(value) => {
-                println!("UPDATE EMOJI CUSTOM")
+                self.custom_emoji_manager.apply_update_emoji_custom(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::UsersNameChanged(value) => {
                 println!("USERNAME CHANGED")
@@ -146,25 +476,30 @@ impl RocketChatAccount {
                 println!("DELETE USER")
             }
             libddpapi::ddpmessage::ChangeElementType::DeleteCustomUserStatus(value) => {
-                println!("DELETE CUSTOM USER STATUS")
+                self.presence_manager
+                    .apply_delete_custom_user_status(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::UpdateCustomUserStatus(value) => {
-                println!("UPDATE CUSTOM USER STATUS")
+                self.presence_manager
+                    .apply_update_custom_user_status(&value);
             }
-            libddpapi::ddpmessage::ChangeElementType::UserStatus(svalue) => {
-                println!("USER STATUS CHANGED")
+            libddpapi::ddpmessage::ChangeElementType::UserStatus(value) => {
+                self.presence_manager.apply_user_status(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::DeleteEmojiCustom(value) => {
-                println!("DELETE EMOJI CUSTOM")
+                self.custom_emoji_manager.apply_delete_emoji_custom(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::PermissionsChanged(value) => {
-                println!("PERMISSION CHANGED")
+                self.permissions_manager.apply_permissions_changed(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::PrivateSettingsChanged(value) => {
-                println!("PRIVATE SETTINGS CHANGED")
+                self.server_config.apply_settings_changed(&value);
+            }
+            libddpapi::ddpmessage::ChangeElementType::PublicSettingsChanged(value) => {
+                self.server_config.apply_settings_changed(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::StreamRoles(value) => {
-                println!("STREAM ROLE")
+                self.roles_manager.apply_stream_roles(&value);
             }
             libddpapi::ddpmessage::ChangeElementType::Unknown => println!("PROBLEM UNKNOWN"),
         }
@@ -176,6 +511,7 @@ impl RocketChatAccount {
         sender: mpsc::UnboundedSender<CommandToBackend>,
     ) -> Result<(ddpclient::DDpClient, mpsc::UnboundedReceiver<Event>), ddpclient::BuilderError>
     {
+        self.load_public_settings().await;
         // Verify if we can connect or not
         match self.account_backend.ddpclient_builder.clone().build().await {
             Err(b_error) => match b_error {
@@ -191,16 +527,53 @@ impl RocketChatAccount {
             },
             Ok((ddpclient, mut event_receiver)) => {
                 println!("CONNECTED ");
+                let mut timer = tokio::time::interval(Duration::from_secs(1));
                 loop {
                     tokio::select! {
                         Some(receiver) = receiver.recv() => {
                             match receiver {
                                 CommandToBackend::SendMessage {message, room_id} => {
+                                    if self.user_activity(Instant::now()) {
+                                        ddpclient.set_away(false);
+                                    }
+                                    if let Some(info) = self.typing_notification.stop_typing(&room_id) {
+                                        ddpclient.change_typing_info(info);
+                                    }
                                     ddpclient.send_message(message, room_id);
                                 }
+                                CommandToBackend::ComposerTextChanged {room_id, thread_message_id, text_is_empty} => {
+                                    if self.user_activity(Instant::now()) {
+                                        ddpclient.set_away(false);
+                                    }
+                                    let info = self.typing_notification.thread_text_changed(
+                                        &room_id,
+                                        &thread_message_id,
+                                        text_is_empty,
+                                        Instant::now(),
+                                    );
+                                    if let Some(info) = info {
+                                        ddpclient.change_typing_info(info);
+                                    }
+                                }
+                                CommandToBackend::UserActivity => {
+                                    if self.user_activity(Instant::now()) {
+                                        ddpclient.set_away(false);
+                                    }
+                                }
+                                CommandToBackend::NotificationActivated(action) => {
+                                    self.notification_activated(&action).await;
+                                }
                                 _ => println!("Receive a element")
                             }
                         }
+                        _ = timer.tick() => {
+                            for info in self.check_typing_timeout(Instant::now()) {
+                                ddpclient.change_typing_info(info);
+                            }
+                            if self.check_auto_away(Instant::now()) {
+                                ddpclient.set_away(true);
+                            }
+                        }
                         Some(event) = event_receiver.recv() => {
                             match event {
                                 ddpclient::Event::MessageReceived(str) => {
@@ -211,7 +584,7 @@ impl RocketChatAccount {
                                 },
                                 ddpclient::Event::ResultReceived(str) => {
                                     //println!("Result received {:?}", str);
-                                    self.parse_result(str);
+                                    self.parse_result(str).await;
                                 },
                                 ddpclient::Event::ChangeElementType(element_type) => {
                                     println!("Change Elements received {:?}", element_type);
@@ -250,3 +623,317 @@ impl RocketChatAccount {
             .await;
     }
 }
+
+// Result of ddp "login" method: {"result": {"id": <user id>, "token": …, "tokenExpires": …}}
+fn parse_login_result(value: &serde_json::Value) -> Option<AuthenticationType> {
+    let result = &value["result"];
+    let (Some(user_id), Some(auth_token)) = (result["id"].as_str(), result["token"].as_str())
+    else {
+        return None;
+    };
+    Some(AuthenticationType::Auth(AuthSettings {
+        auth_token: auth_token.to_string(),
+        user_id: user_id.to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoticons::unicodeemoji::SkinTone;
+    use crate::markdown::styledspanrenderer::SpanKind;
+    use crate::notification::notificationsink::NotificationAction;
+    use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+    use crate::rocketchataccount::{RocketChatAccount, parse_login_result};
+    use crate::rocketchataccountmanager::CommandToGui;
+    use crate::user::Status;
+    use libauthenticationbase::authenticationsettings::AuthenticationType;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_parse_login_result() {
+        let value = serde_json::json!({
+            "msg": "result",
+            "id": "1",
+            "result": {"id": "XQZAk3998f9hSNwh", "token": "token1", "tokenExpires": {"$date": 1780000000000_i64}, "type": "password"}
+        });
+        let Some(AuthenticationType::Auth(settings)) = parse_login_result(&value) else {
+            panic!("Invalid login result");
+        };
+        assert_eq!(settings.user_id, "XQZAk3998f9hSNwh");
+        assert_eq!(settings.auth_token, "token1");
+        assert!(parse_login_result(&serde_json::json!({"result": {"update": []}})).is_none());
+    }
+
+    #[test]
+    fn test_set_authentication() {
+        let mut account = RocketChatAccount::new();
+        account.account_settings.server_url_name = "https://mydomain.com".to_string();
+        account.set_authentication(
+            parse_login_result(&serde_json::json!({"result": {"id": "user1", "token": "token1"}}))
+                .unwrap(),
+        );
+        assert!(matches!(
+            account.permissions_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(
+            account.permissions_manager.server_url,
+            "https://mydomain.com"
+        );
+        assert!(matches!(
+            account.roles_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(account.roles_manager.server_url, "https://mydomain.com");
+        assert!(matches!(
+            account.server_config.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(account.server_config.server_url, "https://mydomain.com");
+        assert!(matches!(
+            account.attachment_image_cache.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(
+            account.attachment_image_cache.server_url,
+            "https://mydomain.com"
+        );
+        assert!(matches!(
+            account.custom_emoji_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(
+            account.custom_emoji_manager.server_url,
+            "https://mydomain.com"
+        );
+        assert!(matches!(
+            account.own_user_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(account.own_user_manager.server_url, "https://mydomain.com");
+        assert!(matches!(
+            account.presence_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(account.presence_manager.server_url, "https://mydomain.com");
+        assert!(matches!(
+            account.uikit_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(account.uikit_manager.server_url, "https://mydomain.com");
+        assert!(matches!(
+            account.discussions_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(
+            account.discussions_manager.server_url,
+            "https://mydomain.com"
+        );
+        assert!(matches!(
+            account.auto_translate_manager.settings,
+            AuthenticationType::Auth(_)
+        ));
+        assert_eq!(
+            account.auto_translate_manager.server_url,
+            "https://mydomain.com"
+        );
+    }
+
+    #[test]
+    fn test_check_typing_timeout() {
+        let mut account = RocketChatAccount::new();
+        account.typing_notification.user_name = "laurent".to_string();
+        let start = Instant::now();
+        assert!(
+            account
+                .typing_notification
+                .text_changed("room1", false, start)
+                .is_some()
+        );
+        assert!(
+            account
+                .check_typing_timeout(start + Duration::from_secs(1))
+                .is_empty()
+        );
+        let infos = account.check_typing_timeout(start + Duration::from_secs(5));
+        assert_eq!(infos.len(), 1);
+        assert!(!infos[0].typing);
+        assert_eq!(infos[0].room_id, "room1");
+    }
+
+    #[test]
+    fn test_auto_away() {
+        let mut account = RocketChatAccount::new();
+        account
+            .presence_manager
+            .auto_away
+            .set_preferences(&OwnUserPreferences {
+                enable_auto_away: true,
+                idle_time_limit: 300,
+                ..Default::default()
+            });
+        account.own_user_manager.own_user.status = Status::Online;
+        let start = Instant::now();
+        assert!(!account.user_activity(start));
+        assert!(!account.check_auto_away(start + Duration::from_secs(299)));
+        assert!(account.check_auto_away(start + Duration::from_secs(300)));
+        // Server sends new status
+        account.own_user_manager.own_user.status = Status::Away;
+        assert!(!account.check_auto_away(start + Duration::from_secs(400)));
+        assert!(account.user_activity(start + Duration::from_secs(401)));
+        assert!(!account.user_activity(start + Duration::from_secs(402)));
+    }
+
+    #[tokio::test]
+    async fn test_notification_activated() {
+        let mut account = RocketChatAccount::new();
+        account.account_settings.account_name = "kde".to_string();
+        let action = NotificationAction::OpenThread {
+            account_name: "kde".to_string(),
+            room_id: "GENERAL".to_string(),
+            thread_message_id: "thread1".to_string(),
+        };
+        assert!(account.notification_activated(&action).await);
+        assert_eq!(account.notification_manager.current_room_id, "GENERAL");
+        assert_eq!(
+            account.notification_manager.current_thread_message_id,
+            "thread1"
+        );
+
+        // Other account
+        let action = NotificationAction::OpenRoom {
+            account_name: "other".to_string(),
+            room_id: "room2".to_string(),
+        };
+        assert!(!account.notification_activated(&action).await);
+        assert_eq!(account.notification_manager.current_room_id, "GENERAL");
+    }
+
+    #[test]
+    fn test_room_messages_changed() {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut account = RocketChatAccount::new();
+        account.account_settings.account_name = "kde".to_string();
+        account.gui_sender = Some(sender);
+        account.room_messages_changed(&serde_json::json!([{
+            "_id": "AkzpHAvZpdnuchw2a", "msg": "```rust\nfn main() {}\n```", "alias": "",
+            "emoji": "", "avatar": "", "role": "", "rid": "GENERAL", "unread": false
+        }]));
+        let Ok(CommandToGui::RoomMessageChanged {
+            account_name,
+            room_id,
+            message_id,
+            spans,
+        }) = receiver.try_recv()
+        else {
+            panic!("No message sent to client");
+        };
+        assert_eq!(account_name, "kde");
+        assert_eq!(room_id, "GENERAL");
+        assert_eq!(message_id, "AkzpHAvZpdnuchw2a");
+        assert!(spans.iter().all(|span| span.kind
+            == SpanKind::CodeBlock {
+                language: "rust".to_string()
+            }));
+        assert!(spans.iter().any(|span| span.style.color.is_some()));
+
+        // Invalid message is ignored
+        account.room_messages_changed(&serde_json::json!([{"_id": "foo"}]));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_own_user_changed() {
+        let mut account = RocketChatAccount::new();
+        account.own_user_manager.own_user.parse_elements(
+            &std::fs::read_to_string("src/data/ownuser/me.json").expect("Failed to open file"),
+        );
+        account.own_user_changed();
+        let user_name = &account.own_user_manager.own_user.username;
+        assert!(!user_name.is_empty());
+        assert_eq!(&account.typing_notification.user_name, user_name);
+        assert_eq!(
+            &account.receive_typing_notification_manager.own_user_name,
+            user_name
+        );
+    }
+
+    #[test]
+    fn test_set_cache_directory() {
+        let mut account = RocketChatAccount::new();
+        account.set_cache_directory(std::path::Path::new("/tmp/ruqola/account1"));
+        assert_eq!(
+            account.custom_emoji_manager.image_cache.cache_directory,
+            std::path::Path::new("/tmp/ruqola/account1/emoji")
+        );
+        assert_eq!(
+            account.url_preview_manager.image_cache.cache_directory,
+            std::path::Path::new("/tmp/ruqola/account1/previews")
+        );
+        assert_eq!(
+            account.attachment_image_cache.cache_directory,
+            std::path::Path::new("/tmp/ruqola/account1/attachments")
+        );
+    }
+
+    #[test]
+    fn test_settings() {
+        let file_name = std::env::temp_dir()
+            .join(format!(
+                "ruqola-rocketchataccount-{}.conf",
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .to_string();
+        let mut account = RocketChatAccount::new();
+        account.account_settings.account_name = "kde".to_string();
+        account.url_preview_manager.hide_previews = true;
+        account.unicode_emoji_manager.emoji_used("tada");
+        account.unicode_emoji_manager.skin_tone = SkinTone::Medium;
+        account.write_settings(file_name.clone());
+
+        let mut loaded = RocketChatAccount::new();
+        loaded.load_settings(file_name.clone());
+        assert_eq!(loaded.account_settings.account_name, "kde");
+        assert!(loaded.url_preview_manager.hide_previews);
+        assert_eq!(
+            loaded.unicode_emoji_manager.recent_emojis.emojis,
+            vec!["tada"]
+        );
+        assert_eq!(loaded.unicode_emoji_manager.skin_tone, SkinTone::Medium);
+        let _ = std::fs::remove_file(&file_name);
+    }
+
+    #[test]
+    fn test_parse_initial_subscriptions() {
+        let subscription: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("src/data/room/subscription.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        let mut account = RocketChatAccount::new();
+        // subscriptions/get result is received before rooms/get result
+        account.parse_rooms(serde_json::json!({
+            "msg": "result",
+            "id": "2",
+            "result": {"update": [subscription], "remove": []}
+        }));
+        assert!(account.rooms.rooms.is_empty());
+        account.parse_rooms(serde_json::json!({
+            "msg": "result",
+            "id": "3",
+            "result": {"update": [{"_id": "Fc4EWcQXCkJkJ7QEQ", "t": "c", "name": "foo"}], "remove": []}
+        }));
+        assert_eq!(account.rooms.rooms.len(), 1);
+        let room = account.rooms.find_room("Fc4EWcQXCkJkJ7QEQ").unwrap();
+        assert!(room.notification_settings.hide_unread_status);
+        assert!(room.auto_translate_settings.auto_translate);
+        assert_eq!(
+            account
+                .permissions_manager
+                .room_roles
+                .get("Fc4EWcQXCkJkJ7QEQ"),
+            Some(&vec!["owner".to_string()])
+        );
+    }
+}
@@ -6,11 +6,13 @@
 
 extern crate xdg;
 
+use crate::markdown::styledspanrenderer::StyledSpan;
+use crate::notification::notificationsink::NotificationAction;
 use crate::rocketchataccount::RocketChatAccount;
 use crate::rocketchataccountsettings::RocketChatAccountSettings;
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 use tokio::sync::Mutex;
 
@@ -41,14 +43,39 @@ pub enum CommandFromGui {
 }
 
 pub enum CommandToBackend {
-    SendMessage { message: String, room_id: String },
+    SendMessage {
+        message: String,
+        room_id: String,
+    },
     UpdateAccountList,
+    // Sends typing notifications, thread_message_id is empty for room composer
+    ComposerTextChanged {
+        room_id: String,
+        thread_message_id: String,
+        text_is_empty: bool,
+    },
+    // Key press or mouse move, restores status set by auto away
+    UserActivity,
+    // User clicked on a notification, room or thread must be opened
+    NotificationActivated(NotificationAction),
+}
+
+// Sent by accounts, client updates its views
+pub enum CommandToGui {
+    // Message added or edited, spans are displayed by message list
+    RoomMessageChanged {
+        account_name: String,
+        room_id: String,
+        message_id: String,
+        spans: Vec<StyledSpan>,
+    },
 }
 
 pub struct RocketChatAccountManager {
     // Vector of rocketchataccount settings
     pub rocketchat_accounts: Vec<RocketChatAccount>,
     pub receiver: Option<mpsc::Receiver<CommandFromGui>>,
+    pub gui_sender: Option<mpsc::UnboundedSender<CommandToGui>>,
     // TODO currentAccount
 }
 
@@ -63,6 +90,7 @@ impl RocketChatAccountManager {
         Self {
             rocketchat_accounts: Vec::<RocketChatAccount>::new(),
             receiver: None,
+            gui_sender: None,
         }
     }
 
@@ -70,6 +98,11 @@ impl RocketChatAccountManager {
         self.receiver = Some(rx);
     }
 
+    // Must be called before initialize_accounts
+    pub fn set_gui_tx(&mut self, tx: mpsc::UnboundedSender<CommandToGui>) {
+        self.gui_sender = Some(tx);
+    }
+
     pub fn send_message(&self, message: String, room_id: String) {
         // TODO send message to current account manager
         println!("SEND MESSAGE **********************************");
@@ -78,7 +111,8 @@ impl RocketChatAccountManager {
     pub async fn initialize_accounts(&mut self) {
         <Vec<RocketChatAccount> as Clone>::clone(&self.rocketchat_accounts)
             .into_iter()
-            .for_each(|account: RocketChatAccount| {
+            .for_each(|mut account: RocketChatAccount| {
+                account.gui_sender = self.gui_sender.clone();
                 tokio::spawn({
                     let (sender, mut receiver) = mpsc::unbounded_channel::<CommandToBackend>();
                     account.build(receiver, sender)
@@ -116,11 +150,20 @@ impl RocketChatAccountManager {
         }
     }
 
+    // Images downloaded by account (custom emojis, previews, attachments)
+    fn cache_directory(account_name: &str) -> PathBuf {
+        let xdg_dirs = xdg::BaseDirectories::with_prefix("ruqola-slint").unwrap();
+        xdg_dirs.get_cache_home().join(account_name)
+    }
+
     // TODO look at how we can use it only in test => remove pub when test
     pub fn load_account(&mut self, file_name: String) {
         println!("File path**** : {:?}", file_name);
         let mut rocketaccount = RocketChatAccount::new();
         rocketaccount.load_settings(file_name);
+        rocketaccount.set_cache_directory(&Self::cache_directory(
+            &rocketaccount.account_settings.account_name,
+        ));
         rocketaccount
             .account_backend
             .ddpclient_builder
@@ -175,6 +218,8 @@ impl RocketChatAccountManager {
         if account_settings.is_valid() {
             println!("Add account  is Valid !");
             let mut rocketaccount = RocketChatAccount::new();
+            rocketaccount
+                .set_cache_directory(&Self::cache_directory(&account_settings.account_name));
             rocketaccount.account_settings = account_settings;
             self.rocketchat_accounts.push(rocketaccount);
         }
@@ -207,8 +252,7 @@ impl RocketChatAccountManager {
                 fs::create_dir(path).expect("Impossible to create {path}");
             }
             let path = fillpath.join("ruqola.conf");
-            x.account_settings
-                .write_settings(String::from(path.to_str().unwrap()));
+            x.write_settings(String::from(path.to_str().unwrap()));
         }
     }
 
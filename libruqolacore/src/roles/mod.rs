@@ -4,4 +4,6 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 pub mod role;
+pub mod roleinfo;
 pub mod roles;
+pub mod rolesmanager;
@@ -37,6 +37,34 @@ impl Role {
     pub fn has_role(self) -> bool {
         self.is_moderator || self.is_leader || self.is_owner
     }
+
+    // Returns false for a role which is not a room role.
+    pub fn set_role(&mut self, role: &str, value: bool) -> bool {
+        match role {
+            "owner" => self.is_owner = value,
+            "moderator" => self.is_moderator = value,
+            "leader" => self.is_leader = value,
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn contains_role(&self, role: &str) -> bool {
+        match role {
+            "owner" => self.is_owner,
+            "moderator" => self.is_moderator,
+            "leader" => self.is_leader,
+            _ => false,
+        }
+    }
+
+    // Used to show badges next to user name
+    pub fn role_names(&self) -> Vec<&'static str> {
+        ["owner", "moderator", "leader"]
+            .into_iter()
+            .filter(|role| self.contains_role(role))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +102,18 @@ mod tests {
         assert!(!r.clone().has_role());
     }
     // TODO add test for is_valid
+
+    #[test]
+    fn test_set_role() {
+        let mut r = Role::default();
+        assert!(r.set_role("owner", true));
+        assert!(r.set_role("leader", true));
+        assert!(!r.set_role("admin", true));
+        assert!(r.contains_role("owner"));
+        assert!(!r.contains_role("moderator"));
+        assert!(!r.contains_role("admin"));
+        assert_eq!(r.role_names(), vec!["owner", "leader"]);
+        assert!(r.set_role("owner", false));
+        assert_eq!(r.role_names(), vec!["leader"]);
+    }
 }
@@ -0,0 +1,82 @@
+/*
+* SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+*
+* SPDX-License-Identifier: LGPL-2.0-or-later
+*/
+
+use serde::Deserialize;
+use std::fmt;
+
+// Role definition (roles.list)
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+pub struct RoleInfo {
+    #[serde(rename = "_id")]
+    pub identifier: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    // "Users" or "Subscriptions"
+    #[serde(default)]
+    pub scope: String,
+    #[serde(default, rename = "mandatory2fa")]
+    pub mandatory_2fa: bool,
+    #[serde(default, rename = "protected")]
+    pub is_protected: bool,
+}
+
+impl RoleInfo {
+    pub fn new() -> Self {
+        RoleInfo {
+            ..Default::default()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.identifier.is_empty()
+    }
+
+    // Role which can be given in a room
+    pub fn is_subscription_role(&self) -> bool {
+        self.scope == "Subscriptions"
+    }
+}
+
+/* Debug output for RoleInfo */
+impl fmt::Display for RoleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RoleInfo(identifier: {}, name: {}, scope: {}, protected: {})",
+            self.identifier, self.name, self.scope, self.is_protected
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::roles::roleinfo::RoleInfo;
+
+    #[test]
+    fn test_default_values() {
+        let r = RoleInfo::new();
+        assert!(r.identifier.is_empty());
+        assert!(r.name.is_empty());
+        assert!(r.scope.is_empty());
+        assert!(!r.mandatory_2fa);
+        assert!(!r.is_protected);
+        assert!(!r.is_valid());
+    }
+
+    #[test]
+    fn test_parsing() {
+        let r: RoleInfo = serde_json::from_str(
+            r#"{"_id":"owner","name":"owner","description":"","mandatory2fa":false,"protected":true,"scope":"Subscriptions"}"#,
+        )
+        .unwrap();
+        assert!(r.is_valid());
+        assert_eq!(r.identifier, "owner");
+        assert!(r.is_protected);
+        assert!(r.is_subscription_role());
+    }
+}
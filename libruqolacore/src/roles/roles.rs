@@ -5,6 +5,29 @@
 */
 
 use crate::roles::role::Role;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct RoomRoleUser {
+    #[serde(rename = "_id")]
+    identifier: String,
+    #[serde(default)]
+    username: String,
+}
+
+#[derive(Deserialize)]
+struct RoomRoleItem {
+    u: RoomRoleUser,
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RoomRolesResult {
+    #[serde(default)]
+    roles: Vec<RoomRoleItem>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 
 pub struct Roles {
@@ -23,13 +46,113 @@ impl Roles {
             roles: Vec::<Role>::default(),
         }
     }
+
+    // Parse channels.roles/groups.roles result.
+    pub fn parse_elements(&mut self, json: &str) {
+        if let Ok(result) = serde_json::from_str::<RoomRolesResult>(json) {
+            self.roles = result
+                .roles
+                .into_iter()
+                .map(|item| {
+                    let mut role = Role {
+                        user_id: item.u.identifier,
+                        user_name: item.u.username,
+                        ..Default::default()
+                    };
+                    for name in &item.roles {
+                        role.set_role(name, true);
+                    }
+                    role
+                })
+                .filter(|role| role.clone().has_role())
+                .collect();
+        }
+    }
+
+    pub fn find_role(&self, user_id: &str) -> Option<&Role> {
+        self.roles.iter().find(|role| role.user_id == user_id)
+    }
+
+    // Add or remove a room role, user without role is removed.
+    pub fn update_role(&mut self, user_id: &str, user_name: &str, role: &str, value: bool) {
+        let position = self.roles.iter().position(|r| r.user_id == user_id);
+        let index = match position {
+            Some(index) => index,
+            None if value => {
+                self.roles.push(Role {
+                    user_id: user_id.to_string(),
+                    user_name: user_name.to_string(),
+                    ..Default::default()
+                });
+                self.roles.len() - 1
+            }
+            None => return,
+        };
+        self.roles[index].set_role(role, value);
+        if !self.roles[index].clone().has_role() {
+            self.roles.remove(index);
+        }
+    }
+
+    pub fn users_with_role(&self, role: &str) -> Vec<&Role> {
+        self.roles
+            .iter()
+            .filter(|r| r.contains_role(role))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::roles::roles::Roles;
+
+    fn parse(filename: &str) -> Roles {
+        let mut r = Roles::new();
+        r.parse_elements(&std::fs::read_to_string(filename).expect("Failed to open file"));
+        r
+    }
+
     #[test]
     fn test_default_values() {
-        // TODO
+        let r = Roles::new();
+        assert!(r.roles.is_empty());
+        assert!(r.find_role("foo").is_none());
+    }
+
+    #[test]
+    fn test_parse_elements() {
+        let r = parse("src/data/roles/roomroles.json");
+        assert_eq!(r.roles.len(), 3);
+        let role = r.find_role("Lymsiu4Mn6xjTAan4").unwrap();
+        assert_eq!(role.user_name, "laurent");
+        assert!(role.is_owner);
+        assert!(role.is_moderator);
+        assert!(!role.is_leader);
+        assert_eq!(r.users_with_role("moderator").len(), 2);
+        assert_eq!(r.users_with_role("leader").len(), 1);
+        assert!(r.users_with_role("admin").is_empty());
+    }
+
+    #[test]
+    fn test_update_role() {
+        let mut r = Roles::new();
+        r.update_role("user1", "foo", "moderator", true);
+        assert_eq!(r.roles.len(), 1);
+        assert!(r.find_role("user1").unwrap().is_moderator);
+
+        r.update_role("user1", "foo", "owner", true);
+        assert_eq!(r.roles.len(), 1);
+        assert_eq!(
+            r.find_role("user1").unwrap().role_names(),
+            vec!["owner", "moderator"]
+        );
+
+        r.update_role("user1", "foo", "moderator", false);
+        r.update_role("user1", "foo", "owner", false);
+        assert!(r.roles.is_empty());
+
+        // Removing a role of unknown user does nothing
+        r.update_role("user2", "bla", "owner", false);
+        assert!(r.roles.is_empty());
     }
 }
@@ -0,0 +1,229 @@
+/*
+* SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+*
+* SPDX-License-Identifier: LGPL-2.0-or-later
+*/
+
+use crate::roles::role::Role;
+use crate::roles::roleinfo::RoleInfo;
+use crate::roles::roles::Roles;
+use crate::room::Room;
+use crate::roomrestapi::{RoomRestApi, RoomRestApiError};
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{APIMethod, RolesListMethod};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct RolesListResult {
+    #[serde(default)]
+    roles: Vec<RoleInfo>,
+}
+
+// Role definitions and room roles (owner, moderator, leader) of users.
+#[derive(Clone)]
+pub struct RolesManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub role_infos: Vec<RoleInfo>,
+    // Room identifier => users with a role in this room
+    pub room_roles: HashMap<String, Roles>,
+}
+
+impl Default for RolesManager {
+    fn default() -> Self {
+        RolesManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl RolesManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        RolesManager {
+            settings,
+            server_url,
+            role_infos: Vec::new(),
+            room_roles: HashMap::new(),
+        }
+    }
+
+    // Parse roles.list result.
+    pub fn parse_roles_list(&mut self, json: &str) {
+        if let Ok(result) = serde_json::from_str::<RolesListResult>(json) {
+            self.role_infos = result
+                .roles
+                .into_iter()
+                .filter(|role| role.is_valid())
+                .collect();
+        }
+    }
+
+    pub fn role_info(&self, identifier: &str) -> Option<&RoleInfo> {
+        self.role_infos
+            .iter()
+            .find(|role| role.identifier == identifier)
+    }
+
+    // Parse channels.roles/groups.roles result.
+    pub fn parse_room_roles(&mut self, room_id: &str, json: &str) {
+        let mut roles = Roles::new();
+        roles.parse_elements(json);
+        self.room_roles.insert(room_id.to_string(), roles);
+    }
+
+    pub fn roles(&self, room_id: &str) -> Option<&Roles> {
+        self.room_roles.get(room_id)
+    }
+
+    pub fn role(&self, room_id: &str, user_id: &str) -> Option<&Role> {
+        self.room_roles.get(room_id)?.find_role(user_id)
+    }
+
+    // "roles-change" stream: [{"type": "added"|"removed", "_id": role, "u": {…}, "scope": room_id}]
+    // Global roles don't have a scope and are ignored.
+    pub fn apply_roles_change(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            let change_type = change["type"].as_str().unwrap_or_default();
+            let (Some(role), Some(room_id), Some(user_id)) = (
+                change["_id"].as_str(),
+                change["scope"].as_str(),
+                change["u"]["_id"].as_str(),
+            ) else {
+                continue;
+            };
+            let user_name = change["u"]["username"].as_str().unwrap_or_default();
+            let value = match change_type {
+                "added" => true,
+                "removed" => false,
+                _ => continue,
+            };
+            self.room_roles
+                .entry(room_id.to_string())
+                .or_default()
+                .update_role(user_id, user_name, role, value);
+        }
+    }
+
+    // "roles" stream: [{"type": "changed"|"removed", "_id": …, "name": …, …}]
+    pub fn apply_stream_roles(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            let Some(identifier) = change["_id"].as_str() else {
+                continue;
+            };
+            match change["type"].as_str() {
+                Some("removed") => self.role_infos.retain(|r| r.identifier != identifier),
+                Some("changed") => {
+                    let Ok(info) = serde_json::from_value::<RoleInfo>(change.clone()) else {
+                        continue;
+                    };
+                    if let Some(existing) = self
+                        .role_infos
+                        .iter_mut()
+                        .find(|r| r.identifier == identifier)
+                    {
+                        *existing = info;
+                    } else {
+                        self.role_infos.push(info);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub async fn load_roles_list(&mut self) -> Result<(), Error> {
+        let result = RolesListMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+        }
+        .call()
+        .await?;
+        self.parse_roles_list(&result);
+        Ok(())
+    }
+
+    pub async fn load_room_roles(&mut self, room: &Room) -> Result<(), RoomRestApiError> {
+        let result = RoomRestApi::new(self.settings.clone(), self.server_url.clone())
+            .roles(room)
+            .await?;
+        self.parse_room_roles(&room.room_id, &result);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::roles::rolesmanager::RolesManager;
+
+    fn read(filename: &str) -> String {
+        std::fs::read_to_string(filename).expect("Failed to open file")
+    }
+
+    #[test]
+    fn test_default_values() {
+        let m = RolesManager::default();
+        assert!(m.role_infos.is_empty());
+        assert!(m.room_roles.is_empty());
+        assert!(m.role("room1", "user1").is_none());
+    }
+
+    #[test]
+    fn test_parse_roles_list() {
+        let mut m = RolesManager::default();
+        m.parse_roles_list(&read("src/data/roles/roleslist.json"));
+        assert_eq!(m.role_infos.len(), 6);
+        let owner = m.role_info("owner").unwrap();
+        assert!(owner.is_subscription_role());
+        assert!(owner.is_protected);
+        assert!(!m.role_info("admin").unwrap().is_subscription_role());
+        assert!(m.role_info("foo").is_none());
+    }
+
+    #[test]
+    fn test_apply_stream_roles() {
+        let mut m = RolesManager::default();
+        m.parse_roles_list(&read("src/data/roles/roleslist.json"));
+        m.apply_stream_roles(&serde_json::json!([
+            {"type": "changed", "_id": "support", "name": "support", "description": "Support team", "scope": "Users", "mandatory2fa": false, "protected": false}
+        ]));
+        assert_eq!(m.role_infos.len(), 7);
+        m.apply_stream_roles(&serde_json::json!([
+            {"type": "changed", "_id": "support", "name": "support", "description": "Support", "scope": "Users"}
+        ]));
+        assert_eq!(m.role_infos.len(), 7);
+        assert_eq!(m.role_info("support").unwrap().description, "Support");
+        m.apply_stream_roles(&serde_json::json!([
+            {"type": "removed", "_id": "support", "name": "support"}
+        ]));
+        assert_eq!(m.role_infos.len(), 6);
+        assert!(m.role_info("support").is_none());
+    }
+
+    #[test]
+    fn test_room_roles() {
+        let mut m = RolesManager::default();
+        m.parse_room_roles("GENERAL", &read("src/data/roles/roomroles.json"));
+        assert_eq!(m.roles("GENERAL").unwrap().roles.len(), 3);
+        assert!(m.role("GENERAL", "Lymsiu4Mn6xjTAan4").unwrap().is_owner);
+
+        m.apply_roles_change(&serde_json::json!([
+            {"type": "added", "_id": "leader", "u": {"_id": "Lymsiu4Mn6xjTAan4", "username": "laurent", "name": "Laurent"}, "scope": "GENERAL"},
+            {"type": "added", "_id": "moderator", "u": {"_id": "user5", "username": "bla"}, "scope": "room2"},
+            // Global role
+            {"type": "added", "_id": "admin", "u": {"_id": "user5", "username": "bla"}}
+        ]));
+        assert!(m.role("GENERAL", "Lymsiu4Mn6xjTAan4").unwrap().is_leader);
+        assert!(m.role("room2", "user5").unwrap().is_moderator);
+
+        m.apply_roles_change(&serde_json::json!([
+            {"type": "removed", "_id": "moderator", "u": {"_id": "user5", "username": "bla"}, "scope": "room2"}
+        ]));
+        assert!(m.role("room2", "user5").is_none());
+    }
+}
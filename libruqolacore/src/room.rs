@@ -6,7 +6,9 @@
 
 use serde::Deserialize;
 
+use crate::autotranslate::roomautotranslatesettings::RoomAutoTranslateSettings;
 use crate::messages::Messages;
+use crate::notification::roomnotificationsettings::RoomNotificationSettings;
 
 #[derive(Default, Deserialize, Clone, Debug, PartialEq)]
 pub enum RoomType {
@@ -58,6 +60,15 @@ pub struct Room {
     pub open: bool,
     #[serde(default)] // Return false by default
     pub alert: bool,
+    // Roles of the user in this room (owner, moderator, leader)
+    #[serde(default)]
+    pub roles: Vec<String>,
+    // Notification preferences from subscription
+    #[serde(flatten)]
+    pub notification_settings: RoomNotificationSettings,
+    // Auto translate preferences from subscription
+    #[serde(flatten)]
+    pub auto_translate_settings: RoomAutoTranslateSettings,
 
     #[serde(default)] // Return false by default
     pub was_initialized: bool,
@@ -83,7 +94,24 @@ impl Room {
     }
 
     pub fn parse_subscription_room(&mut self, json: &serde_json::Value) {
-        // TODO
+        // TODO parse other subscription values
+        if let Ok(settings) = serde_json::from_value::<RoomNotificationSettings>(json.clone()) {
+            self.notification_settings = settings;
+        }
+        if let Ok(settings) = serde_json::from_value::<RoomAutoTranslateSettings>(json.clone()) {
+            self.auto_translate_settings = settings;
+        }
+        // Room roles are only sent in subscription
+        self.roles = json["roles"]
+            .as_array()
+            .map(|roles| {
+                roles
+                    .iter()
+                    .filter_map(|r| r.as_str())
+                    .map(|r| r.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
     }
 
     pub fn parse_update_room(&mut self, json: &serde_json::Value) {
@@ -140,6 +168,9 @@ impl Room {
 
 #[cfg(test)]
 mod tests {
+    use crate::notification::roomnotificationsettings::{
+        NotificationPreference, RoomNotificationSettings,
+    };
     use crate::room::{Room, RoomType};
 
     #[test]
@@ -160,5 +191,39 @@ mod tests {
         assert!(!b.favorite);
         assert!(!b.alert);
         assert_eq!(b.channel_type, RoomType::Unknown);
+        assert_eq!(b.notification_settings, RoomNotificationSettings::default());
+        assert!(!b.auto_translate_settings.auto_translate);
+    }
+
+    #[test]
+    fn test_parse_subscription_room() {
+        let json: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("src/data/room/subscription.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        let mut b = Room::new();
+        b.parse_subscription_room(&json);
+        let settings = &b.notification_settings;
+        assert_eq!(
+            settings.desktop_notifications,
+            NotificationPreference::Mentions
+        );
+        assert_eq!(
+            settings.mobile_push_notifications,
+            NotificationPreference::Nothing
+        );
+        assert_eq!(
+            settings.email_notifications,
+            NotificationPreference::Default
+        );
+        assert_eq!(settings.audio_notification_value, "beep");
+        assert!(settings.hide_unread_status);
+        assert!(settings.mute_group_mentions);
+        assert!(!settings.disable_notifications);
+        assert!(settings.hide_mention_status);
+        assert!(b.auto_translate_settings.auto_translate);
+        assert_eq!(b.auto_translate_settings.auto_translate_language, "fr");
+        assert_eq!(b.roles, vec!["owner"]);
     }
 }
@@ -0,0 +1,658 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+// Room type agnostic access to the channels.* / groups.* / im.* REST API.
+// Public rooms use channels.*, private rooms use groups.*, direct messages use im.*.
+
+use crate::room::{Room, RoomType};
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, ChannelArchiveMethod, ChannelCountersMethod, ChannelCreateMethod,
+    ChannelFilesMethod, ChannelHistoryMethod, ChannelInviteMethod, ChannelKickMethod,
+    ChannelLeaveMethod, ChannelMembersMethod, ChannelRenameMethod, ChannelRolesMethod,
+    ChannelSetAnnouncementMethod, ChannelSetDescriptionMethod, ChannelSetReadOnlyMethod,
+    ChannelSetTopicMethod, ChannelSetTypeMethod, GroupArchiveMethod, GroupCountersMethod,
+    GroupCreateMethod, GroupFilesMethod, GroupHistoryMethod, GroupInviteMethod, GroupKickMethod,
+    GroupLeaveMethod, GroupMembersMethod, GroupRenameMethod, GroupRolesMethod,
+    GroupSetAnnouncementMethod, GroupSetDescriptionMethod, GroupSetReadOnlyMethod,
+    GroupSetTopicMethod, GroupSetTypeMethod, ImCloseMethod, ImCreateMethod, ImFilesMethod,
+    ImHistoryMethod, ImMembersMethod, ImSetTopicMethod, RoomsSaveNotificationInfo,
+    RoomsSaveNotificationMethod,
+};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RoomRestApiError {
+    UnsupportedRoomType(RoomType),
+    RestApi(Error),
+    InvalidResponse(String),
+}
+
+impl From<Error> for RoomRestApiError {
+    fn from(error: Error) -> Self {
+        RoomRestApiError::RestApi(error)
+    }
+}
+
+/* Debug output for RoomRestApiError */
+impl fmt::Display for RoomRestApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoomRestApiError::UnsupportedRoomType(room_type) => {
+                write!(f, "Unsupported room type: {:?}", room_type)
+            }
+            RoomRestApiError::RestApi(error) => write!(f, "Rest api error: {:?}", error),
+            RoomRestApiError::InvalidResponse(response) => {
+                write!(f, "Invalid response: {}", response)
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RoomRestApi {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+}
+
+impl Default for RoomRestApi {
+    fn default() -> Self {
+        RoomRestApi::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl RoomRestApi {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        RoomRestApi {
+            settings,
+            server_url,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        members: Option<Vec<String>>,
+        read_only: bool,
+        room_type: RoomType,
+    ) -> Result<String, RoomRestApiError> {
+        match room_type {
+            RoomType::Channel => Ok(ChannelCreateMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                name: name.to_string(),
+                members,
+                read_only: Some(read_only),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupCreateMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                name: name.to_string(),
+                members,
+                read_only: Some(read_only),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(room_type)),
+        }
+    }
+
+    pub async fn invite(&self, room: &Room, user_id: &str) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelInviteMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                user_id: user_id.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupInviteMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                user_id: user_id.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn kick(&self, room: &Room, user_id: &str) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelKickMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                user_id: user_id.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupKickMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                user_id: user_id.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn leave(&self, room: &Room) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelLeaveMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupLeaveMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            // A direct message can't be left, only closed (hidden from the room list).
+            RoomType::Direct => Ok(ImCloseMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn rename(&self, room: &Room, name: &str) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelRenameMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                name: name.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupRenameMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                name: name.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn set_topic(&self, room: &Room, topic: &str) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelSetTopicMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                topic: topic.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupSetTopicMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                topic: topic.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Direct => Ok(ImSetTopicMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                topic: topic.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn set_description(
+        &self,
+        room: &Room,
+        description: &str,
+    ) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelSetDescriptionMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                description: description.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupSetDescriptionMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                description: description.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn set_announcement(
+        &self,
+        room: &Room,
+        announcement: &str,
+    ) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelSetAnnouncementMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                announcement: announcement.to_string(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupSetAnnouncementMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                announcement: announcement.to_string(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    // Convert room to a public (RoomType::Channel) or private (RoomType::Private) room.
+    pub async fn set_type(
+        &self,
+        room: &Room,
+        room_type: RoomType,
+    ) -> Result<String, RoomRestApiError> {
+        let is_private = match room_type {
+            RoomType::Channel => false,
+            RoomType::Private => true,
+            _ => return Err(RoomRestApiError::UnsupportedRoomType(room_type)),
+        };
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelSetTypeMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                is_private,
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupSetTypeMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                is_private,
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn set_read_only(
+        &self,
+        room: &Room,
+        read_only: bool,
+    ) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelSetReadOnlyMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                read_only,
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupSetReadOnlyMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                read_only,
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn archive(&self, room: &Room, archive: bool) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelArchiveMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                archive,
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupArchiveMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                archive,
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn members(
+        &self,
+        room: &Room,
+        offset: Option<i64>,
+        count: Option<i64>,
+    ) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelMembersMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                offset,
+                count,
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupMembersMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                offset,
+                count,
+            }
+            .call()
+            .await?),
+            RoomType::Direct => Ok(ImMembersMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                offset,
+                count,
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn roles(&self, room: &Room) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelRolesMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupRolesMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn history(
+        &self,
+        room: &Room,
+        latest: Option<String>,
+        oldest: Option<String>,
+        count: Option<i64>,
+    ) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelHistoryMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+                latest,
+                oldest,
+                count,
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupHistoryMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                latest,
+                oldest,
+                count,
+            }
+            .call()
+            .await?),
+            RoomType::Direct => Ok(ImHistoryMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                latest,
+                oldest,
+                count,
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn files(&self, room: &Room) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelFilesMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupFilesMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            RoomType::Direct => Ok(ImFilesMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+                ..Default::default()
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    pub async fn counters(&self, room: &Room) -> Result<String, RoomRestApiError> {
+        match room.channel_type {
+            RoomType::Channel => Ok(ChannelCountersMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                channel_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            RoomType::Private => Ok(GroupCountersMethod {
+                settings: self.settings.clone(),
+                server_url: self.server_url.clone(),
+                room_id: room.room_id.clone(),
+            }
+            .call()
+            .await?),
+            _ => Err(RoomRestApiError::UnsupportedRoomType(
+                room.channel_type.clone(),
+            )),
+        }
+    }
+
+    // Changes are received by "subscriptions-changed" stream.
+    pub async fn save_notification(
+        &self,
+        room: &Room,
+        notifications: RoomsSaveNotificationInfo,
+    ) -> Result<String, RoomRestApiError> {
+        Ok(RoomsSaveNotificationMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            room_id: room.room_id.clone(),
+            notifications,
+        }
+        .call()
+        .await?)
+    }
+
+    // Open the direct message with usernames, it is created when it doesn't exist yet.
+    pub async fn open_direct_message(
+        &self,
+        usernames: Vec<String>,
+    ) -> Result<Room, RoomRestApiError> {
+        let result = ImCreateMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            usernames: usernames.clone(),
+            ..Default::default()
+        }
+        .call()
+        .await?;
+        parse_direct_message_room(&result, &usernames)
+            .ok_or(RoomRestApiError::InvalidResponse(result))
+    }
+}
+
+// Parse im.create result.
+pub fn parse_direct_message_room(json: &str, usernames: &[String]) -> Option<Room> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let room_value = value.get("room")?;
+    room_value.get("_id")?.as_str()?;
+    let mut room = Room::new();
+    room.parse_insert_room(room_value);
+    room.channel_type = RoomType::Direct;
+    room.open = true;
+    if room.name.is_empty() {
+        room.name = usernames.join(", ");
+    }
+    if room.fname.is_empty() {
+        room.fname = room.name.clone();
+    }
+    Some(room)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::room::{Room, RoomType};
+    use crate::roomrestapi::{RoomRestApi, RoomRestApiError, parse_direct_message_room};
+    use librocketchatrestapi::Error;
+
+    #[tokio::test]
+    async fn test_unsupported_room_type() {
+        let api = RoomRestApi::default();
+        let room = Room {
+            room_id: "foo".to_string(),
+            channel_type: RoomType::Unknown,
+            ..Default::default()
+        };
+        assert!(matches!(
+            api.leave(&room).await,
+            Err(RoomRestApiError::UnsupportedRoomType(RoomType::Unknown))
+        ));
+        assert!(matches!(
+            api.create("bla", None, false, RoomType::Unknown).await,
+            Err(RoomRestApiError::UnsupportedRoomType(RoomType::Unknown))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_leave_direct_message() {
+        let api = RoomRestApi::default();
+        let room = Room {
+            room_id: "foo".to_string(),
+            channel_type: RoomType::Direct,
+            ..Default::default()
+        };
+        // im.close is called, no settings
+        assert!(matches!(
+            api.leave(&room).await,
+            Err(RoomRestApiError::RestApi(Error::MissingSettings))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_set_type_target() {
+        let api = RoomRestApi::default();
+        let room = Room {
+            room_id: "foo".to_string(),
+            channel_type: RoomType::Channel,
+            ..Default::default()
+        };
+        assert!(matches!(
+            api.set_type(&room, RoomType::Direct).await,
+            Err(RoomRestApiError::UnsupportedRoomType(RoomType::Direct))
+        ));
+    }
+
+    #[test]
+    fn test_parse_direct_message_room() {
+        let json = std::fs::read_to_string("src/data/roomrestapi/imcreate.json")
+            .expect("Failed to open file");
+        let room = parse_direct_message_room(&json, &["foo".to_string()]).unwrap();
+        assert_eq!(room.room_id, "Lymsiu4Mn6xjTAan4RtMDEYc28fQ5aHpf4");
+        assert_eq!(room.channel_type, RoomType::Direct);
+        assert_eq!(room.name, "foo");
+        assert_eq!(room.fname, "foo");
+        assert!(room.open);
+
+        assert!(parse_direct_message_room("{\"success\": false}", &[]).is_none());
+    }
+}
@@ -12,6 +12,9 @@ use serde_json::Value;
 #[derive(Clone, Deserialize, Debug, PartialEq)]
 pub struct Rooms {
     pub rooms: Vec<Room>,
+    // Subscriptions received before their room (subscriptions.get is answered before rooms.get)
+    #[serde(skip)]
+    pending_subscriptions: Vec<Value>,
 }
 
 impl Default for Rooms {
@@ -24,6 +27,7 @@ impl Rooms {
     pub fn new() -> Self {
         Rooms {
             rooms: Vec::<Room>::default(),
+            pending_subscriptions: Vec::<Value>::default(),
         }
     }
     pub fn parse_elements(&mut self, json: &str) {
@@ -57,6 +61,13 @@ impl Rooms {
             let mut r = Room::new();
             r.parse_insert_room(room);
             if r.is_valid() {
+                if let Some(index) = self
+                    .pending_subscriptions
+                    .iter()
+                    .position(|subscription| subscription["rid"].as_str() == Some(&r.room_id))
+                {
+                    r.parse_subscription_room(&self.pending_subscriptions.remove(index));
+                }
                 self.rooms.push(r);
             }
         });
@@ -67,6 +78,38 @@ impl Rooms {
             *self = val
         }
     }
+
+    pub fn find_room(&self, room_id: &str) -> Option<&Room> {
+        self.rooms.iter().find(|room| room.room_id == room_id)
+    }
+
+    pub fn find_room_mut(&mut self, room_id: &str) -> Option<&mut Room> {
+        self.rooms.iter_mut().find(|room| room.room_id == room_id)
+    }
+
+    // Apply subscriptions.get result (or subscription from stream) to known rooms.
+    pub fn parse_subscriptions(&mut self, subscriptions: &[Value]) {
+        for subscription in subscriptions {
+            let Some(room_id) = subscription["rid"].as_str() else {
+                continue;
+            };
+            if let Some(room) = self.find_room_mut(room_id) {
+                room.parse_subscription_room(subscription);
+            } else {
+                // Applied when room is inserted
+                self.pending_subscriptions
+                    .retain(|pending| pending["rid"].as_str() != Some(room_id));
+                self.pending_subscriptions.push(subscription.clone());
+            }
+        }
+    }
+
+    // "<user_id>/subscriptions-changed" stream: ["inserted"|"updated"|"removed", {subscription}]
+    pub fn apply_subscriptions_changed(&mut self, value: &Value) {
+        if matches!(value[0].as_str(), Some("inserted") | Some("updated")) {
+            self.parse_subscriptions(std::slice::from_ref(&value[1]));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -85,5 +128,49 @@ mod tests {
     fn test_is_empty() {
         let b = Rooms::new();
         assert!(b.rooms.is_empty());
+        assert!(b.find_room("GENERAL").is_none());
+    }
+
+    #[test]
+    fn test_apply_subscriptions_changed() {
+        let mut b = Rooms::new();
+        b.parse_insert_rooms(&[
+            serde_json::json!({"_id": "Fc4EWcQXCkJkJ7QEQ", "t": "c", "name": "foo"}),
+        ]);
+        let subscription: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("src/data/room/subscription.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        b.apply_subscriptions_changed(&serde_json::json!(["removed", subscription]));
+        assert!(
+            !b.find_room("Fc4EWcQXCkJkJ7QEQ")
+                .unwrap()
+                .notification_settings
+                .hide_unread_status
+        );
+        b.apply_subscriptions_changed(&serde_json::json!(["updated", subscription]));
+        let room = b.find_room("Fc4EWcQXCkJkJ7QEQ").unwrap();
+        assert!(room.notification_settings.hide_unread_status);
+        assert_eq!(room.notification_settings.audio_notification_value, "beep");
+    }
+
+    #[test]
+    fn test_parse_subscriptions_before_rooms() {
+        let mut b = Rooms::new();
+        let subscription: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string("src/data/room/subscription.json")
+                .expect("Failed to open file"),
+        )
+        .unwrap();
+        b.parse_subscriptions(&[subscription]);
+        assert!(b.find_room("Fc4EWcQXCkJkJ7QEQ").is_none());
+        b.parse_insert_rooms(&[
+            serde_json::json!({"_id": "Fc4EWcQXCkJkJ7QEQ", "t": "c", "name": "foo"}),
+        ]);
+        let room = b.find_room("Fc4EWcQXCkJkJ7QEQ").unwrap();
+        assert!(room.notification_settings.hide_unread_status);
+        assert!(room.auto_translate_settings.auto_translate);
+        assert_eq!(room.roles, vec!["owner"]);
     }
 }
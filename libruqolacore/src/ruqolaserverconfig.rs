@@ -0,0 +1,585 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ruqolaserverconfigpassword::RuqolaServerConfigPassword;
+use chrono::TimeDelta;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, SettingsMethod, SettingsOauthMethod, SettingsPublicMethod,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Settings used by client.
+pub const SERVER_SETTINGS: &[&str] = &[
+    "Site_Name",
+    "Site_Url",
+    "Accounts_ShowFormLogin",
+    "Accounts_RegistrationForm",
+    "Accounts_PasswordReset",
+    "Accounts_AllowPasswordChange",
+    "Accounts_EmailVerification",
+    "Accounts_ManuallyApproveNewUsers",
+    "Accounts_RequireNameForSignUp",
+    "Accounts_TwoFactorAuthentication_Enabled",
+    "LDAP_Enable",
+    "Accounts_Password_Policy_Enabled",
+    "Accounts_Password_Policy_MinLength",
+    "Accounts_Password_Policy_MaxLength",
+    "Accounts_Password_Policy_ForbidRepeatingCharacters",
+    "Accounts_Password_Policy_ForbidRepeatingCharactersCount",
+    "Accounts_Password_Policy_AtLeastOneLowercase",
+    "Accounts_Password_Policy_AtLeastOneUppercase",
+    "Accounts_Password_Policy_AtLeastOneNumber",
+    "Accounts_Password_Policy_AtLeastOneSpecialCharacter",
+    "FileUpload_Enabled",
+    "FileUpload_MaxFileSize",
+    "FileUpload_MediaTypeWhiteList",
+    "FileUpload_MediaTypeBlackList",
+    "Message_MaxAllowedSize",
+    "Message_AllowEditing",
+    "Message_AllowEditing_BlockEditInMinutes",
+    "Message_AllowDeleting",
+    "Message_AllowDeleting_BlockDeleteInMinutes",
+    "Threads_enabled",
+    "Discussion_enabled",
+    "E2E_Enable",
+    "AutoTranslate_Enabled",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoginMethod {
+    Password,
+    Ldap,
+    // OAuth service name ("google", "github", …)
+    Oauth(String),
+}
+
+#[derive(Deserialize)]
+struct SettingValue {
+    #[serde(rename = "_id")]
+    identifier: String,
+    #[serde(default)]
+    value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct SettingsResult {
+    #[serde(default)]
+    settings: Vec<SettingValue>,
+}
+
+#[derive(Deserialize)]
+struct OauthService {
+    #[serde(default)]
+    service: String,
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct OauthResult {
+    #[serde(default)]
+    services: Vec<OauthService>,
+}
+
+#[derive(Clone)]
+pub struct RuqolaServerConfig {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    // Setting identifier => value
+    pub values: HashMap<String, serde_json::Value>,
+    pub oauth_services: Vec<String>,
+}
+
+impl Default for RuqolaServerConfig {
+    fn default() -> Self {
+        RuqolaServerConfig::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl RuqolaServerConfig {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        RuqolaServerConfig {
+            settings,
+            server_url,
+            values: HashMap::new(),
+            oauth_services: Vec::new(),
+        }
+    }
+
+    // Parse settings.public/settings result, returns number of settings.
+    pub fn parse_settings(&mut self, json: &str) -> usize {
+        match serde_json::from_str::<SettingsResult>(json) {
+            Ok(result) => {
+                let count = result.settings.len();
+                for setting in result.settings {
+                    self.values.insert(setting.identifier, setting.value);
+                }
+                count
+            }
+            Err(_) => 0,
+        }
+    }
+
+    pub fn parse_oauth_settings(&mut self, json: &str) {
+        if let Ok(result) = serde_json::from_str::<OauthResult>(json) {
+            self.oauth_services = result
+                .services
+                .into_iter()
+                .map(|s| {
+                    if s.service.is_empty() {
+                        s.name
+                    } else {
+                        s.service
+                    }
+                })
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+    }
+
+    // "public-settings-changed"/"private-settings-changed" streams:
+    // ["updated"|"inserted"|"removed", {"_id": …, "value": …}]
+    pub fn apply_settings_changed(&mut self, value: &serde_json::Value) {
+        let (Some(action), Some(identifier)) = (value[0].as_str(), value[1]["_id"].as_str()) else {
+            return;
+        };
+        match action {
+            "removed" => {
+                self.values.remove(identifier);
+            }
+            "updated" | "inserted" | "changed" => {
+                if let Some(setting_value) = value[1].get("value") {
+                    self.values
+                        .insert(identifier.to_string(), setting_value.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Public settings are available before login.
+    pub async fn load_public_settings(&mut self) -> Result<(), Error> {
+        let result = SettingsPublicMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: self.server_url.clone(),
+            ids: SERVER_SETTINGS.iter().map(|s| s.to_string()).collect(),
+            count: Some(SERVER_SETTINGS.len() as i64),
+            ..Default::default()
+        }
+        .call()
+        .await?;
+        self.parse_settings(&result);
+
+        let result = SettingsOauthMethod {
+            settings: AuthenticationType::NoAuthRequired,
+            server_url: self.server_url.clone(),
+        }
+        .call()
+        .await?;
+        self.parse_oauth_settings(&result);
+        Ok(())
+    }
+
+    pub async fn load_private_settings(&mut self) -> Result<(), Error> {
+        let result = SettingsMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            ids: SERVER_SETTINGS.iter().map(|s| s.to_string()).collect(),
+            count: Some(SERVER_SETTINGS.len() as i64),
+            ..Default::default()
+        }
+        .call()
+        .await?;
+        self.parse_settings(&result);
+        Ok(())
+    }
+
+    pub fn value(&self, identifier: &str) -> Option<&serde_json::Value> {
+        self.values.get(identifier)
+    }
+
+    pub fn bool_value(&self, identifier: &str, default_value: bool) -> bool {
+        self.value(identifier)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(default_value)
+    }
+
+    pub fn int_value(&self, identifier: &str, default_value: i64) -> i64 {
+        self.value(identifier)
+            .and_then(|v| v.as_i64())
+            .unwrap_or(default_value)
+    }
+
+    pub fn string_value(&self, identifier: &str) -> &str {
+        self.value(identifier)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    pub fn site_name(&self) -> &str {
+        self.string_value("Site_Name")
+    }
+
+    pub fn file_upload_enabled(&self) -> bool {
+        self.bool_value("FileUpload_Enabled", true)
+    }
+
+    // In bytes, negative value means no limit.
+    pub fn file_upload_max_file_size(&self) -> i64 {
+        self.int_value("FileUpload_MaxFileSize", 104857600)
+    }
+
+    pub fn allowed_mime_types(&self) -> Vec<String> {
+        split_list(self.string_value("FileUpload_MediaTypeWhiteList"))
+    }
+
+    pub fn blocked_mime_types(&self) -> Vec<String> {
+        split_list(self.string_value("FileUpload_MediaTypeBlackList"))
+    }
+
+    // Empty allowed list accepts all types not blocked. "image/*" matches all images.
+    pub fn is_mime_type_allowed(&self, mime_type: &str) -> bool {
+        if self
+            .blocked_mime_types()
+            .iter()
+            .any(|pattern| mime_type_matches(pattern, mime_type))
+        {
+            return false;
+        }
+        let allowed = self.allowed_mime_types();
+        allowed.is_empty()
+            || allowed
+                .iter()
+                .any(|pattern| mime_type_matches(pattern, mime_type))
+    }
+
+    pub fn can_upload_file(&self, size: i64, mime_type: &str) -> bool {
+        let max_size = self.file_upload_max_file_size();
+        self.file_upload_enabled()
+            && (max_size < 0 || size <= max_size)
+            && self.is_mime_type_allowed(mime_type)
+    }
+
+    pub fn message_max_length(&self) -> i64 {
+        self.int_value("Message_MaxAllowedSize", 5000)
+    }
+
+    pub fn allow_message_editing(&self) -> bool {
+        self.bool_value("Message_AllowEditing", true)
+    }
+
+    // None when there is no time limit.
+    pub fn message_edit_time_limit(&self) -> Option<TimeDelta> {
+        time_limit(self.int_value("Message_AllowEditing_BlockEditInMinutes", 0))
+    }
+
+    pub fn allow_message_deleting(&self) -> bool {
+        self.bool_value("Message_AllowDeleting", true)
+    }
+
+    pub fn message_delete_time_limit(&self) -> Option<TimeDelta> {
+        time_limit(self.int_value("Message_AllowDeleting_BlockDeleteInMinutes", 0))
+    }
+
+    // elapsed: time since message was sent
+    pub fn can_edit_message(&self, elapsed: TimeDelta) -> bool {
+        self.allow_message_editing()
+            && self
+                .message_edit_time_limit()
+                .is_none_or(|limit| elapsed < limit)
+    }
+
+    pub fn can_delete_message(&self, elapsed: TimeDelta) -> bool {
+        self.allow_message_deleting()
+            && self
+                .message_delete_time_limit()
+                .is_none_or(|limit| elapsed < limit)
+    }
+
+    pub fn threads_enabled(&self) -> bool {
+        self.bool_value("Threads_enabled", true)
+    }
+
+    pub fn discussions_enabled(&self) -> bool {
+        self.bool_value("Discussion_enabled", true)
+    }
+
+    pub fn e2e_enabled(&self) -> bool {
+        self.bool_value("E2E_Enable", false)
+    }
+
+    pub fn auto_translate_enabled(&self) -> bool {
+        self.bool_value("AutoTranslate_Enabled", false)
+    }
+
+    pub fn two_factor_authentication_enabled(&self) -> bool {
+        self.bool_value("Accounts_TwoFactorAuthentication_Enabled", true)
+    }
+
+    // "Public", "Disabled" or "Secret URL"
+    pub fn registration_form(&self) -> &str {
+        match self.string_value("Accounts_RegistrationForm") {
+            "" => "Public",
+            form => form,
+        }
+    }
+
+    pub fn registration_enabled(&self) -> bool {
+        self.registration_form() == "Public"
+    }
+
+    pub fn password_reset_enabled(&self) -> bool {
+        self.bool_value("Accounts_PasswordReset", true)
+    }
+
+    pub fn allow_password_change(&self) -> bool {
+        self.bool_value("Accounts_AllowPasswordChange", true)
+    }
+
+    pub fn email_verification_required(&self) -> bool {
+        self.bool_value("Accounts_EmailVerification", false)
+    }
+
+    pub fn manually_approve_new_users(&self) -> bool {
+        self.bool_value("Accounts_ManuallyApproveNewUsers", false)
+    }
+
+    pub fn require_name_for_signup(&self) -> bool {
+        self.bool_value("Accounts_RequireNameForSignUp", true)
+    }
+
+    pub fn login_methods(&self) -> Vec<LoginMethod> {
+        let mut methods = Vec::new();
+        if self.bool_value("Accounts_ShowFormLogin", true) {
+            methods.push(LoginMethod::Password);
+        }
+        if self.bool_value("LDAP_Enable", false) {
+            methods.push(LoginMethod::Ldap);
+        }
+        methods.extend(
+            self.oauth_services
+                .iter()
+                .map(|service| LoginMethod::Oauth(service.clone())),
+        );
+        methods
+    }
+
+    // Server defaults are used for missing settings.
+    pub fn password_settings(&self) -> RuqolaServerConfigPassword {
+        let default = RuqolaServerConfigPassword::new();
+        RuqolaServerConfigPassword {
+            accounts_password_policy_min_length: self.int_value(
+                "Accounts_Password_Policy_MinLength",
+                default.accounts_password_policy_min_length,
+            ),
+            accounts_password_policy_max_length: self.int_value(
+                "Accounts_Password_Policy_MaxLength",
+                default.accounts_password_policy_max_length,
+            ),
+            accounts_password_policy_forbid_repeating_characters_count: self.int_value(
+                "Accounts_Password_Policy_ForbidRepeatingCharactersCount",
+                default.accounts_password_policy_forbid_repeating_characters_count,
+            ),
+            accounts_password_policy_enabled: self.bool_value(
+                "Accounts_Password_Policy_Enabled",
+                default.accounts_password_policy_enabled,
+            ),
+            accounts_password_policy_forbid_repeating_characters: self.bool_value(
+                "Accounts_Password_Policy_ForbidRepeatingCharacters",
+                default.accounts_password_policy_forbid_repeating_characters,
+            ),
+            accounts_password_policy_at_least_one_lowercase: self.bool_value(
+                "Accounts_Password_Policy_AtLeastOneLowercase",
+                default.accounts_password_policy_at_least_one_lowercase,
+            ),
+            accounts_password_policy_at_least_one_uppercase: self.bool_value(
+                "Accounts_Password_Policy_AtLeastOneUppercase",
+                default.accounts_password_policy_at_least_one_uppercase,
+            ),
+            accounts_password_policy_at_least_one_number: self.bool_value(
+                "Accounts_Password_Policy_AtLeastOneNumber",
+                default.accounts_password_policy_at_least_one_number,
+            ),
+            accounts_password_policy_at_least_one_special_character: self.bool_value(
+                "Accounts_Password_Policy_AtLeastOneSpecialCharacter",
+                default.accounts_password_policy_at_least_one_special_character,
+            ),
+        }
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn mime_type_matches(pattern: &str, mime_type: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some(prefix) => mime_type
+            .split_once('/')
+            .is_some_and(|(main_type, _)| main_type == prefix),
+        None => pattern == mime_type,
+    }
+}
+
+fn time_limit(minutes: i64) -> Option<TimeDelta> {
+    if minutes > 0 {
+        Some(TimeDelta::minutes(minutes))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ruqolaserverconfig::{LoginMethod, RuqolaServerConfig};
+    use chrono::TimeDelta;
+
+    fn generate_config() -> RuqolaServerConfig {
+        let mut config = RuqolaServerConfig::default();
+        config.parse_settings(
+            &std::fs::read_to_string("src/data/ruqolaserverconfig/settingspublic.json")
+                .expect("Failed to open file"),
+        );
+        config
+    }
+
+    #[test]
+    fn test_default_values() {
+        let config = RuqolaServerConfig::default();
+        assert!(config.values.is_empty());
+        assert!(config.site_name().is_empty());
+        assert!(config.file_upload_enabled());
+        assert_eq!(config.file_upload_max_file_size(), 104857600);
+        assert_eq!(config.message_max_length(), 5000);
+        assert!(config.message_edit_time_limit().is_none());
+        assert!(config.threads_enabled());
+        assert!(config.discussions_enabled());
+        assert!(!config.e2e_enabled());
+        assert!(!config.auto_translate_enabled());
+        assert!(config.registration_enabled());
+        assert_eq!(config.login_methods(), vec![LoginMethod::Password]);
+        assert_eq!(
+            config
+                .password_settings()
+                .accounts_password_policy_min_length,
+            12
+        );
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let config = generate_config();
+        assert_eq!(config.values.len(), 16);
+        assert_eq!(config.site_name(), "KDE Chat");
+        assert_eq!(config.file_upload_max_file_size(), 20971520);
+        assert_eq!(config.message_max_length(), 8000);
+        assert_eq!(
+            config.message_edit_time_limit(),
+            Some(TimeDelta::minutes(60))
+        );
+        assert!(config.message_delete_time_limit().is_none());
+        assert!(!config.allow_message_deleting());
+        assert!(!config.discussions_enabled());
+        assert!(config.e2e_enabled());
+        assert_eq!(config.registration_form(), "Secret URL");
+        assert!(!config.registration_enabled());
+        assert_eq!(
+            config.login_methods(),
+            vec![LoginMethod::Password, LoginMethod::Ldap]
+        );
+
+        let password = config.password_settings();
+        assert!(password.accounts_password_policy_enabled);
+        assert_eq!(password.accounts_password_policy_min_length, 8);
+        // Not in settings, default value
+        assert_eq!(password.accounts_password_policy_max_length, 24);
+
+        assert_eq!(RuqolaServerConfig::default().parse_settings("invalid"), 0);
+    }
+
+    #[test]
+    fn test_mime_types() {
+        let config = generate_config();
+        assert_eq!(
+            config.allowed_mime_types(),
+            vec!["image/*", "application/pdf", "text/plain"]
+        );
+        assert!(config.is_mime_type_allowed("image/png"));
+        assert!(config.is_mime_type_allowed("application/pdf"));
+        assert!(!config.is_mime_type_allowed("image/svg+xml"));
+        assert!(!config.is_mime_type_allowed("video/mp4"));
+        assert!(config.can_upload_file(1024, "text/plain"));
+        assert!(!config.can_upload_file(30000000, "text/plain"));
+
+        // No restriction
+        let config = RuqolaServerConfig::default();
+        assert!(config.is_mime_type_allowed("video/mp4"));
+    }
+
+    #[test]
+    fn test_edit_and_delete_time_windows() {
+        let mut config = generate_config();
+        assert!(config.can_edit_message(TimeDelta::minutes(10)));
+        assert!(!config.can_edit_message(TimeDelta::minutes(61)));
+        assert!(!config.can_delete_message(TimeDelta::minutes(1)));
+
+        config.apply_settings_changed(&serde_json::json!([
+            "updated",
+            {"_id": "Message_AllowDeleting", "value": true}
+        ]));
+        config.apply_settings_changed(&serde_json::json!([
+            "updated",
+            {"_id": "Message_AllowDeleting_BlockDeleteInMinutes", "value": 5}
+        ]));
+        assert!(config.can_delete_message(TimeDelta::minutes(1)));
+        assert!(!config.can_delete_message(TimeDelta::minutes(6)));
+    }
+
+    #[test]
+    fn test_apply_settings_changed() {
+        let mut config = generate_config();
+        config.apply_settings_changed(&serde_json::json!([
+            "updated",
+            {"_id": "Message_MaxAllowedSize", "value": 10000, "_updatedAt": {"$date": 1760000000000_i64}}
+        ]));
+        assert_eq!(config.message_max_length(), 10000);
+
+        config.apply_settings_changed(&serde_json::json!(["removed", {"_id": "E2E_Enable"}]));
+        assert!(!config.e2e_enabled());
+
+        // Invalid values are ignored
+        config.apply_settings_changed(&serde_json::json!({"_id": "Site_Name"}));
+        config.apply_settings_changed(&serde_json::json!(["updated", {"_id": "Site_Name"}]));
+        assert_eq!(config.site_name(), "KDE Chat");
+    }
+
+    #[test]
+    fn test_parse_oauth_settings() {
+        let mut config = generate_config();
+        config.parse_oauth_settings(
+            &std::fs::read_to_string("src/data/ruqolaserverconfig/settingsoauth.json")
+                .expect("Failed to open file"),
+        );
+        assert_eq!(config.oauth_services, vec!["github", "gitlab"]);
+        assert_eq!(
+            config.login_methods(),
+            vec![
+                LoginMethod::Password,
+                LoginMethod::Ldap,
+                LoginMethod::Oauth("github".to_string()),
+                LoginMethod::Oauth("gitlab".to_string())
+            ]
+        );
+    }
+}
@@ -4,23 +4,95 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
-// TODO need to implement bitwise
-/*
+use crate::ruqolaserverconfig::RuqolaServerConfig;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PasswordSettingCheck {
-   none(0), // We need to have 0!
-   minLengh(1 << 0),
-   maxLengh(1 << 1),
-   forbidRepeatingCharactersCount(1 << 2),
-   forbidRepeatingCharacters(1 << 4),
-   atLeastOneLowercase(1 << 8),
-   atLeastOneUppercase(1 << 16),
-   atLeastOneSpecialCharacter(1 << 32),
-   atLeastOneNumber(1 << 64);
-
-   final int value;
-   const PasswordSettingCheck(this.value);
- }
- */
+    MinLength = 1 << 0,
+    MaxLength = 1 << 1,
+    ForbidRepeatingCharacters = 1 << 2,
+    AtLeastOneLowercase = 1 << 3,
+    AtLeastOneUppercase = 1 << 4,
+    AtLeastOneNumber = 1 << 5,
+    AtLeastOneSpecialCharacter = 1 << 6,
+}
+
+const ALL_CHECKS: [PasswordSettingCheck; 7] = [
+    PasswordSettingCheck::MinLength,
+    PasswordSettingCheck::MaxLength,
+    PasswordSettingCheck::ForbidRepeatingCharacters,
+    PasswordSettingCheck::AtLeastOneLowercase,
+    PasswordSettingCheck::AtLeastOneUppercase,
+    PasswordSettingCheck::AtLeastOneNumber,
+    PasswordSettingCheck::AtLeastOneSpecialCharacter,
+];
+
+impl PasswordSettingCheck {
+    // Same identifiers as server errors, can be used as translation key.
+    pub fn error_key(&self) -> &'static str {
+        match self {
+            PasswordSettingCheck::MinLength => "error-password-policy-not-met-minLength",
+            PasswordSettingCheck::MaxLength => "error-password-policy-not-met-maxLength",
+            PasswordSettingCheck::ForbidRepeatingCharacters => {
+                "error-password-policy-not-met-repeatingCharacters"
+            }
+            PasswordSettingCheck::AtLeastOneLowercase => {
+                "error-password-policy-not-met-oneLowercase"
+            }
+            PasswordSettingCheck::AtLeastOneUppercase => {
+                "error-password-policy-not-met-oneUppercase"
+            }
+            PasswordSettingCheck::AtLeastOneNumber => "error-password-policy-not-met-oneNumber",
+            PasswordSettingCheck::AtLeastOneSpecialCharacter => {
+                "error-password-policy-not-met-oneSpecial"
+            }
+        }
+    }
+
+    // Untranslated text, "{}" is replaced by the policy value.
+    pub fn text(&self) -> &'static str {
+        match self {
+            PasswordSettingCheck::MinLength => "At least {} characters",
+            PasswordSettingCheck::MaxLength => "At most {} characters",
+            PasswordSettingCheck::ForbidRepeatingCharacters => "Max. {} repeating characters",
+            PasswordSettingCheck::AtLeastOneLowercase => "At least one lowercase letter",
+            PasswordSettingCheck::AtLeastOneUppercase => "At least one uppercase letter",
+            PasswordSettingCheck::AtLeastOneNumber => "At least one number",
+            PasswordSettingCheck::AtLeastOneSpecialCharacter => "At least one symbol",
+        }
+    }
+}
+
+// Set of PasswordSettingCheck
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PasswordSettingChecks(u32);
+
+impl PasswordSettingChecks {
+    pub fn new() -> Self {
+        PasswordSettingChecks(0)
+    }
+
+    pub fn insert(&mut self, check: PasswordSettingCheck) {
+        self.0 |= check as u32;
+    }
+
+    pub fn contains(&self, check: PasswordSettingCheck) -> bool {
+        self.0 & check as u32 != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PasswordSettingCheck> + '_ {
+        ALL_CHECKS.into_iter().filter(|check| self.contains(*check))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct RuqolaServerConfigPassword {
     pub accounts_password_policy_min_length: i64,
@@ -54,4 +126,240 @@ impl RuqolaServerConfigPassword {
             accounts_password_policy_at_least_one_special_character: true,
         }
     }
+
+    pub fn from_server_config(config: &RuqolaServerConfig) -> Self {
+        config.password_settings()
+    }
+
+    // Checks enabled by policy, used to show requirements in forms.
+    pub fn enabled_checks(&self) -> PasswordSettingChecks {
+        let mut checks = PasswordSettingChecks::new();
+        if !self.accounts_password_policy_enabled {
+            return checks;
+        }
+        if self.accounts_password_policy_min_length >= 1 {
+            checks.insert(PasswordSettingCheck::MinLength);
+        }
+        if self.accounts_password_policy_max_length >= 1 {
+            checks.insert(PasswordSettingCheck::MaxLength);
+        }
+        if self.accounts_password_policy_forbid_repeating_characters {
+            checks.insert(PasswordSettingCheck::ForbidRepeatingCharacters);
+        }
+        if self.accounts_password_policy_at_least_one_lowercase {
+            checks.insert(PasswordSettingCheck::AtLeastOneLowercase);
+        }
+        if self.accounts_password_policy_at_least_one_uppercase {
+            checks.insert(PasswordSettingCheck::AtLeastOneUppercase);
+        }
+        if self.accounts_password_policy_at_least_one_number {
+            checks.insert(PasswordSettingCheck::AtLeastOneNumber);
+        }
+        if self.accounts_password_policy_at_least_one_special_character {
+            checks.insert(PasswordSettingCheck::AtLeastOneSpecialCharacter);
+        }
+        checks
+    }
+
+    // Returns failed checks, empty when password is valid.
+    pub fn validate(&self, password: &str) -> PasswordSettingChecks {
+        let mut failed = PasswordSettingChecks::new();
+        let length = password.chars().count() as i64;
+        for check in self.enabled_checks().iter() {
+            let valid = match check {
+                PasswordSettingCheck::MinLength => {
+                    length >= self.accounts_password_policy_min_length
+                }
+                PasswordSettingCheck::MaxLength => {
+                    length <= self.accounts_password_policy_max_length
+                }
+                PasswordSettingCheck::ForbidRepeatingCharacters => {
+                    longest_repeat(password)
+                        <= self.accounts_password_policy_forbid_repeating_characters_count
+                }
+                PasswordSettingCheck::AtLeastOneLowercase => {
+                    password.chars().any(|c| c.is_lowercase())
+                }
+                PasswordSettingCheck::AtLeastOneUppercase => {
+                    password.chars().any(|c| c.is_uppercase())
+                }
+                PasswordSettingCheck::AtLeastOneNumber => password.chars().any(|c| c.is_numeric()),
+                PasswordSettingCheck::AtLeastOneSpecialCharacter => {
+                    password.chars().any(|c| !c.is_alphanumeric())
+                }
+            };
+            if !valid {
+                failed.insert(check);
+            }
+        }
+        failed
+    }
+
+    pub fn is_valid(&self, password: &str) -> bool {
+        self.validate(password).is_empty()
+    }
+
+    // Message with policy value, text() can be used to translate it before.
+    pub fn message(&self, check: PasswordSettingCheck) -> String {
+        self.translated_message(check, check.text())
+    }
+
+    // translated_text is the translation of check.text()
+    pub fn translated_message(&self, check: PasswordSettingCheck, translated_text: &str) -> String {
+        let value = match check {
+            PasswordSettingCheck::MinLength => self.accounts_password_policy_min_length,
+            PasswordSettingCheck::MaxLength => self.accounts_password_policy_max_length,
+            PasswordSettingCheck::ForbidRepeatingCharacters => {
+                self.accounts_password_policy_forbid_repeating_characters_count
+            }
+            _ => return translated_text.to_string(),
+        };
+        translated_text.replacen("{}", &value.to_string(), 1)
+    }
+
+    pub fn messages(&self, failed: PasswordSettingChecks) -> Vec<String> {
+        failed.iter().map(|check| self.message(check)).collect()
+    }
+}
+
+// Longest sequence of the same character
+fn longest_repeat(password: &str) -> i64 {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous: Option<char> = None;
+    for c in password.chars() {
+        if previous == Some(c) {
+            current += 1;
+        } else {
+            current = 1;
+            previous = Some(c);
+        }
+        longest = longest.max(current);
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ruqolaserverconfig::RuqolaServerConfig;
+    use crate::ruqolaserverconfigpassword::{
+        PasswordSettingCheck, PasswordSettingChecks, RuqolaServerConfigPassword,
+    };
+
+    fn generate_policy() -> RuqolaServerConfigPassword {
+        RuqolaServerConfigPassword {
+            accounts_password_policy_enabled: true,
+            accounts_password_policy_min_length: 8,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_values() {
+        let p = RuqolaServerConfigPassword::new();
+        assert!(!p.accounts_password_policy_enabled);
+        assert!(p.enabled_checks().is_empty());
+        // Policy disabled
+        assert!(p.is_valid("a"));
+    }
+
+    #[test]
+    fn test_checks() {
+        let mut checks = PasswordSettingChecks::new();
+        assert!(checks.is_empty());
+        checks.insert(PasswordSettingCheck::MaxLength);
+        checks.insert(PasswordSettingCheck::AtLeastOneNumber);
+        assert!(checks.contains(PasswordSettingCheck::MaxLength));
+        assert!(!checks.contains(PasswordSettingCheck::MinLength));
+        assert_eq!(checks.bits(), 0b100010);
+        assert_eq!(
+            checks.iter().collect::<Vec<_>>(),
+            vec![
+                PasswordSettingCheck::MaxLength,
+                PasswordSettingCheck::AtLeastOneNumber
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let p = generate_policy();
+        assert_eq!(p.enabled_checks().iter().count(), 7);
+        assert!(p.is_valid("Secret-42"));
+
+        let failed = p.validate("abc");
+        assert_eq!(
+            failed.iter().collect::<Vec<_>>(),
+            vec![
+                PasswordSettingCheck::MinLength,
+                PasswordSettingCheck::AtLeastOneUppercase,
+                PasswordSettingCheck::AtLeastOneNumber,
+                PasswordSettingCheck::AtLeastOneSpecialCharacter,
+            ]
+        );
+
+        let failed = p.validate("SECRET-4222");
+        assert!(failed.contains(PasswordSettingCheck::AtLeastOneLowercase));
+        assert!(!failed.contains(PasswordSettingCheck::ForbidRepeatingCharacters));
+        let failed = p.validate("SECRET-42222");
+        assert!(failed.contains(PasswordSettingCheck::ForbidRepeatingCharacters));
+
+        let failed = p.validate("Secret-42-Secret-42-Secret-42");
+        assert_eq!(
+            failed.iter().collect::<Vec<_>>(),
+            vec![PasswordSettingCheck::MaxLength]
+        );
+
+        // Unicode letters are counted as one character
+        assert!(p.is_valid("Éléphant-1"));
+    }
+
+    #[test]
+    fn test_disabled_checks() {
+        let p = RuqolaServerConfigPassword {
+            accounts_password_policy_at_least_one_special_character: false,
+            accounts_password_policy_forbid_repeating_characters: false,
+            accounts_password_policy_max_length: -1,
+            ..generate_policy()
+        };
+        assert!(p.is_valid("Secret4222222222222222222222222"));
+    }
+
+    #[test]
+    fn test_messages() {
+        let p = generate_policy();
+        let failed = p.validate("aaaa");
+        assert_eq!(
+            p.messages(failed),
+            vec![
+                "At least 8 characters",
+                "Max. 3 repeating characters",
+                "At least one uppercase letter",
+                "At least one number",
+                "At least one symbol",
+            ]
+        );
+        assert_eq!(
+            p.translated_message(PasswordSettingCheck::MinLength, "Au moins {} caractères"),
+            "Au moins 8 caractères"
+        );
+        assert_eq!(
+            PasswordSettingCheck::MinLength.error_key(),
+            "error-password-policy-not-met-minLength"
+        );
+    }
+
+    #[test]
+    fn test_from_server_config() {
+        let mut config = RuqolaServerConfig::default();
+        config.parse_settings(
+            &std::fs::read_to_string("src/data/ruqolaserverconfig/settingspublic.json")
+                .expect("Failed to open file"),
+        );
+        let p = RuqolaServerConfigPassword::from_server_config(&config);
+        assert!(p.accounts_password_policy_enabled);
+        assert_eq!(p.accounts_password_policy_min_length, 8);
+        assert!(!p.validate("Short1!").is_empty());
+        assert!(p.is_valid("Longer1!"));
+    }
 }
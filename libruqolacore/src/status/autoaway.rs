@@ -0,0 +1,159 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+use crate::user::Status;
+use std::time::{Duration, Instant};
+
+// Set user away after idle time, restore previous status on activity.
+// Caller sends "UserPresence:away"/"UserPresence:online" when status must change.
+#[derive(Clone, Debug)]
+pub struct AutoAway {
+    pub enabled: bool,
+    pub idle_time_limit: Duration,
+    last_activity: Instant,
+    // Status before auto away, None when user is not auto away
+    previous_status: Option<Status>,
+}
+
+impl Default for AutoAway {
+    fn default() -> Self {
+        AutoAway::new()
+    }
+}
+
+impl AutoAway {
+    pub fn new() -> Self {
+        AutoAway {
+            enabled: false,
+            idle_time_limit: Duration::ZERO,
+            last_activity: Instant::now(),
+            previous_status: None,
+        }
+    }
+
+    // idle_time_limit is in seconds, auto away is disabled when it's not positive.
+    pub fn set_preferences(&mut self, preferences: &OwnUserPreferences) {
+        self.idle_time_limit = Duration::from_secs(preferences.idle_time_limit.max(0) as u64);
+        self.enabled = preferences.enable_auto_away && !self.idle_time_limit.is_zero();
+    }
+
+    pub fn is_auto_away(&self) -> bool {
+        self.previous_status.is_some()
+    }
+
+    // Returns status to restore when user was set away automatically.
+    pub fn user_activity(&mut self, now: Instant) -> Option<Status> {
+        self.last_activity = now;
+        self.previous_status.take()
+    }
+
+    // Returns true when user must be set away.
+    // Only online users are set away, busy or offline status are kept.
+    pub fn check_idle(&mut self, now: Instant, current_status: &Status) -> bool {
+        if !self.enabled || self.is_auto_away() || *current_status != Status::Online {
+            return false;
+        }
+        if now.saturating_duration_since(self.last_activity) < self.idle_time_limit {
+            return false;
+        }
+        self.previous_status = Some(current_status.clone());
+        true
+    }
+
+    // Time before next check_idle can set user away.
+    pub fn remaining_time(&self, now: Instant) -> Option<Duration> {
+        if !self.enabled || self.is_auto_away() {
+            return None;
+        }
+        Some(
+            self.idle_time_limit
+                .saturating_sub(now.saturating_duration_since(self.last_activity)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+    use crate::status::autoaway::AutoAway;
+    use crate::user::Status;
+    use std::time::{Duration, Instant};
+
+    fn generate_auto_away(idle_time_limit: i64) -> AutoAway {
+        let mut auto_away = AutoAway::new();
+        auto_away.set_preferences(&OwnUserPreferences {
+            enable_auto_away: true,
+            idle_time_limit,
+            ..Default::default()
+        });
+        auto_away
+    }
+
+    #[test]
+    fn test_default_values() {
+        let auto_away = AutoAway::new();
+        assert!(!auto_away.enabled);
+        assert!(!auto_away.is_auto_away());
+        assert!(auto_away.remaining_time(Instant::now()).is_none());
+    }
+
+    #[test]
+    fn test_set_preferences() {
+        let auto_away = generate_auto_away(300);
+        assert!(auto_away.enabled);
+        assert_eq!(auto_away.idle_time_limit, Duration::from_secs(300));
+
+        assert!(!generate_auto_away(-1).enabled);
+        assert!(!generate_auto_away(0).enabled);
+
+        let mut auto_away = AutoAway::new();
+        auto_away.set_preferences(&OwnUserPreferences {
+            enable_auto_away: false,
+            idle_time_limit: 300,
+            ..Default::default()
+        });
+        assert!(!auto_away.enabled);
+    }
+
+    #[test]
+    fn test_check_idle() {
+        let mut auto_away = generate_auto_away(300);
+        let start = Instant::now();
+        auto_away.user_activity(start);
+        assert!(!auto_away.check_idle(start + Duration::from_secs(299), &Status::Online));
+        assert_eq!(
+            auto_away.remaining_time(start + Duration::from_secs(200)),
+            Some(Duration::from_secs(100))
+        );
+        // Busy users are not set away
+        assert!(!auto_away.check_idle(start + Duration::from_secs(400), &Status::Busy));
+        assert!(auto_away.check_idle(start + Duration::from_secs(300), &Status::Online));
+        assert!(auto_away.is_auto_away());
+        // Already away
+        assert!(!auto_away.check_idle(start + Duration::from_secs(600), &Status::Away));
+
+        assert_eq!(
+            auto_away.user_activity(start + Duration::from_secs(700)),
+            Some(Status::Online)
+        );
+        assert!(!auto_away.is_auto_away());
+        assert!(
+            auto_away
+                .user_activity(start + Duration::from_secs(710))
+                .is_none()
+        );
+        assert!(!auto_away.check_idle(start + Duration::from_secs(900), &Status::Online));
+    }
+
+    #[test]
+    fn test_check_idle_disabled() {
+        let mut auto_away = generate_auto_away(0);
+        let start = Instant::now();
+        auto_away.user_activity(start);
+        assert!(!auto_away.check_idle(start + Duration::from_secs(3600), &Status::Online));
+    }
+}
@@ -23,7 +23,7 @@ impl Default for CustomUserStatus {
 }
 
 impl CustomUserStatus {
-    fn new() -> Self {
+    pub fn new() -> Self {
         CustomUserStatus {
             identifier: String::default(),
             name: String::default(),
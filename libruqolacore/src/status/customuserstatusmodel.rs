@@ -23,7 +23,7 @@ impl Default for CustomUserStatusModel {
 }
 
 impl CustomUserStatusModel {
-    fn new() -> Self {
+    pub fn new() -> Self {
         CustomUserStatusModel {
             custom_user_count: 0,
             offset: 0,
@@ -31,11 +31,49 @@ impl CustomUserStatusModel {
             statuses: Vec::default(),
         }
     }
+
+    // Parse custom-user-status.list result.
+    pub fn parse_elements(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<CustomUserStatusModel>(json) {
+            *self = val
+        }
+    }
+
+    pub fn find(&self, identifier: &str) -> Option<&CustomUserStatus> {
+        self.statuses
+            .iter()
+            .find(|status| status.identifier == identifier)
+    }
+
+    // Add or replace custom status.
+    pub fn update_status(&mut self, status: CustomUserStatus) {
+        if let Some(existing) = self
+            .statuses
+            .iter_mut()
+            .find(|s| s.identifier == status.identifier)
+        {
+            *existing = status;
+        } else {
+            self.statuses.push(status);
+            self.total += 1;
+        }
+    }
+
+    pub fn remove_status(&mut self, identifier: &str) {
+        let count = self.statuses.len();
+        self.statuses
+            .retain(|status| status.identifier != identifier);
+        if self.statuses.len() != count {
+            self.total -= 1;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::status::customuserstatus::CustomUserStatus;
     use crate::status::customuserstatusmodel::CustomUserStatusModel;
+    use crate::user::Status;
 
     #[test]
     fn test_is_invalid_by_default() {
@@ -46,5 +84,43 @@ mod tests {
         assert_eq!(b.custom_user_count, 0);
         assert!(b.statuses.is_empty());
     }
+
+    #[test]
+    fn test_parse_elements() {
+        let mut b = CustomUserStatusModel::new();
+        b.parse_elements(
+            &std::fs::read_to_string("src/data/customuserstatus/customuserstatuslist.json")
+                .expect("Failed to open file"),
+        );
+        assert_eq!(b.statuses.len(), 3);
+        assert_eq!(b.total, 3);
+        let status = b.find("sr5XT2gTgYNBhTaEi").unwrap();
+        assert_eq!(status.name, "In a meeting");
+        assert_eq!(status.status_type, Status::Busy);
+        assert!(b.find("foo").is_none());
+    }
+
+    #[test]
+    fn test_update_remove_status() {
+        let mut b = CustomUserStatusModel::new();
+        b.update_status(CustomUserStatus {
+            identifier: "status1".to_string(),
+            name: "Lunch".to_string(),
+            status_type: Status::Away,
+        });
+        assert_eq!(b.total, 1);
+        b.update_status(CustomUserStatus {
+            identifier: "status1".to_string(),
+            name: "Lunch break".to_string(),
+            status_type: Status::Away,
+        });
+        assert_eq!(b.total, 1);
+        assert_eq!(b.find("status1").unwrap().name, "Lunch break");
+        b.remove_status("foo");
+        assert_eq!(b.total, 1);
+        b.remove_status("status1");
+        assert_eq!(b.total, 0);
+        assert!(b.statuses.is_empty());
+    }
 }
 // TODO
@@ -3,6 +3,8 @@
  *
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
+pub mod autoaway;
 pub mod customuserstatus;
 pub mod customuserstatusmodel;
+pub mod presencemanager;
 pub mod statusmodel;
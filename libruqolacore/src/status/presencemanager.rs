@@ -0,0 +1,289 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ownuser::ownusermanager::status_name;
+use crate::status::autoaway::AutoAway;
+use crate::status::customuserstatus::CustomUserStatus;
+use crate::status::customuserstatusmodel::CustomUserStatusModel;
+use crate::user::Status;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{
+    APIMethod, CustomUserStatusCreateMethod, CustomUserStatusDeleteMethod,
+    CustomUserStatusListMethod, CustomUserStatusUpdateMethod,
+};
+use std::collections::HashMap;
+use std::time::Instant;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserStatusInfo {
+    pub user_id: String,
+    pub username: String,
+    pub status: Status,
+    pub status_text: String,
+}
+
+// Status sent by "user-status" stream
+pub fn status_from_number(value: i64) -> Status {
+    match value {
+        0 => Status::Offline,
+        1 => Status::Online,
+        2 => Status::Away,
+        3 => Status::Busy,
+        _ => Status::Unknown,
+    }
+}
+
+// Status of users, custom statuses and auto away.
+#[derive(Clone)]
+pub struct PresenceManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    // User identifier => status
+    pub user_statuses: HashMap<String, UserStatusInfo>,
+    pub custom_user_status_model: CustomUserStatusModel,
+    pub auto_away: AutoAway,
+}
+
+impl Default for PresenceManager {
+    fn default() -> Self {
+        PresenceManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl PresenceManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        PresenceManager {
+            settings,
+            server_url,
+            user_statuses: HashMap::new(),
+            custom_user_status_model: CustomUserStatusModel::new(),
+            auto_away: AutoAway::new(),
+        }
+    }
+
+    // "user-status" stream: [[user_id, username, status, status_text, name, roles]]
+    pub fn apply_user_status(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            let Some(user_id) = change[0].as_str() else {
+                continue;
+            };
+            self.user_statuses.insert(
+                user_id.to_string(),
+                UserStatusInfo {
+                    user_id: user_id.to_string(),
+                    username: change[1].as_str().unwrap_or_default().to_string(),
+                    status: status_from_number(change[2].as_i64().unwrap_or(-1)),
+                    status_text: change[3].as_str().unwrap_or_default().to_string(),
+                },
+            );
+        }
+    }
+
+    pub fn user_status(&self, user_id: &str) -> Status {
+        self.user_statuses
+            .get(user_id)
+            .map(|info| info.status.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn user_status_info(&self, user_id: &str) -> Option<&UserStatusInfo> {
+        self.user_statuses.get(user_id)
+    }
+
+    // "updateCustomUserStatus" stream: [{"userStatusData": {"_id": …, "name": …, "statusType": …}}]
+    pub fn apply_update_custom_user_status(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            if let Ok(status) =
+                serde_json::from_value::<CustomUserStatus>(change["userStatusData"].clone())
+            {
+                self.custom_user_status_model.update_status(status);
+            }
+        }
+    }
+
+    // "deleteCustomUserStatus" stream: [{"userStatusData": {"_id": …}}]
+    pub fn apply_delete_custom_user_status(&mut self, value: &serde_json::Value) {
+        let Some(args) = value.as_array() else {
+            return;
+        };
+        for change in args {
+            if let Some(identifier) = change["userStatusData"]["_id"].as_str() {
+                self.custom_user_status_model.remove_status(identifier);
+            }
+        }
+    }
+
+    pub fn custom_user_statuses(&self) -> &[CustomUserStatus] {
+        &self.custom_user_status_model.statuses
+    }
+
+    // Returns status to restore when user was set away automatically.
+    pub fn user_activity(&mut self) -> Option<Status> {
+        self.auto_away.user_activity(Instant::now())
+    }
+
+    // Returns true when own user must be set away.
+    pub fn check_idle(&mut self, own_status: &Status) -> bool {
+        self.auto_away.check_idle(Instant::now(), own_status)
+    }
+
+    pub async fn load_custom_user_statuses(&mut self) -> Result<(), Error> {
+        let result = CustomUserStatusListMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            ..Default::default()
+        }
+        .call()
+        .await?;
+        self.custom_user_status_model.parse_elements(&result);
+        Ok(())
+    }
+
+    // Admin: changes are received by "updateCustomUserStatus" stream.
+    pub async fn create_custom_user_status(
+        &self,
+        name: &str,
+        status_type: Status,
+    ) -> Result<(), Error> {
+        CustomUserStatusCreateMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            name: name.to_string(),
+            status_type: status_name(&status_type).unwrap_or_default().to_string(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn update_custom_user_status(
+        &self,
+        identifier: &str,
+        name: &str,
+        status_type: Status,
+    ) -> Result<(), Error> {
+        CustomUserStatusUpdateMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            identifier: identifier.to_string(),
+            name: name.to_string(),
+            status_type: status_name(&status_type).unwrap_or_default().to_string(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_custom_user_status(&self, identifier: &str) -> Result<(), Error> {
+        CustomUserStatusDeleteMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            identifier: identifier.to_string(),
+        }
+        .call()
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::status::presencemanager::{PresenceManager, status_from_number};
+    use crate::user::Status;
+
+    #[test]
+    fn test_default_values() {
+        let m = PresenceManager::default();
+        assert!(m.user_statuses.is_empty());
+        assert!(m.custom_user_statuses().is_empty());
+        assert_eq!(m.user_status("foo"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_status_from_number() {
+        assert_eq!(status_from_number(0), Status::Offline);
+        assert_eq!(status_from_number(1), Status::Online);
+        assert_eq!(status_from_number(2), Status::Away);
+        assert_eq!(status_from_number(3), Status::Busy);
+        assert_eq!(status_from_number(8), Status::Unknown);
+    }
+
+    #[test]
+    fn test_apply_user_status() {
+        let mut m = PresenceManager::default();
+        m.apply_user_status(&serde_json::json!([
+            ["hLdKM5PH", "foo", 1, "Hacking", null, ["user"]],
+            ["XQZAk399", "bla", 3, "", null, ["user", "admin"]]
+        ]));
+        assert_eq!(m.user_status("hLdKM5PH"), Status::Online);
+        assert_eq!(
+            m.user_status_info("hLdKM5PH").unwrap().status_text,
+            "Hacking"
+        );
+        assert_eq!(m.user_status_info("hLdKM5PH").unwrap().username, "foo");
+        assert_eq!(m.user_status("XQZAk399"), Status::Busy);
+
+        m.apply_user_status(&serde_json::json!([[
+            "hLdKM5PH",
+            "foo",
+            0,
+            "",
+            null,
+            ["user"]
+        ]]));
+        assert_eq!(m.user_status("hLdKM5PH"), Status::Offline);
+        assert!(
+            m.user_status_info("hLdKM5PH")
+                .unwrap()
+                .status_text
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_user_status() {
+        let mut m = PresenceManager::default();
+        m.custom_user_status_model.parse_elements(
+            &std::fs::read_to_string("src/data/customuserstatus/customuserstatuslist.json")
+                .expect("Failed to open file"),
+        );
+        assert_eq!(m.custom_user_statuses().len(), 3);
+
+        m.apply_update_custom_user_status(&serde_json::json!([
+            {"userStatusData": {"_id": "GDeCzNZzaJ8ozTwk4", "name": "Lunch break", "statusType": "away", "previousName": "Lunch"}}
+        ]));
+        assert_eq!(m.custom_user_statuses().len(), 3);
+        assert_eq!(
+            m.custom_user_status_model
+                .find("GDeCzNZzaJ8ozTwk4")
+                .unwrap()
+                .name,
+            "Lunch break"
+        );
+
+        m.apply_update_custom_user_status(&serde_json::json!([
+            {"userStatusData": {"_id": "newstatus", "name": "Vacation", "statusType": "offline"}}
+        ]));
+        assert_eq!(m.custom_user_statuses().len(), 4);
+
+        m.apply_delete_custom_user_status(&serde_json::json!([
+            {"userStatusData": {"_id": "sr5XT2gTgYNBhTaEi", "name": "In a meeting", "statusType": "busy"}}
+        ]));
+        assert_eq!(m.custom_user_statuses().len(), 3);
+        assert!(
+            m.custom_user_status_model
+                .find("sr5XT2gTgYNBhTaEi")
+                .is_none()
+        );
+    }
+}
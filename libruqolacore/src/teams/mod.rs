@@ -4,6 +4,7 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
+pub mod team;
 pub mod teamcompleter;
 pub mod teaminfo;
 pub mod teamroom;
@@ -0,0 +1,277 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::teams::teamroom::TeamRoom;
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+pub struct TeamMemberUser {
+    #[serde(rename = "_id")]
+    pub user_id: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Clone, Deserialize, Debug, Default, PartialEq)]
+pub struct TeamMember {
+    pub user: TeamMemberUser,
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+impl TeamMember {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+    pub fn is_owner(&self) -> bool {
+        self.has_role("owner")
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct Team {
+    #[serde(rename = "_id")]
+    pub team_id: String,
+    #[serde(default)]
+    pub name: String,
+    // 0: public, 1: private
+    #[serde(rename = "type", default)]
+    pub team_type: i64,
+    #[serde(rename = "roomId", default)]
+    pub main_room_id: String,
+
+    #[serde(skip_deserializing)]
+    pub rooms: Vec<TeamRoom>,
+    #[serde(skip_deserializing)]
+    pub members: Vec<TeamMember>,
+}
+
+#[derive(Deserialize)]
+struct TeamInfoResult {
+    #[serde(rename = "teamInfo")]
+    team_info: Team,
+}
+
+#[derive(Deserialize)]
+struct TeamRoomsResult {
+    rooms: Vec<TeamRoom>,
+}
+
+#[derive(Deserialize)]
+struct TeamMembersResult {
+    members: Vec<TeamMember>,
+}
+
+impl Default for Team {
+    fn default() -> Self {
+        Team::new()
+    }
+}
+
+impl Team {
+    pub fn new() -> Self {
+        Team {
+            team_id: String::default(),
+            name: String::default(),
+            team_type: 0,
+            main_room_id: String::default(),
+            rooms: Vec::<TeamRoom>::default(),
+            members: Vec::<TeamMember>::default(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.team_id.is_empty()
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.team_type == 1
+    }
+
+    // Parse teams.info result. Rooms and members are kept.
+    pub fn parse_team_info(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<TeamInfoResult>(json) {
+            let rooms = std::mem::take(&mut self.rooms);
+            let members = std::mem::take(&mut self.members);
+            *self = val.team_info;
+            self.rooms = rooms;
+            self.members = members;
+        }
+    }
+
+    // Parse teams.listRooms result.
+    pub fn parse_rooms(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<TeamRoomsResult>(json) {
+            self.rooms = val.rooms;
+        }
+    }
+
+    // Parse teams.members result.
+    pub fn parse_members(&mut self, json: &str) {
+        if let Ok(val) = serde_json::from_str::<TeamMembersResult>(json) {
+            self.members = val.members;
+        }
+    }
+
+    // Rooms of the team without the main room.
+    pub fn child_rooms(&self) -> Vec<&TeamRoom> {
+        self.rooms
+            .iter()
+            .filter(|room| room.identifier != self.main_room_id)
+            .collect()
+    }
+
+    // Rooms that new members join automatically.
+    pub fn auto_join_rooms(&self) -> Vec<&TeamRoom> {
+        self.child_rooms()
+            .into_iter()
+            .filter(|room| room.auto_join)
+            .collect()
+    }
+
+    pub fn set_auto_join(&mut self, room_id: &str, auto_join: bool) {
+        if let Some(room) = self.rooms.iter_mut().find(|r| r.identifier == room_id) {
+            room.auto_join = auto_join;
+        }
+    }
+
+    pub fn remove_room(&mut self, room_id: &str) {
+        self.rooms.retain(|r| r.identifier != room_id);
+    }
+
+    pub fn member(&self, user_id: &str) -> Option<&TeamMember> {
+        self.members.iter().find(|m| m.user.user_id == user_id)
+    }
+
+    pub fn members_with_role(&self, role: &str) -> Vec<&TeamMember> {
+        self.members.iter().filter(|m| m.has_role(role)).collect()
+    }
+
+    pub fn update_member_roles(&mut self, user_id: &str, roles: Vec<String>) {
+        if let Some(member) = self.members.iter_mut().find(|m| m.user.user_id == user_id) {
+            member.roles = roles;
+        }
+    }
+
+    pub fn remove_member(&mut self, user_id: &str) {
+        self.members.retain(|m| m.user.user_id != user_id);
+    }
+}
+
+/*
+Debug output for Team
+*/
+impl fmt::Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Team(team_id: {}, name: {}, team_type: {}, main_room_id: {}, rooms: {}, members: {})",
+            self.team_id,
+            self.name,
+            self.team_type,
+            self.main_room_id,
+            self.rooms.len(),
+            self.members.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::teams::team::Team;
+
+    pub fn parse(team: &mut Team, filename: &str) {
+        let json = std::fs::read_to_string(filename).expect("Failed to open file");
+        team.parse_team_info(&json);
+    }
+
+    pub fn parse_rooms(team: &mut Team, filename: &str) {
+        let json = std::fs::read_to_string(filename).expect("Failed to open file");
+        team.parse_rooms(&json);
+    }
+
+    pub fn parse_members(team: &mut Team, filename: &str) {
+        let json = std::fs::read_to_string(filename).expect("Failed to open file");
+        team.parse_members(&json);
+    }
+
+    #[test]
+    fn test_is_invalid_by_default() {
+        let b = Team::new();
+        assert!(!b.is_valid());
+        assert!(!b.is_private());
+        assert!(b.name.is_empty());
+        assert!(b.main_room_id.is_empty());
+        assert!(b.rooms.is_empty());
+        assert!(b.members.is_empty());
+    }
+
+    #[test]
+    fn test_parse_team_info() {
+        let mut b = Team::new();
+        parse(&mut b, "src/data/teams/teaminfo.json");
+        assert!(b.is_valid());
+        assert!(b.is_private());
+        assert_eq!(b.team_id, "6783afc4f2c4d5a1e0a8b001");
+        assert_eq!(b.name, "kde-devel");
+        assert_eq!(b.main_room_id, "6783afc4f2c4d5a1e0a8b002");
+    }
+
+    #[test]
+    fn test_parse_rooms() {
+        let mut b = Team::new();
+        parse(&mut b, "src/data/teams/teaminfo.json");
+        parse_rooms(&mut b, "src/data/teams/teamrooms.json");
+        assert_eq!(b.rooms.len(), 3);
+
+        let child_rooms = b.child_rooms();
+        assert_eq!(child_rooms.len(), 2);
+        assert_eq!(child_rooms[0].name, "announce");
+        assert_eq!(child_rooms[1].name, "random");
+
+        let auto_join_rooms = b.auto_join_rooms();
+        assert_eq!(auto_join_rooms.len(), 1);
+        assert_eq!(auto_join_rooms[0].identifier, "6783b0a1f2c4d5a1e0a8b010");
+
+        b.set_auto_join("6783b0a1f2c4d5a1e0a8b011", true);
+        assert_eq!(b.auto_join_rooms().len(), 2);
+
+        b.remove_room("6783b0a1f2c4d5a1e0a8b010");
+        assert_eq!(b.child_rooms().len(), 1);
+
+        // Team info update keeps rooms
+        parse(&mut b, "src/data/teams/teaminfo.json");
+        assert_eq!(b.rooms.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_members() {
+        let mut b = Team::new();
+        parse_members(&mut b, "src/data/teams/teammembers.json");
+        assert_eq!(b.members.len(), 2);
+
+        let owner = b.member("Lymsiu4Mn6xjTAan4").unwrap();
+        assert_eq!(owner.user.username, "laurent");
+        assert_eq!(owner.user.name, "Laurent");
+        assert!(owner.is_owner());
+        assert!(!b.member("RtMDEYc28fQ5aHpf4").unwrap().is_owner());
+        assert_eq!(b.members_with_role("member").len(), 2);
+        assert_eq!(b.members_with_role("owner").len(), 1);
+
+        b.update_member_roles(
+            "RtMDEYc28fQ5aHpf4",
+            vec!["owner".to_string(), "member".to_string()],
+        );
+        assert_eq!(b.members_with_role("owner").len(), 2);
+
+        b.remove_member("Lymsiu4Mn6xjTAan4");
+        assert_eq!(b.members.len(), 1);
+        assert!(b.member("Lymsiu4Mn6xjTAan4").is_none());
+    }
+}
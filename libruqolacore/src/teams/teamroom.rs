@@ -9,10 +9,12 @@ use serde::Deserialize;
 #[derive(Clone, Deserialize, Debug)]
 pub struct TeamRoom {
     pub name: String,
+    #[serde(default)]
     pub fname: String,
     #[serde(rename = "_id")]
     pub identifier: String,
-    #[serde(rename = "teamDefault")]
+    // Not sent by server when room is not an auto-join room
+    #[serde(rename = "teamDefault", default)]
     pub auto_join: bool,
 }
 
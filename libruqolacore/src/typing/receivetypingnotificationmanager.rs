@@ -0,0 +1,307 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// Texts displayed under room, "{}" are replaced by user names (or number of other
+// users). Client replaces them by translations of default texts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypingNotificationTexts {
+    pub one_user: String,
+    pub two_users: String,
+    pub three_users: String,
+    pub more_users: String,
+}
+
+impl Default for TypingNotificationTexts {
+    fn default() -> Self {
+        TypingNotificationTexts {
+            one_user: "{} is typing…".to_string(),
+            two_users: "{} and {} are typing…".to_string(),
+            three_users: "{}, {} and {} are typing…".to_string(),
+            more_users: "{}, {} and {} others are typing…".to_string(),
+        }
+    }
+}
+
+// Users typing in rooms, updated by "<room_id>/user-activity" stream.
+// An entry expires when no new activity is received, so users which
+// disconnect while typing are removed.
+#[derive(Clone, Debug)]
+pub struct ReceiveTypingNotificationManager {
+    // Own user is never listed
+    pub own_user_name: String,
+    pub expiry: Duration,
+    pub texts: TypingNotificationTexts,
+    // Room identifier => (user name, last activity)
+    room_typings: HashMap<String, Vec<(String, Instant)>>,
+}
+
+impl Default for ReceiveTypingNotificationManager {
+    fn default() -> Self {
+        ReceiveTypingNotificationManager::new(String::default())
+    }
+}
+
+impl ReceiveTypingNotificationManager {
+    pub fn new(own_user_name: String) -> Self {
+        ReceiveTypingNotificationManager {
+            own_user_name,
+            expiry: Duration::from_secs(15),
+            texts: TypingNotificationTexts::default(),
+            room_typings: HashMap::new(),
+        }
+    }
+
+    // Args are [username, ["user-typing"]|[], {"tmid": …}]
+    // Older servers send [username, true|false].
+    pub fn apply_user_activity(&mut self, room_id: &str, value: &serde_json::Value, now: Instant) {
+        let Some(user_name) = value[0].as_str() else {
+            return;
+        };
+        if user_name.is_empty() || user_name == self.own_user_name {
+            return;
+        }
+        let typing = match &value[1] {
+            serde_json::Value::Bool(typing) => *typing,
+            serde_json::Value::Array(activities) => activities
+                .iter()
+                .any(|activity| activity.as_str() == Some("user-typing")),
+            _ => return,
+        };
+        let users = self.room_typings.entry(room_id.to_string()).or_default();
+        users.retain(|(name, _)| name != user_name);
+        if typing {
+            users.push((user_name.to_string(), now));
+        }
+        if users.is_empty() {
+            self.room_typings.remove(room_id);
+        }
+    }
+
+    // Users typing in room, in order they started to type.
+    pub fn typing_users(&self, room_id: &str, now: Instant) -> Vec<String> {
+        self.room_typings
+            .get(room_id)
+            .map(|users| {
+                users
+                    .iter()
+                    .filter(|(_, last)| now.saturating_duration_since(*last) < self.expiry)
+                    .map(|(name, _)| name.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // Text displayed under room, empty when nobody is typing.
+    pub fn typing_text(&self, room_id: &str, now: Instant) -> String {
+        typing_notification_text(&self.typing_users(room_id, now), &self.texts)
+    }
+
+    // Called periodically, returns rooms which changed (sorted).
+    pub fn remove_expired(&mut self, now: Instant) -> Vec<String> {
+        let mut changed_rooms = Vec::new();
+        for (room_id, users) in self.room_typings.iter_mut() {
+            let count = users.len();
+            users.retain(|(_, last)| now.saturating_duration_since(*last) < self.expiry);
+            if users.len() != count {
+                changed_rooms.push(room_id.clone());
+            }
+        }
+        self.room_typings.retain(|_, users| !users.is_empty());
+        changed_rooms.sort();
+        changed_rooms
+    }
+
+    // Called when room is closed or user left it.
+    pub fn clear_room(&mut self, room_id: &str) {
+        self.room_typings.remove(room_id);
+    }
+}
+
+pub fn typing_notification_text(users: &[String], texts: &TypingNotificationTexts) -> String {
+    match users {
+        [] => String::default(),
+        [user] => replace_placeholders(&texts.one_user, &[user]),
+        [first, second] => replace_placeholders(&texts.two_users, &[first, second]),
+        [first, second, third] => replace_placeholders(&texts.three_users, &[first, second, third]),
+        [first, second, others @ ..] => replace_placeholders(
+            &texts.more_users,
+            &[first, second, &others.len().to_string()],
+        ),
+    }
+}
+
+// User names can contain "{}", they are not replaced again.
+fn replace_placeholders(text: &str, values: &[&str]) -> String {
+    let mut result = String::new();
+    let mut values = values.iter();
+    let mut parts = text.split("{}").peekable();
+    while let Some(part) = parts.next() {
+        result.push_str(part);
+        if parts.peek().is_some() {
+            result.push_str(values.next().unwrap_or(&""));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::typing::receivetypingnotificationmanager::{
+        ReceiveTypingNotificationManager, TypingNotificationTexts, typing_notification_text,
+    };
+    use std::time::{Duration, Instant};
+
+    fn users(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_default_values() {
+        let m = ReceiveTypingNotificationManager::default();
+        assert!(m.own_user_name.is_empty());
+        assert_eq!(m.expiry, Duration::from_secs(15));
+        assert!(m.typing_users("GENERAL", Instant::now()).is_empty());
+        assert!(m.typing_text("GENERAL", Instant::now()).is_empty());
+    }
+
+    #[test]
+    fn test_typing_notification_text() {
+        assert!(typing_notification_text(&[], &TypingNotificationTexts::default()).is_empty());
+        assert_eq!(
+            typing_notification_text(&users(&["Alice"]), &TypingNotificationTexts::default()),
+            "Alice is typing…"
+        );
+        assert_eq!(
+            typing_notification_text(
+                &users(&["Alice", "Bob"]),
+                &TypingNotificationTexts::default()
+            ),
+            "Alice and Bob are typing…"
+        );
+        assert_eq!(
+            typing_notification_text(
+                &users(&["Alice", "Bob", "Carol"]),
+                &TypingNotificationTexts::default()
+            ),
+            "Alice, Bob and Carol are typing…"
+        );
+        assert_eq!(
+            typing_notification_text(
+                &users(&["Alice", "Bob", "Carol", "Dave", "Eve"]),
+                &TypingNotificationTexts::default()
+            ),
+            "Alice, Bob and 3 others are typing…"
+        );
+        assert_eq!(
+            typing_notification_text(&users(&["{}", "Bob"]), &TypingNotificationTexts::default()),
+            "{} and Bob are typing…"
+        );
+    }
+
+    #[test]
+    fn test_translated_typing_text() {
+        let mut m = ReceiveTypingNotificationManager::new("laurent".to_string());
+        m.texts = TypingNotificationTexts {
+            one_user: "{} écrit…".to_string(),
+            two_users: "{} et {} écrivent…".to_string(),
+            three_users: "{}, {} et {} écrivent…".to_string(),
+            more_users: "{}, {} et {} autres écrivent…".to_string(),
+        };
+        let now = Instant::now();
+        m.apply_user_activity(
+            "GENERAL",
+            &serde_json::json!(["Alice", ["user-typing"], {}]),
+            now,
+        );
+        assert_eq!(m.typing_text("GENERAL", now), "Alice écrit…");
+        for user in ["Bob", "Carol", "Dave"] {
+            m.apply_user_activity(
+                "GENERAL",
+                &serde_json::json!([user, ["user-typing"], {}]),
+                now,
+            );
+        }
+        assert_eq!(
+            m.typing_text("GENERAL", now),
+            "Alice, Bob et 2 autres écrivent…"
+        );
+    }
+
+    #[test]
+    fn test_apply_user_activity() {
+        let mut m = ReceiveTypingNotificationManager::new("laurent".to_string());
+        let now = Instant::now();
+        m.apply_user_activity(
+            "GENERAL",
+            &serde_json::json!(["Alice", ["user-typing"], {}]),
+            now,
+        );
+        m.apply_user_activity("GENERAL", &serde_json::json!(["Bob", true]), now);
+        // Own user is ignored
+        m.apply_user_activity(
+            "GENERAL",
+            &serde_json::json!(["laurent", ["user-typing"], {}]),
+            now,
+        );
+        m.apply_user_activity(
+            "room2",
+            &serde_json::json!(["Carol", ["user-typing"], {"tmid": "thread1"}]),
+            now,
+        );
+        assert_eq!(m.typing_users("GENERAL", now), users(&["Alice", "Bob"]));
+        assert_eq!(m.typing_text("GENERAL", now), "Alice and Bob are typing…");
+        assert_eq!(m.typing_users("room2", now), users(&["Carol"]));
+
+        // Stopped
+        m.apply_user_activity("GENERAL", &serde_json::json!(["Alice", [], {}]), now);
+        m.apply_user_activity("room2", &serde_json::json!(["Carol", false]), now);
+        assert_eq!(m.typing_users("GENERAL", now), users(&["Bob"]));
+        assert!(m.typing_users("room2", now).is_empty());
+
+        // Invalid values
+        m.apply_user_activity("GENERAL", &serde_json::json!([]), now);
+        m.apply_user_activity("GENERAL", &serde_json::json!(["Bob", "foo"]), now);
+        assert_eq!(m.typing_users("GENERAL", now), users(&["Bob"]));
+    }
+
+    #[test]
+    fn test_remove_expired() {
+        let mut m = ReceiveTypingNotificationManager::new("laurent".to_string());
+        let start = Instant::now();
+        m.apply_user_activity("GENERAL", &serde_json::json!(["Alice", true]), start);
+        m.apply_user_activity(
+            "GENERAL",
+            &serde_json::json!(["Bob", true]),
+            start + Duration::from_secs(10),
+        );
+        m.apply_user_activity(
+            "room2",
+            &serde_json::json!(["Carol", true]),
+            start + Duration::from_secs(10),
+        );
+        assert!(m.remove_expired(start + Duration::from_secs(14)).is_empty());
+        assert_eq!(
+            m.typing_users("GENERAL", start + Duration::from_secs(15)),
+            users(&["Bob"])
+        );
+        assert_eq!(
+            m.remove_expired(start + Duration::from_secs(15)),
+            vec!["GENERAL"]
+        );
+        assert_eq!(
+            m.remove_expired(start + Duration::from_secs(25)),
+            vec!["GENERAL", "room2"]
+        );
+        assert!(m.typing_users("GENERAL", start).is_empty());
+
+        m.apply_user_activity("room2", &serde_json::json!(["Carol", true]), start);
+        m.clear_room("room2");
+        assert!(m.typing_users("room2", start).is_empty());
+    }
+}
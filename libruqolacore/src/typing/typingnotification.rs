@@ -0,0 +1,280 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use libddpapi::ddpclient::TypingInfo;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// Outgoing typing notifications.
+// "typing" is sent when user starts to type and again every refresh interval while
+// user is typing (receivers drop it after 15s), "stopped" is sent when text is
+// cleared, message is sent or user didn't type during timeout.
+// Returned TypingInfo must be sent by DDpClient::change_typing_info.
+#[derive(Clone, Debug)]
+pub struct TypingNotification {
+    pub user_name: String,
+    // Send "stopped" when user didn't type during this delay
+    pub timeout: Duration,
+    // Send "typing" again after this delay, shorter than receivers expiry
+    pub refresh_interval: Duration,
+    // (Room identifier, thread message identifier) => key press and sending times,
+    // thread message identifier is empty for room composer
+    typing_rooms: HashMap<(String, String), TypingRoom>,
+}
+
+#[derive(Clone, Debug)]
+struct TypingRoom {
+    last_key_press: Instant,
+    last_sent: Instant,
+}
+
+impl Default for TypingNotification {
+    fn default() -> Self {
+        TypingNotification::new(String::default())
+    }
+}
+
+impl TypingNotification {
+    pub fn new(user_name: String) -> Self {
+        TypingNotification {
+            user_name,
+            timeout: Duration::from_secs(5),
+            refresh_interval: Duration::from_secs(5),
+            typing_rooms: HashMap::new(),
+        }
+    }
+
+    pub fn is_typing(&self, room_id: &str) -> bool {
+        self.is_thread_typing(room_id, "")
+    }
+
+    pub fn is_thread_typing(&self, room_id: &str, thread_message_id: &str) -> bool {
+        self.typing_rooms
+            .contains_key(&(room_id.to_string(), thread_message_id.to_string()))
+    }
+
+    fn info(&self, room_id: &str, thread_message_id: &str, typing: bool) -> TypingInfo {
+        TypingInfo {
+            thread_message_id: thread_message_id.to_string(),
+            ..TypingInfo::new(room_id.to_string(), self.user_name.clone(), typing)
+        }
+    }
+
+    // Called when composer text changed.
+    pub fn text_changed(
+        &mut self,
+        room_id: &str,
+        text_is_empty: bool,
+        now: Instant,
+    ) -> Option<TypingInfo> {
+        self.thread_text_changed(room_id, "", text_is_empty, now)
+    }
+
+    // Called when composer text of a thread changed.
+    pub fn thread_text_changed(
+        &mut self,
+        room_id: &str,
+        thread_message_id: &str,
+        text_is_empty: bool,
+        now: Instant,
+    ) -> Option<TypingInfo> {
+        if text_is_empty {
+            return self.stop_thread_typing(room_id, thread_message_id);
+        }
+        let key = (room_id.to_string(), thread_message_id.to_string());
+        match self.typing_rooms.get_mut(&key) {
+            Some(room) => {
+                room.last_key_press = now;
+                if now.saturating_duration_since(room.last_sent) < self.refresh_interval {
+                    // Already sent
+                    return None;
+                }
+                room.last_sent = now;
+            }
+            None => {
+                self.typing_rooms.insert(
+                    key,
+                    TypingRoom {
+                        last_key_press: now,
+                        last_sent: now,
+                    },
+                );
+            }
+        }
+        Some(self.info(room_id, thread_message_id, true))
+    }
+
+    // Called when message is sent or room is closed.
+    pub fn stop_typing(&mut self, room_id: &str) -> Option<TypingInfo> {
+        self.stop_thread_typing(room_id, "")
+    }
+
+    pub fn stop_thread_typing(
+        &mut self,
+        room_id: &str,
+        thread_message_id: &str,
+    ) -> Option<TypingInfo> {
+        self.typing_rooms
+            .remove(&(room_id.to_string(), thread_message_id.to_string()))
+            .map(|_| self.info(room_id, thread_message_id, false))
+    }
+
+    // Called periodically, returns "stopped" notifications of rooms without key press during timeout.
+    pub fn check_timeout(&mut self, now: Instant) -> Vec<TypingInfo> {
+        let mut expired: Vec<(String, String)> = self
+            .typing_rooms
+            .iter()
+            .filter(|(_, room)| now.saturating_duration_since(room.last_key_press) >= self.timeout)
+            .map(|(key, _)| key.clone())
+            .collect();
+        expired.sort();
+        expired
+            .iter()
+            .filter_map(|(room_id, thread_message_id)| {
+                self.stop_thread_typing(room_id, thread_message_id)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::typing::typingnotification::TypingNotification;
+    use libddpapi::ddpclient::TypingInfo;
+    use std::time::{Duration, Instant};
+
+    fn info(room_id: &str, typing: bool) -> TypingInfo {
+        TypingInfo::new(room_id.to_string(), "laurent".to_string(), typing)
+    }
+
+    #[test]
+    fn test_default_values() {
+        let t = TypingNotification::default();
+        assert!(t.user_name.is_empty());
+        assert_eq!(t.timeout, Duration::from_secs(5));
+        assert_eq!(t.refresh_interval, Duration::from_secs(5));
+        assert!(!t.is_typing("room1"));
+    }
+
+    #[test]
+    fn test_text_changed() {
+        let mut t = TypingNotification::new("laurent".to_string());
+        let start = Instant::now();
+        assert_eq!(
+            t.text_changed("room1", false, start),
+            Some(info("room1", true))
+        );
+        assert!(t.is_typing("room1"));
+        // Debounced
+        assert!(
+            t.text_changed("room1", false, start + Duration::from_secs(1))
+                .is_none()
+        );
+        assert!(
+            t.text_changed("room1", false, start + Duration::from_secs(2))
+                .is_none()
+        );
+        // Other room
+        assert_eq!(
+            t.text_changed("room2", false, start + Duration::from_secs(2)),
+            Some(info("room2", true))
+        );
+        // Text cleared
+        assert_eq!(
+            t.text_changed("room1", true, start + Duration::from_secs(3)),
+            Some(info("room1", false))
+        );
+        assert!(
+            t.text_changed("room1", true, start + Duration::from_secs(3))
+                .is_none()
+        );
+        assert!(!t.is_typing("room1"));
+    }
+
+    #[test]
+    fn test_refresh_typing() {
+        let mut t = TypingNotification::new("laurent".to_string());
+        let start = Instant::now();
+        assert_eq!(
+            t.text_changed("room1", false, start),
+            Some(info("room1", true))
+        );
+        assert!(
+            t.text_changed("room1", false, start + Duration::from_secs(4))
+                .is_none()
+        );
+        // Still typing after refresh interval
+        assert_eq!(
+            t.text_changed("room1", false, start + Duration::from_secs(6)),
+            Some(info("room1", true))
+        );
+        assert!(
+            t.text_changed("room1", false, start + Duration::from_secs(9))
+                .is_none()
+        );
+        assert_eq!(
+            t.text_changed("room1", false, start + Duration::from_secs(12)),
+            Some(info("room1", true))
+        );
+        assert!(t.check_timeout(start + Duration::from_secs(16)).is_empty());
+    }
+
+    #[test]
+    fn test_thread_text_changed() {
+        let mut t = TypingNotification::new("laurent".to_string());
+        let start = Instant::now();
+        let thread_info = |typing: bool| TypingInfo {
+            thread_message_id: "thread1".to_string(),
+            ..info("room1", typing)
+        };
+        assert_eq!(
+            t.thread_text_changed("room1", "thread1", false, start),
+            Some(thread_info(true))
+        );
+        assert!(t.is_thread_typing("room1", "thread1"));
+        assert!(!t.is_typing("room1"));
+        // Room composer is independent
+        assert_eq!(
+            t.text_changed("room1", false, start),
+            Some(info("room1", true))
+        );
+        assert_eq!(
+            t.check_timeout(start + Duration::from_secs(5)),
+            vec![info("room1", false), thread_info(false)]
+        );
+    }
+
+    #[test]
+    fn test_stop_typing() {
+        let mut t = TypingNotification::new("laurent".to_string());
+        assert!(t.stop_typing("room1").is_none());
+        t.text_changed("room1", false, Instant::now());
+        assert_eq!(t.stop_typing("room1"), Some(info("room1", false)));
+        assert!(!t.is_typing("room1"));
+    }
+
+    #[test]
+    fn test_check_timeout() {
+        let mut t = TypingNotification::new("laurent".to_string());
+        let start = Instant::now();
+        t.text_changed("room1", false, start);
+        t.text_changed("room2", false, start + Duration::from_secs(3));
+        assert!(t.check_timeout(start + Duration::from_secs(4)).is_empty());
+        // Key press renews timeout
+        t.text_changed("room1", false, start + Duration::from_secs(4));
+        assert!(t.check_timeout(start + Duration::from_secs(7)).is_empty());
+        assert_eq!(
+            t.check_timeout(start + Duration::from_secs(8)),
+            vec![info("room2", false)]
+        );
+        assert_eq!(
+            t.check_timeout(start + Duration::from_secs(10)),
+            vec![info("room1", false)]
+        );
+        assert!(!t.is_typing("room1"));
+        assert!(!t.is_typing("room2"));
+    }
+}
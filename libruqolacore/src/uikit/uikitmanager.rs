@@ -0,0 +1,464 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::uikit::uikitrenderer::UiKitAction;
+use crate::uikit::uikitview::UiKitView;
+use libauthenticationbase::authenticationsettings::AuthenticationType;
+use librocketchatrestapi::Error;
+use librocketchatrestapi::methods::{APIMethod, AppsUiInteractionMethod, UiInteractionType};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Same alphabet and length as meteor Random.id()
+const TRIGGER_ID_ALPHABET: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTWXYZabcdefghijkmnopqrstuvwxyz";
+const TRIGGER_ID_LENGTH: usize = 17;
+
+static TRIGGER_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+pub fn generate_random_id() -> String {
+    let state = std::collections::hash_map::RandomState::new();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or_default();
+    let counter = TRIGGER_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    (0..TRIGGER_ID_LENGTH)
+        .map(|index| {
+            let mut hasher = state.build_hasher();
+            hasher.write_u64(nanos);
+            hasher.write_u64(counter);
+            hasher.write_usize(index);
+            let position = hasher.finish() as usize % TRIGGER_ID_ALPHABET.len();
+            TRIGGER_ID_ALPHABET[position] as char
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum UiKitError {
+    UnknownView(String),
+    RestApi(Error),
+}
+
+impl From<Error> for UiKitError {
+    fn from(error: Error) -> Self {
+        UiKitError::RestApi(error)
+    }
+}
+
+/* Debug output for UiKitError */
+impl fmt::Display for UiKitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiKitError::UnknownView(view_id) => write!(f, "Unknown view: {}", view_id),
+            UiKitError::RestApi(error) => write!(f, "Rest api error: {:?}", error),
+        }
+    }
+}
+
+// Where the interactive element is displayed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiKitContainer {
+    Message {
+        room_id: String,
+        message_id: String,
+        tmid: Option<String>,
+    },
+    View {
+        view_id: String,
+    },
+}
+
+// What client must do after an interaction, views are stored in UiKitManager.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiKitInstruction {
+    ModalOpen,
+    ModalUpdate,
+    ModalClose,
+    ContextualBarOpen,
+    ContextualBarUpdate,
+    ContextualBarClose,
+    // View id, errors are stored in view
+    Errors(String),
+    Ignored,
+}
+
+// Payload of ui.interaction result and of "uiInteraction" user stream event.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct UiKitInteraction {
+    #[serde(rename = "type")]
+    interaction_type: String,
+    #[serde(rename = "triggerId")]
+    trigger_id: String,
+    #[serde(rename = "viewId")]
+    view_id: String,
+    view: Option<UiKitView>,
+    errors: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UiKitManager {
+    pub settings: AuthenticationType,
+    pub server_url: String,
+    pub modal: Option<UiKitView>,
+    pub contextual_bar: Option<UiKitView>,
+    // Trigger ids sent to apps and not answered yet
+    pub pending_trigger_ids: HashSet<String>,
+}
+
+impl Default for UiKitManager {
+    fn default() -> Self {
+        UiKitManager::new(AuthenticationType::None, String::default())
+    }
+}
+
+impl UiKitManager {
+    pub fn new(settings: AuthenticationType, server_url: String) -> Self {
+        UiKitManager {
+            settings,
+            server_url,
+            modal: None,
+            contextual_bar: None,
+            pending_trigger_ids: HashSet::new(),
+        }
+    }
+
+    // Apps can only open a view in answer to an interaction which sent this id.
+    pub fn generate_trigger_id(&mut self) -> String {
+        let trigger_id = generate_random_id();
+        self.pending_trigger_ids.insert(trigger_id.clone());
+        trigger_id
+    }
+
+    pub fn find_view(&self, view_id: &str) -> Option<&UiKitView> {
+        [self.modal.as_ref(), self.contextual_bar.as_ref()]
+            .into_iter()
+            .flatten()
+            .find(|view| view.id == view_id)
+    }
+
+    pub fn find_view_mut(&mut self, view_id: &str) -> Option<&mut UiKitView> {
+        [self.modal.as_mut(), self.contextual_bar.as_mut()]
+            .into_iter()
+            .flatten()
+            .find(|view| view.id == view_id)
+    }
+
+    // Open or update view, values typed in an already displayed view are kept.
+    fn show_view(current: &mut Option<UiKitView>, view: UiKitView) {
+        match current {
+            Some(current) if current.id == view.id => current.update(view),
+            _ => *current = Some(view),
+        }
+    }
+
+    pub fn close_view(&mut self, view_id: &str) -> UiKitInstruction {
+        if self.modal.as_ref().is_some_and(|view| view.id == view_id) {
+            self.modal = None;
+            UiKitInstruction::ModalClose
+        } else if self
+            .contextual_bar
+            .as_ref()
+            .is_some_and(|view| view.id == view_id)
+        {
+            self.contextual_bar = None;
+            UiKitInstruction::ContextualBarClose
+        } else {
+            UiKitInstruction::Ignored
+        }
+    }
+
+    // Stream event contains args list, ui.interaction result the object.
+    pub fn apply_interaction(&mut self, value: &serde_json::Value) -> UiKitInstruction {
+        let value = match value.as_array() {
+            Some(args) => match args.first() {
+                Some(value) => value,
+                None => return UiKitInstruction::Ignored,
+            },
+            None => value,
+        };
+        let Ok(interaction) = UiKitInteraction::deserialize(value) else {
+            return UiKitInstruction::Ignored;
+        };
+        if !interaction.trigger_id.is_empty()
+            && !self.pending_trigger_ids.remove(&interaction.trigger_id)
+        {
+            // Already handled, or sent to another client of same user
+            return UiKitInstruction::Ignored;
+        }
+        let view = interaction.view;
+        match interaction.interaction_type.as_str() {
+            "modal.open" | "modal.update" => {
+                let Some(view) = view else {
+                    return UiKitInstruction::Ignored;
+                };
+                let opened = self.modal.as_ref().is_some_and(|modal| modal.id == view.id);
+                UiKitManager::show_view(&mut self.modal, view);
+                if opened {
+                    UiKitInstruction::ModalUpdate
+                } else {
+                    UiKitInstruction::ModalOpen
+                }
+            }
+            "contextual_bar.open" | "contextual_bar.update" => {
+                let Some(view) = view else {
+                    return UiKitInstruction::Ignored;
+                };
+                let opened = self
+                    .contextual_bar
+                    .as_ref()
+                    .is_some_and(|contextual_bar| contextual_bar.id == view.id);
+                UiKitManager::show_view(&mut self.contextual_bar, view);
+                if opened {
+                    UiKitInstruction::ContextualBarUpdate
+                } else {
+                    UiKitInstruction::ContextualBarOpen
+                }
+            }
+            "modal.close" => {
+                self.modal = None;
+                UiKitInstruction::ModalClose
+            }
+            "contextual_bar.close" => {
+                self.contextual_bar = None;
+                UiKitInstruction::ContextualBarClose
+            }
+            "errors" => {
+                let view_id = view.map(|view| view.id).unwrap_or(interaction.view_id);
+                match self.find_view_mut(&view_id) {
+                    Some(view) => {
+                        view.errors = interaction.errors;
+                        UiKitInstruction::Errors(view_id)
+                    }
+                    None => UiKitInstruction::Ignored,
+                }
+            }
+            _ => UiKitInstruction::Ignored,
+        }
+    }
+
+    fn apply_result(&mut self, result: &str) -> UiKitInstruction {
+        match serde_json::from_str::<serde_json::Value>(result) {
+            Ok(value) => self.apply_interaction(&value),
+            Err(_) => UiKitInstruction::Ignored,
+        }
+    }
+
+    fn interaction_method(
+        &mut self,
+        interaction_type: UiInteractionType,
+        app_id: &str,
+    ) -> AppsUiInteractionMethod {
+        AppsUiInteractionMethod {
+            settings: self.settings.clone(),
+            server_url: self.server_url.clone(),
+            app_id: app_id.to_string(),
+            interaction_type,
+            trigger_id: self.generate_trigger_id(),
+            ..Default::default()
+        }
+    }
+
+    // Button clicked or option selected, value is already set in action.
+    pub async fn block_action(
+        &mut self,
+        action: &UiKitAction,
+        container: &UiKitContainer,
+    ) -> Result<UiKitInstruction, UiKitError> {
+        let mut method = self.interaction_method(UiInteractionType::BlockAction, &action.app_id);
+        method.action_id = action.action_id.clone();
+        method.block_id = action.block_id.clone();
+        method.value = action.value.clone();
+        match container {
+            UiKitContainer::Message {
+                room_id,
+                message_id,
+                tmid,
+            } => {
+                method.room_id = room_id.clone();
+                method.message_id = message_id.clone();
+                method.tmid = tmid.clone();
+            }
+            UiKitContainer::View { view_id } => {
+                let view = self
+                    .find_view(view_id)
+                    .ok_or_else(|| UiKitError::UnknownView(view_id.clone()))?;
+                method.view_id = view_id.clone();
+                method.state = view.state.clone();
+            }
+        }
+        let result = method.call().await?;
+        Ok(self.apply_result(&result))
+    }
+
+    // View is closed when app doesn't answer with an update or errors.
+    pub async fn view_submit(&mut self, view_id: &str) -> Result<UiKitInstruction, UiKitError> {
+        let view = self
+            .find_view(view_id)
+            .ok_or_else(|| UiKitError::UnknownView(view_id.to_string()))?;
+        let (app_id, state) = (view.app_id.clone(), view.state.clone());
+        let mut method = self.interaction_method(UiInteractionType::ViewSubmit, &app_id);
+        method.view_id = view_id.to_string();
+        method.state = state;
+        let result = method.call().await?;
+        match self.apply_result(&result) {
+            UiKitInstruction::Ignored => Ok(self.close_view(view_id)),
+            instruction => Ok(instruction),
+        }
+    }
+
+    // Closed by user, app is notified after view is removed.
+    pub async fn view_closed(&mut self, view_id: &str) -> Result<UiKitInstruction, UiKitError> {
+        let view = self
+            .find_view(view_id)
+            .ok_or_else(|| UiKitError::UnknownView(view_id.to_string()))?;
+        let (app_id, state) = (view.app_id.clone(), view.state.clone());
+        let instruction = self.close_view(view_id);
+        let mut method = self.interaction_method(UiInteractionType::ViewClosed, &app_id);
+        method.view_id = view_id.to_string();
+        method.state = state;
+        // Nothing to display after close
+        self.pending_trigger_ids.remove(&method.trigger_id);
+        method.call().await?;
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uikit::uikitmanager::{
+        TRIGGER_ID_ALPHABET, UiKitInstruction, UiKitManager, generate_random_id,
+    };
+    use serde_json::json;
+
+    fn modal_open() -> serde_json::Value {
+        let file =
+            std::fs::File::open("src/data/uikit/modalopen.json").expect("Failed to open file");
+        serde_json::from_reader(file).expect("JSON was not well-formatted")
+    }
+
+    fn generate_manager() -> UiKitManager {
+        let mut manager = UiKitManager::default();
+        manager
+            .pending_trigger_ids
+            .insert("Tx3pQmRbW8cZk2LdN".to_string());
+        manager
+    }
+
+    #[test]
+    fn test_generate_trigger_id() {
+        let mut manager = UiKitManager::default();
+        let trigger_id = manager.generate_trigger_id();
+        assert_eq!(trigger_id.len(), 17);
+        assert!(trigger_id.bytes().all(|c| TRIGGER_ID_ALPHABET.contains(&c)));
+        assert!(manager.pending_trigger_ids.contains(&trigger_id));
+        assert_ne!(generate_random_id(), generate_random_id());
+    }
+
+    #[test]
+    fn test_modal() {
+        let mut manager = generate_manager();
+        // Stream event sends args list
+        assert_eq!(
+            manager.apply_interaction(&json!([modal_open()])),
+            UiKitInstruction::ModalOpen
+        );
+        assert!(manager.pending_trigger_ids.is_empty());
+        assert_eq!(manager.modal.as_ref().unwrap().id, "rollback-view");
+
+        // Same trigger id is only handled once
+        assert_eq!(
+            manager.apply_interaction(&modal_open()),
+            UiKitInstruction::Ignored
+        );
+
+        manager.find_view_mut("rollback-view").unwrap().set_value(
+            "reason",
+            "reason_input",
+            "broken build",
+        );
+        let mut update = modal_open();
+        update["type"] = json!("modal.update");
+        update["triggerId"] = json!("");
+        update["view"]["title"]["text"] = json!("Rollback production");
+        assert_eq!(
+            manager.apply_interaction(&update),
+            UiKitInstruction::ModalUpdate
+        );
+        let modal = manager.modal.as_ref().unwrap();
+        assert_eq!(modal.title, "Rollback production");
+        assert_eq!(modal.value("reason", "reason_input"), Some("broken build"));
+
+        assert_eq!(
+            manager.apply_interaction(&json!({
+                "type": "errors", "viewId": "rollback-view", "appId": "deploy-bot",
+                "errors": {"reason": "Reason is too short"}
+            })),
+            UiKitInstruction::Errors("rollback-view".to_string())
+        );
+        assert_eq!(
+            manager.modal.as_ref().unwrap().errors["reason"],
+            "Reason is too short"
+        );
+
+        assert_eq!(
+            manager.apply_interaction(&json!({"type": "modal.close", "appId": "deploy-bot"})),
+            UiKitInstruction::ModalClose
+        );
+        assert!(manager.modal.is_none());
+    }
+
+    #[test]
+    fn test_contextual_bar() {
+        let mut manager = generate_manager();
+        let mut open = modal_open();
+        open["type"] = json!("contextual_bar.open");
+        open["view"]["type"] = json!("contextualBar");
+        assert_eq!(
+            manager.apply_interaction(&open),
+            UiKitInstruction::ContextualBarOpen
+        );
+        assert!(manager.modal.is_none());
+        assert!(manager.find_view("rollback-view").is_some());
+
+        assert_eq!(
+            manager.close_view("rollback-view"),
+            UiKitInstruction::ContextualBarClose
+        );
+        assert!(manager.contextual_bar.is_none());
+        assert_eq!(
+            manager.close_view("rollback-view"),
+            UiKitInstruction::Ignored
+        );
+    }
+
+    #[test]
+    fn test_invalid_interaction() {
+        let mut manager = generate_manager();
+        assert_eq!(
+            manager.apply_interaction(&json!([])),
+            UiKitInstruction::Ignored
+        );
+        assert_eq!(
+            manager.apply_interaction(&json!({"success": true})),
+            UiKitInstruction::Ignored
+        );
+        // No view to display
+        assert_eq!(
+            manager.apply_interaction(&json!({"type": "modal.open"})),
+            UiKitInstruction::Ignored
+        );
+        assert_eq!(
+            manager.apply_interaction(&json!({"type": "errors", "viewId": "unknown"})),
+            UiKitInstruction::Ignored
+        );
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::message::block::{Block, BlockType};
+use crate::message::blockaccessoryoption::BlockAccessoryOption;
+use crate::message::blockaction::{BlockAction, ButtonAction};
+
+// Identify the interactive element which sends a blockAction.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UiKitAction {
+    pub app_id: String,
+    pub block_id: String,
+    pub action_id: String,
+    // Value of button, overwritten by selected option for menus
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiKitElement {
+    Button {
+        action: UiKitAction,
+        text: String,
+        // "primary", "danger" or empty
+        style: String,
+        // Link buttons are opened by client and still notify app
+        url: String,
+    },
+    Overflow {
+        action: UiKitAction,
+        options: Vec<BlockAccessoryOption>,
+    },
+    Select {
+        action: UiKitAction,
+        placeholder: String,
+        options: Vec<BlockAccessoryOption>,
+    },
+    Image {
+        url: String,
+        alt_text: String,
+    },
+    TextInput {
+        action: UiKitAction,
+        placeholder: String,
+        multiline: bool,
+    },
+    Text(ButtonAction),
+    // Element type
+    Unsupported(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiKitNode {
+    Section {
+        text: String,
+        fields: Vec<ButtonAction>,
+        accessory: Option<UiKitElement>,
+    },
+    Actions(Vec<UiKitElement>),
+    Context(Vec<UiKitElement>),
+    Divider,
+    Image {
+        url: String,
+        alt_text: String,
+        title: String,
+    },
+    Preview {
+        title: String,
+        description: String,
+        url: String,
+        thumbnail_url: String,
+    },
+    Callout {
+        // "info", "success", "warning" or "danger"
+        variant: String,
+        title: String,
+        text: String,
+    },
+    VideoConf {
+        call_id: String,
+    },
+    Input {
+        label: String,
+        optional: bool,
+        element: Option<UiKitElement>,
+    },
+    Unsupported,
+}
+
+fn render_action(block: &Block, element: &BlockAction) -> UiKitAction {
+    UiKitAction {
+        app_id: block.app_id.clone(),
+        block_id: if element.block_id.is_empty() {
+            block.block_id.clone()
+        } else {
+            element.block_id.clone()
+        },
+        action_id: element.action_id.clone(),
+        value: element.value.clone(),
+    }
+}
+
+pub fn render_element(block: &Block, element: &BlockAction) -> UiKitElement {
+    match element.type_block.as_str() {
+        "button" => UiKitElement::Button {
+            action: render_action(block, element),
+            text: element.button.text.clone(),
+            style: element.style.clone(),
+            url: element.url.clone(),
+        },
+        "overflow" => UiKitElement::Overflow {
+            action: render_action(block, element),
+            options: element.options.clone(),
+        },
+        "static_select" => UiKitElement::Select {
+            action: render_action(block, element),
+            placeholder: element.placeholder.text.clone(),
+            options: element.options.clone(),
+        },
+        "image" => UiKitElement::Image {
+            url: element.image_url.clone(),
+            alt_text: element.alt_text.clone(),
+        },
+        "plain_text_input" => UiKitElement::TextInput {
+            action: render_action(block, element),
+            placeholder: element.placeholder.text.clone(),
+            multiline: element.multiline,
+        },
+        "plain_text" | "mrkdwn" => UiKitElement::Text(element.button.clone()),
+        element_type => UiKitElement::Unsupported(element_type.to_string()),
+    }
+}
+
+pub fn render_block(block: &Block) -> UiKitNode {
+    let elements = || {
+        block
+            .block_actions
+            .blockactions
+            .iter()
+            .map(|element| render_element(block, element))
+            .collect()
+    };
+    match block.block_type {
+        BlockType::Section => UiKitNode::Section {
+            text: block.section_text.clone(),
+            fields: block.fields.clone(),
+            accessory: block
+                .accessory
+                .as_ref()
+                .map(|element| render_element(block, element)),
+        },
+        BlockType::Actions => UiKitNode::Actions(elements()),
+        BlockType::Context => UiKitNode::Context(elements()),
+        BlockType::Divider => UiKitNode::Divider,
+        BlockType::Image => UiKitNode::Image {
+            url: block.image_url.clone(),
+            alt_text: block.alt_text.clone(),
+            title: block.title.clone(),
+        },
+        BlockType::Preview => UiKitNode::Preview {
+            title: block.title.clone(),
+            description: block.description.clone(),
+            url: block.external_url.clone(),
+            thumbnail_url: block
+                .thumb
+                .as_ref()
+                .map(|thumb| thumb.url.clone())
+                .unwrap_or_default(),
+        },
+        BlockType::Callout => UiKitNode::Callout {
+            variant: block.variant.clone(),
+            title: block.title.clone(),
+            text: block.section_text.clone(),
+        },
+        BlockType::VideoConf => UiKitNode::VideoConf {
+            call_id: block.call_id.clone(),
+        },
+        BlockType::Input => UiKitNode::Input {
+            label: block.label.clone(),
+            optional: block.optional,
+            element: block
+                .element
+                .as_ref()
+                .map(|element| render_element(block, element)),
+        },
+        BlockType::Overflow | BlockType::Unknown => UiKitNode::Unsupported,
+    }
+}
+
+// Tree displayed by clients, text of sections and callouts is markdown.
+pub fn render_blocks(blocks: &[Block]) -> Vec<UiKitNode> {
+    blocks.iter().map(render_block).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::block::Block;
+    use crate::uikit::uikitrenderer::{UiKitAction, UiKitElement, UiKitNode, render_blocks};
+    use std::fs::File;
+
+    fn parse(filename: &str) -> Vec<UiKitNode> {
+        let file = File::open(filename).expect("Failed to open file");
+        let blocks: Vec<Block> =
+            serde_json::from_reader(file).expect("JSON was not well-formatted");
+        render_blocks(&blocks)
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let nodes = parse("src/data/uikit/blocks.json");
+        assert_eq!(nodes.len(), 9);
+
+        let UiKitNode::Section {
+            text,
+            fields,
+            accessory,
+        } = &nodes[0]
+        else {
+            panic!("Section expected");
+        };
+        assert_eq!(text, "*Deploy* of `ruqola` requested");
+        assert_eq!(fields.len(), 2);
+        let Some(UiKitElement::Overflow { action, options }) = accessory else {
+            panic!("Overflow expected");
+        };
+        assert_eq!(action.app_id, "deploy-bot");
+        assert_eq!(action.action_id, "more");
+        assert_eq!(options.len(), 2);
+
+        let UiKitNode::Actions(elements) = &nodes[1] else {
+            panic!("Actions expected");
+        };
+        assert_eq!(
+            elements[0],
+            UiKitElement::Button {
+                action: UiKitAction {
+                    app_id: "deploy-bot".to_string(),
+                    block_id: "decision".to_string(),
+                    action_id: "approve".to_string(),
+                    value: "1234".to_string(),
+                },
+                text: "Approve".to_string(),
+                style: "primary".to_string(),
+                url: String::new(),
+            }
+        );
+        assert!(matches!(
+            &elements[1],
+            UiKitElement::Button { url, .. } if url.starts_with("https://invent.kde.org/")
+        ));
+        assert!(matches!(
+            &elements[2],
+            UiKitElement::Select { placeholder, options, .. } if placeholder == "Environment" && options.len() == 2
+        ));
+
+        let UiKitNode::Context(elements) = &nodes[2] else {
+            panic!("Context expected");
+        };
+        assert!(
+            matches!(&elements[0], UiKitElement::Image { alt_text, .. } if alt_text == "konqi")
+        );
+        assert!(
+            matches!(&elements[1], UiKitElement::Text(text) if text.text == "Requested by @foo")
+        );
+
+        assert_eq!(nodes[3], UiKitNode::Divider);
+        assert!(matches!(&nodes[4], UiKitNode::Image { title, .. } if title == "Screenshot"));
+        assert_eq!(
+            nodes[5],
+            UiKitNode::Preview {
+                title: "Release notes".to_string(),
+                description: "What's new in 2.6".to_string(),
+                url: "https://kde.org/announcements/".to_string(),
+                thumbnail_url: "https://kde.org/thumb.png".to_string(),
+            }
+        );
+        assert_eq!(
+            nodes[6],
+            UiKitNode::Callout {
+                variant: "warning".to_string(),
+                title: "Heads up".to_string(),
+                text: "Production is *frozen* this week".to_string(),
+            }
+        );
+        assert_eq!(
+            nodes[7],
+            UiKitNode::VideoConf {
+                call_id: "call1".to_string()
+            }
+        );
+        assert_eq!(nodes[8], UiKitNode::Unsupported);
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::message::block::Block;
+use crate::message::blockaction::{BlockAction, deserialize_text};
+use crate::uikit::uikitrenderer::{UiKitNode, render_blocks};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+// Modal or contextual bar opened by an app.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct UiKitView {
+    pub id: String,
+    #[serde(rename = "appId")]
+    pub app_id: String,
+    // "modal" or "contextualBar"
+    #[serde(rename = "type")]
+    pub view_type: String,
+    #[serde(deserialize_with = "deserialize_text")]
+    pub title: String,
+    pub submit: Option<BlockAction>,
+    pub close: Option<BlockAction>,
+    pub blocks: Vec<Block>,
+    // Values typed in input blocks: block id => action id => value
+    #[serde(skip)]
+    pub state: HashMap<String, HashMap<String, String>>,
+    // Block id => error message, set when app rejects submitted values
+    #[serde(skip)]
+    pub errors: HashMap<String, String>,
+}
+
+impl UiKitView {
+    pub fn new() -> Self {
+        UiKitView::default()
+    }
+
+    pub fn nodes(&self) -> Vec<UiKitNode> {
+        render_blocks(&self.blocks)
+    }
+
+    pub fn set_value(&mut self, block_id: &str, action_id: &str, value: &str) {
+        self.state
+            .entry(block_id.to_string())
+            .or_default()
+            .insert(action_id.to_string(), value.to_string());
+        // Error is obsolete once value changed
+        self.errors.remove(block_id);
+    }
+
+    pub fn value(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        self.state
+            .get(block_id)
+            .and_then(|values| values.get(action_id))
+            .map(|value| value.as_str())
+    }
+
+    // Update keeps values already typed by user.
+    pub fn update(&mut self, view: UiKitView) {
+        let state = std::mem::take(&mut self.state);
+        *self = view;
+        self.state = state;
+    }
+}
+
+/*
+Debug output for UiKitView
+*/
+impl fmt::Display for UiKitView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UiKitView(id: {}, appId: {}, type: {}, title: {}, blocks: {})",
+            self.id,
+            self.app_id,
+            self.view_type,
+            self.title,
+            self.blocks.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uikit::uikitrenderer::UiKitNode;
+    use crate::uikit::uikitview::UiKitView;
+    use std::fs::File;
+
+    pub fn parse(filename: &str) -> UiKitView {
+        let file = File::open(filename).expect("Failed to open file");
+        let value: serde_json::Value =
+            serde_json::from_reader(file).expect("JSON was not well-formatted");
+        serde_json::from_value(value["view"].clone()).expect("Invalid view")
+    }
+
+    #[test]
+    fn test_view_default() {
+        let view = UiKitView::new();
+        assert!(view.id.is_empty());
+        assert!(view.app_id.is_empty());
+        assert!(view.title.is_empty());
+        assert!(view.submit.is_none());
+        assert!(view.blocks.is_empty());
+        assert!(view.state.is_empty());
+    }
+
+    #[test]
+    fn test_parse_modal() {
+        let view = parse("src/data/uikit/modalopen.json");
+        assert_eq!(view.id, "rollback-view");
+        assert_eq!(view.app_id, "deploy-bot");
+        assert_eq!(view.view_type, "modal");
+        assert_eq!(view.title, "Rollback");
+        assert_eq!(view.submit.as_ref().unwrap().button.text, "Rollback");
+        assert_eq!(view.close.as_ref().unwrap().button.text, "Cancel");
+        assert_eq!(view.blocks.len(), 2);
+        let nodes = view.nodes();
+        assert!(
+            matches!(&nodes[1], UiKitNode::Input { label, optional: false, element: Some(_) } if label == "Reason")
+        );
+    }
+
+    #[test]
+    fn test_state() {
+        let mut view = parse("src/data/uikit/modalopen.json");
+        view.errors
+            .insert("reason".to_string(), "Reason is required".to_string());
+        view.set_value("reason", "reason_input", "broken build");
+        assert_eq!(view.value("reason", "reason_input"), Some("broken build"));
+        assert!(view.value("reason", "other").is_none());
+        assert!(view.errors.is_empty());
+
+        let mut updated = view.clone();
+        updated.title = "Rollback production".to_string();
+        updated.state.clear();
+        view.update(updated);
+        assert_eq!(view.title, "Rollback production");
+        assert_eq!(view.value("reason", "reason_input"), Some("broken build"));
+    }
+}
@@ -0,0 +1,129 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::message::messageurl::{ContentType, MessageUrl};
+
+// Data displayed for a link of a message.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreviewCard {
+    pub message_identifier: String,
+    pub url: String,
+    pub title: String,
+    pub description: String,
+    pub site_name: String,
+    pub author_name: String,
+    pub author_url: String,
+    // Absolute url, empty when there is no image
+    pub image_url: String,
+    pub image_width: i64,
+    pub image_height: i64,
+    pub content_type: ContentType,
+    // Only title line is displayed
+    pub collapsed: bool,
+    // Image is downloaded only when user asks for it
+    pub load_image_on_demand: bool,
+}
+
+impl PreviewCard {
+    // None when server didn't find anything to show, or preview was removed.
+    pub fn from_message_url(message_identifier: &str, message_url: &MessageUrl) -> Option<Self> {
+        if !message_url.show_preview {
+            return None;
+        }
+        Some(PreviewCard {
+            message_identifier: message_identifier.to_string(),
+            url: message_url.url.clone(),
+            title: message_url.page_title.clone(),
+            description: message_url.description.clone(),
+            site_name: message_url.site_name.clone(),
+            author_name: message_url.author_name.clone(),
+            author_url: message_url.author_url.clone(),
+            image_url: message_url.image_build_url.clone(),
+            image_width: message_url.image_width,
+            image_height: message_url.image_height,
+            content_type: message_url.content_type.clone(),
+            ..Default::default()
+        })
+    }
+
+    pub fn has_image(&self) -> bool {
+        !self.image_url.is_empty()
+    }
+
+    // Title, or url when page doesn't have one.
+    pub fn display_title(&self) -> &str {
+        if self.title.is_empty() {
+            &self.url
+        } else {
+            &self.title
+        }
+    }
+
+    // Image size which fits in maximum size keeping ratio, None when size is unknown.
+    pub fn scaled_image_size(&self, maximum_width: i64, maximum_height: i64) -> Option<(i64, i64)> {
+        if self.image_width <= 0 || self.image_height <= 0 {
+            return None;
+        }
+        let ratio = f64::min(
+            1.0,
+            f64::min(
+                maximum_width as f64 / self.image_width as f64,
+                maximum_height as f64 / self.image_height as f64,
+            ),
+        );
+        Some((
+            (self.image_width as f64 * ratio).round() as i64,
+            (self.image_height as f64 * ratio).round() as i64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::messageurl::{ContentType, MessageUrl};
+    use crate::urlpreview::previewcard::PreviewCard;
+
+    fn generate_message_url() -> MessageUrl {
+        MessageUrl {
+            url: "https://kde.org".to_string(),
+            page_title: "KDE".to_string(),
+            image_build_url: "https://kde.org/logo.png".to_string(),
+            image_width: 1200,
+            image_height: 600,
+            show_preview: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let b = PreviewCard::default();
+        assert!(b.url.is_empty());
+        assert!(!b.has_image());
+        assert!(!b.collapsed);
+        assert!(!b.load_image_on_demand);
+        assert_eq!(b.content_type, ContentType::None);
+        assert!(b.scaled_image_size(100, 100).is_none());
+    }
+
+    #[test]
+    fn test_from_message_url() {
+        let mut message_url = generate_message_url();
+        let card = PreviewCard::from_message_url("msg1", &message_url).unwrap();
+        assert_eq!(card.message_identifier, "msg1");
+        assert_eq!(card.display_title(), "KDE");
+        assert!(card.has_image());
+        assert_eq!(card.scaled_image_size(300, 300), Some((300, 150)));
+        assert_eq!(card.scaled_image_size(2000, 2000), Some((1200, 600)));
+
+        message_url.page_title.clear();
+        let card = PreviewCard::from_message_url("msg1", &message_url).unwrap();
+        assert_eq!(card.display_title(), "https://kde.org");
+
+        message_url.show_preview = false;
+        assert!(PreviewCard::from_message_url("msg1", &message_url).is_none());
+    }
+}
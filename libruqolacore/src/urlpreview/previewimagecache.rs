@@ -0,0 +1,219 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use std::fmt;
+use std::path::PathBuf;
+
+// Images of links come from any site, don't download huge files.
+pub const DEFAULT_MAXIMUM_IMAGE_SIZE: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum PreviewImageCacheError {
+    NoCacheDirectory,
+    InvalidUrl(String),
+    TooLarge(u64),
+    Download(String),
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for PreviewImageCacheError {
+    fn from(error: std::io::Error) -> Self {
+        PreviewImageCacheError::Io(error)
+    }
+}
+
+impl From<reqwest::Error> for PreviewImageCacheError {
+    fn from(error: reqwest::Error) -> Self {
+        PreviewImageCacheError::Download(error.to_string())
+    }
+}
+
+/* Debug output for PreviewImageCacheError */
+impl fmt::Display for PreviewImageCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PreviewImageCacheError::NoCacheDirectory => write!(f, "No cache directory"),
+            PreviewImageCacheError::InvalidUrl(url) => write!(f, "Invalid url: {}", url),
+            PreviewImageCacheError::TooLarge(size) => write!(f, "Image too large: {} bytes", size),
+            PreviewImageCacheError::Download(error) => write!(f, "Download error: {}", error),
+            PreviewImageCacheError::Io(error) => write!(f, "Io error: {}", error),
+        }
+    }
+}
+
+// Images of url previews stored on disk, file name is a hash of image url.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreviewImageCache {
+    // Images are not cached when it's empty
+    pub cache_directory: PathBuf,
+    // In bytes, larger images are not downloaded
+    pub maximum_image_size: u64,
+}
+
+impl Default for PreviewImageCache {
+    fn default() -> Self {
+        PreviewImageCache::new(PathBuf::new())
+    }
+}
+
+impl PreviewImageCache {
+    pub fn new(cache_directory: PathBuf) -> Self {
+        PreviewImageCache {
+            cache_directory,
+            maximum_image_size: DEFAULT_MAXIMUM_IMAGE_SIZE,
+        }
+    }
+
+    fn image_path(&self, url: &str) -> Option<PathBuf> {
+        if self.cache_directory.as_os_str().is_empty() || url.is_empty() {
+            return None;
+        }
+        Some(self.cache_directory.join(cache_file_name(url)))
+    }
+
+    pub fn cached_image(&self, url: &str) -> Option<PathBuf> {
+        self.image_path(url).filter(|path| path.is_file())
+    }
+
+    pub fn store_image(&self, url: &str, data: &[u8]) -> Result<PathBuf, PreviewImageCacheError> {
+        let path = self
+            .image_path(url)
+            .ok_or(PreviewImageCacheError::NoCacheDirectory)?;
+        if data.len() as u64 > self.maximum_image_size {
+            return Err(PreviewImageCacheError::TooLarge(data.len() as u64));
+        }
+        std::fs::create_dir_all(&self.cache_directory)?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    // Returns cached image, downloads it when it's missing.
+    pub async fn image(&self, url: &str) -> Result<PathBuf, PreviewImageCacheError> {
+        if let Some(path) = self.cached_image(url) {
+            return Ok(path);
+        }
+        if self.image_path(url).is_none() {
+            return Err(PreviewImageCacheError::NoCacheDirectory);
+        }
+        let image_url = reqwest::Url::parse(url)
+            .map_err(|_| PreviewImageCacheError::InvalidUrl(url.to_string()))?;
+        let response = reqwest::get(image_url).await?.error_for_status()?;
+        let data = read_limited(response, self.maximum_image_size).await?;
+        self.store_image(url, &data)
+    }
+
+    pub fn remove_image(&self, url: &str) {
+        if let Some(path) = self.image_path(url) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    pub fn clear(&self) {
+        if !self.cache_directory.as_os_str().is_empty() {
+            let _ = std::fs::remove_dir_all(&self.cache_directory);
+        }
+    }
+}
+
+// Stops as soon as maximum size is reached, "Content-Length" can be missing or wrong.
+pub(crate) async fn read_limited(
+    mut response: reqwest::Response,
+    maximum_size: u64,
+) -> Result<Vec<u8>, PreviewImageCacheError> {
+    if let Some(length) = response.content_length()
+        && length > maximum_size
+    {
+        return Err(PreviewImageCacheError::TooLarge(length));
+    }
+    let mut data = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        data.extend_from_slice(&chunk);
+        if data.len() as u64 > maximum_size {
+            return Err(PreviewImageCacheError::TooLarge(data.len() as u64));
+        }
+    }
+    Ok(data)
+}
+
+// FNV-1a, stable between runs (DefaultHasher is not).
+pub(crate) fn hash_name(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub(crate) fn cache_file_name(url: &str) -> String {
+    let extension = url
+        .split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .and_then(|file_name| file_name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase())
+        .filter(|extension| {
+            (1..=4).contains(&extension.len())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+        .unwrap_or_else(|| "img".to_string());
+    format!("{}.{}", hash_name(url), extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::urlpreview::previewimagecache::{
+        DEFAULT_MAXIMUM_IMAGE_SIZE, PreviewImageCache, PreviewImageCacheError, cache_file_name,
+    };
+
+    #[test]
+    fn test_default_values() {
+        let cache = PreviewImageCache::default();
+        assert!(cache.cache_directory.as_os_str().is_empty());
+        assert_eq!(cache.maximum_image_size, DEFAULT_MAXIMUM_IMAGE_SIZE);
+        assert!(cache.cached_image("https://kde.org/logo.png").is_none());
+        assert!(matches!(
+            cache.store_image("https://kde.org/logo.png", b"PNG"),
+            Err(PreviewImageCacheError::NoCacheDirectory)
+        ));
+    }
+
+    #[test]
+    fn test_cache_file_name() {
+        let name = cache_file_name("https://kde.org/logo.PNG?size=2");
+        assert!(name.ends_with(".png"));
+        assert_eq!(name.len(), 20);
+        assert_eq!(name, cache_file_name("https://kde.org/logo.PNG?size=2"));
+        assert_ne!(name, cache_file_name("https://kde.org/logo.PNG?size=3"));
+        assert!(cache_file_name("https://kde.org/image").ends_with(".img"));
+        assert!(cache_file_name("https://kde.org/a.b/image").ends_with(".img"));
+        assert!(cache_file_name("https://kde.org/image.verylong").ends_with(".img"));
+    }
+
+    #[test]
+    fn test_store_image() {
+        let directory =
+            std::env::temp_dir().join(format!("ruqola-previewimagecache-{}", std::process::id()));
+        let mut cache = PreviewImageCache::new(directory.clone());
+        let url = "https://kde.org/logo.png";
+        assert!(cache.cached_image(url).is_none());
+
+        let path = cache.store_image(url, b"PNG1").unwrap();
+        assert!(path.starts_with(&directory));
+        assert_eq!(cache.cached_image(url), Some(path.clone()));
+        assert_eq!(std::fs::read(&path).unwrap(), b"PNG1");
+
+        cache.maximum_image_size = 2;
+        assert!(matches!(
+            cache.store_image("https://kde.org/other.png", b"PNG2"),
+            Err(PreviewImageCacheError::TooLarge(4))
+        ));
+        assert!(cache.cached_image("https://kde.org/other.png").is_none());
+
+        cache.remove_image(url);
+        assert!(cache.cached_image(url).is_none());
+        cache.clear();
+        assert!(!directory.exists());
+    }
+}
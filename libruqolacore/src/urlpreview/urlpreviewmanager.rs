@@ -0,0 +1,218 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+extern crate ini;
+use crate::messageinfo::MessageInfo;
+use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+use crate::urlpreview::previewcard::PreviewCard;
+use crate::urlpreview::previewimagecache::{PreviewImageCache, PreviewImageCacheError};
+use ini::Ini;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+// Builds link previews of messages and keeps collapsed state of each message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UrlPreviewManager {
+    // Local setting, no preview at all
+    pub hide_previews: bool,
+    // From user preferences
+    pub collapse_by_default: bool,
+    pub auto_image_load: bool,
+    pub image_cache: PreviewImageCache,
+    // Message identifier => collapsed, when user changed it
+    collapsed: HashMap<String, bool>,
+}
+
+impl Default for UrlPreviewManager {
+    fn default() -> Self {
+        UrlPreviewManager::new()
+    }
+}
+
+impl UrlPreviewManager {
+    pub fn new() -> Self {
+        UrlPreviewManager {
+            hide_previews: false,
+            collapse_by_default: false,
+            auto_image_load: true,
+            image_cache: PreviewImageCache::default(),
+            collapsed: HashMap::new(),
+        }
+    }
+
+    pub fn set_preferences(&mut self, preferences: &OwnUserPreferences) {
+        self.collapse_by_default = preferences.collapse_media_by_default;
+        self.auto_image_load = preferences.auto_image_load;
+    }
+
+    pub fn is_collapsed(&self, message_identifier: &str) -> bool {
+        self.collapsed
+            .get(message_identifier)
+            .copied()
+            .unwrap_or(self.collapse_by_default)
+    }
+
+    pub fn set_collapsed(&mut self, message_identifier: &str, collapsed: bool) {
+        self.collapsed
+            .insert(message_identifier.to_string(), collapsed);
+    }
+
+    // Returns new state.
+    pub fn toggle_collapsed(&mut self, message_identifier: &str) -> bool {
+        let collapsed = !self.is_collapsed(message_identifier);
+        self.set_collapsed(message_identifier, collapsed);
+        collapsed
+    }
+
+    // Message was deleted.
+    pub fn remove_message(&mut self, message_identifier: &str) {
+        self.collapsed.remove(message_identifier);
+    }
+
+    pub fn preview_cards(&self, message: &MessageInfo) -> Vec<PreviewCard> {
+        if self.hide_previews {
+            return Vec::new();
+        }
+        let collapsed = self.is_collapsed(&message.identifier);
+        message
+            .urls
+            .iter()
+            .filter_map(|url| PreviewCard::from_message_url(&message.identifier, url))
+            .map(|card| PreviewCard {
+                collapsed,
+                load_image_on_demand: !self.auto_image_load,
+                ..card
+            })
+            .collect()
+    }
+
+    // Image of card, downloaded when not cached yet.
+    pub async fn preview_image(
+        &self,
+        card: &PreviewCard,
+    ) -> Result<PathBuf, PreviewImageCacheError> {
+        if !card.has_image() {
+            return Err(PreviewImageCacheError::InvalidUrl(card.url.clone()));
+        }
+        self.image_cache.image(&card.image_url).await
+    }
+
+    pub fn load_settings(&mut self, file_name: &str) {
+        let Ok(conf) = Ini::load_from_file(file_name) else {
+            return;
+        };
+        if let Some(section) = conf.section(Some("UrlPreview"))
+            && let Some(hide_previews) = section.get("hidePreviews")
+        {
+            self.hide_previews = hide_previews == "true";
+        }
+    }
+
+    // Other sections of file are kept.
+    pub fn write_settings(&self, file_name: &str) -> std::io::Result<()> {
+        let mut conf = Ini::load_from_file(file_name).unwrap_or_default();
+        conf.with_section(Some("UrlPreview"))
+            .set("hidePreviews", self.hide_previews.to_string());
+        conf.write_to_file(file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::messageinfo::MessageInfo;
+    use crate::ownuser::ownuserpreferences::OwnUserPreferences;
+    use crate::urlpreview::urlpreviewmanager::UrlPreviewManager;
+
+    fn generate_message() -> MessageInfo {
+        serde_json::from_value(serde_json::json!({
+            "_id": "AkzpHAvZpdnuchw2a", "msg": "see https://kde.org and https://invent.kde.org",
+            "alias": "", "emoji": "", "avatar": "", "role": "", "rid": "GENERAL", "unread": false,
+            "urls": [
+                {"url": "https://kde.org", "meta": {"ogTitle": "KDE", "ogImage": "/logo.png"}},
+                {"url": "https://invent.kde.org", "meta": {}}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_values() {
+        let b = UrlPreviewManager::new();
+        assert!(!b.hide_previews);
+        assert!(!b.collapse_by_default);
+        assert!(b.auto_image_load);
+        assert!(!b.is_collapsed("foo"));
+    }
+
+    #[test]
+    fn test_preview_cards() {
+        let mut manager = UrlPreviewManager::new();
+        let message = generate_message();
+        let cards = manager.preview_cards(&message);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].message_identifier, "AkzpHAvZpdnuchw2a");
+        assert_eq!(cards[0].title, "KDE");
+        assert_eq!(cards[0].image_url, "https://kde.org/logo.png");
+        assert!(!cards[0].collapsed);
+        assert!(!cards[0].load_image_on_demand);
+
+        let preferences = OwnUserPreferences {
+            auto_image_load: false,
+            ..Default::default()
+        };
+        manager.set_preferences(&preferences);
+        assert!(manager.preview_cards(&message)[0].load_image_on_demand);
+
+        manager.hide_previews = true;
+        assert!(manager.preview_cards(&message).is_empty());
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let mut manager = UrlPreviewManager::new();
+        let message = generate_message();
+        assert!(manager.toggle_collapsed(&message.identifier));
+        assert!(manager.preview_cards(&message)[0].collapsed);
+        assert!(!manager.is_collapsed("other"));
+
+        let preferences = OwnUserPreferences {
+            collapse_media_by_default: true,
+            ..Default::default()
+        };
+        manager.set_preferences(&preferences);
+        assert!(manager.is_collapsed("other"));
+        manager.set_collapsed("other", false);
+        assert!(!manager.is_collapsed("other"));
+
+        manager.remove_message(&message.identifier);
+        assert!(!manager.toggle_collapsed(&message.identifier));
+    }
+
+    #[test]
+    fn test_settings() {
+        let file_name = std::env::temp_dir()
+            .join(format!(
+                "ruqola-urlpreviewmanager-{}.ini",
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .to_string();
+        std::fs::write(&file_name, "[General]\naccountName=kde\n").unwrap();
+        let mut manager = UrlPreviewManager::new();
+        manager.hide_previews = true;
+        manager.write_settings(&file_name).unwrap();
+
+        let mut loaded = UrlPreviewManager::new();
+        loaded.load_settings(&file_name);
+        assert!(loaded.hide_previews);
+        assert!(
+            std::fs::read_to_string(&file_name)
+                .unwrap()
+                .contains("accountName=kde")
+        );
+        let _ = std::fs::remove_file(&file_name);
+    }
+}
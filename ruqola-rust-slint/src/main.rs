@@ -6,6 +6,7 @@
 
 // TODO create a module for it!
 mod commandlineoptions;
+mod messagelistmodel;
 mod ruqolawindow;
 mod utils;
 
@@ -13,6 +14,7 @@ use crate::commandlineoptions::Cli;
 use clap::Parser;
 use libruqolacore::rocketchataccountmanager::CommandFromGui;
 use libruqolacore::rocketchataccountmanager::CommandToBackend;
+use libruqolacore::rocketchataccountmanager::CommandToGui;
 use libruqolacore::rocketchataccountmanager::RocketChatAccountManager;
 use ruqolawindow::Gui;
 
@@ -72,8 +74,13 @@ async fn main() {
             let (transmeter_event_data_to_backend, mut receiver_event_data_from_backend) =
                 mpsc::unbounded_channel::<CommandToBackend>();
 
+            // create channel of rendered messages
+            let (transmeter_data_to_gui, receiver_data_from_backend) =
+                mpsc::unbounded_channel::<CommandToGui>();
+
             let mut manager = RocketChatAccountManager::new();
             manager.load_accounts();
+            manager.set_gui_tx(transmeter_data_to_gui);
             manager.initialize_accounts().await;
             tokio::spawn(async move {
                 while let Some(message) = receiver_data_from_ui.recv().await {
@@ -133,10 +140,19 @@ async fn main() {
                         CommandToBackend::UpdateAccountList => {
                             println!("Update account list");
                         }
+                        CommandToBackend::ComposerTextChanged { room_id, .. } => {
+                            println!("Composer text changed {:?}", room_id);
+                        }
+                        CommandToBackend::UserActivity => {
+                            println!("User activity");
+                        }
+                        CommandToBackend::NotificationActivated(action) => {
+                            println!("Notification activated {:?}", action);
+                        }
                     }
                 }
             });
-            Gui::ui_loop(transmeter_data_to_ui).await;
+            Gui::ui_loop(transmeter_data_to_ui, receiver_data_from_backend).await;
         }
     }
 }
@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2026 Laurent Montel <laurent.montel@kdab.com>
+ *
+ * SPDX-License-Identifier: LGPL-2.0-or-later
+ */
+
+use crate::ruqolawindow::ui::{MessageItem, MessageLine, MessageSpan};
+use libruqolacore::markdown::styledspanrenderer::{SpanKind, StyledSpan};
+use slint::{Color, ModelRc, VecModel};
+
+// Slint can't wrap a list of texts, so spans are split in lines (line breaks,
+// lines of code blocks).
+pub fn message_item(room_id: &str, message_id: &str, spans: &[StyledSpan]) -> MessageItem {
+    let mut lines: Vec<Vec<MessageSpan>> = vec![Vec::new()];
+    for span in spans {
+        if span.kind == SpanKind::LineBreak {
+            lines.push(Vec::new());
+            continue;
+        }
+        for (index, text) in span.text.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(Vec::new());
+            }
+            if !text.is_empty() {
+                lines.last_mut().unwrap().push(message_span(span, text));
+            }
+        }
+    }
+    let lines = lines
+        .into_iter()
+        .map(|spans| MessageLine {
+            spans: ModelRc::new(VecModel::from(spans)),
+        })
+        .collect::<Vec<_>>();
+    MessageItem {
+        room_id: room_id.into(),
+        message_id: message_id.into(),
+        lines: ModelRc::new(VecModel::from(lines)),
+    }
+}
+
+fn message_span(span: &StyledSpan, text: &str) -> MessageSpan {
+    let link = match &span.kind {
+        SpanKind::Link(url) => url.as_str(),
+        _ => "",
+    };
+    MessageSpan {
+        text: text.into(),
+        has_color: span.style.color.is_some(),
+        color: span
+            .style
+            .color
+            .map(|color| Color::from_rgb_u8(color.red, color.green, color.blue))
+            .unwrap_or_default(),
+        bold: span.style.bold || span.style.heading_level > 0,
+        italic: span.style.italic,
+        strike: span.style.strike,
+        code: span.style.code,
+        link: link.into(),
+    }
+}
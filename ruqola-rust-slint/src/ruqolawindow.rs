@@ -4,8 +4,9 @@
  * SPDX-License-Identifier: LGPL-2.0-or-later
  */
 
+use crate::messagelistmodel;
 use libruqolacore::{
-    rocketchataccountmanager::{CommandFromGui, RocketChatAccountManager},
+    rocketchataccountmanager::{CommandFromGui, CommandToGui, RocketChatAccountManager},
     rocketchataccountsettings::RocketChatAccountSettings,
 };
 
@@ -21,9 +22,50 @@ use ui::*;
 pub struct Gui {}
 
 impl Gui {
-    pub async fn ui_loop(user_inputs_out: mpsc::UnboundedSender<CommandFromGui>) {
+    pub async fn ui_loop(
+        user_inputs_out: mpsc::UnboundedSender<CommandFromGui>,
+        mut backend_commands_in: mpsc::UnboundedReceiver<CommandToGui>,
+    ) {
         let main_window = RuqolaWindow::new().unwrap();
 
+        main_window.set_message_model(Rc::new(VecModel::<MessageItem>::default()).into());
+        let weak_window = main_window.as_weak();
+        tokio::spawn(async move {
+            while let Some(command) = backend_commands_in.recv().await {
+                match command {
+                    // TODO only display messages of current account and room
+                    CommandToGui::RoomMessageChanged {
+                        room_id,
+                        message_id,
+                        spans,
+                        ..
+                    } => {
+                        let result = weak_window.upgrade_in_event_loop(move |main_window| {
+                            let item =
+                                messagelistmodel::message_item(&room_id, &message_id, &spans);
+                            let model = main_window.get_message_model();
+                            let Some(messages) =
+                                model.as_any().downcast_ref::<VecModel<MessageItem>>()
+                            else {
+                                return;
+                            };
+                            // Edited message replaces existing one
+                            match messages
+                                .iter()
+                                .position(|message| message.message_id == item.message_id)
+                            {
+                                Some(row) => messages.set_row_data(row, item),
+                                None => messages.push(item),
+                            }
+                        });
+                        if result.is_err() {
+                            println!("Impossible to display message, window is closed");
+                        }
+                    }
+                }
+            }
+        });
+
         let user_inputs_out_clone = user_inputs_out.clone();
         let user_inputs_out_clone_connect = user_inputs_out.clone();
         let user_inputs_out_clone_disconnect = user_inputs_out.clone();